@@ -0,0 +1,251 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use obex_alpha_i::vrf::{ecvrf_verify_beta_tai, VrfPi, VrfPk};
+use obex_alpha_ii::{
+    build_header, validate_header, BeaconInputs, BeaconVerifier, Header, PartRootProvider,
+    TicketRootProvider, TxRootProvider, OBEX_ALPHA_II_VERSION,
+};
+use obex_alpha_iii::{enc_ticket_leaf, TicketRecord};
+use obex_primitives::{merkle_root, Hash256};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// System allocator that counts allocations, so the borrowed-vs-owned benches can check
+/// their allocation claims rather than only time them.
+struct CountingAlloc;
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Allocations made by one call of `f`.
+fn allocs<R>(f: impl FnOnce() -> R) -> usize {
+    let before = ALLOCS.load(Ordering::Relaxed);
+    let r = f();
+    let n = ALLOCS.load(Ordering::Relaxed) - before;
+    drop(black_box(r));
+    n
+}
+
+fn bench_merkle_root(c: &mut Criterion) {
+    let leaves: Vec<Vec<u8>> = (0..1024).map(|i| vec![i as u8; 32]).collect();
+    c.bench_function("merkle_root_1024_leaves", |b| {
+        b.iter(|| {
+            let _ = merkle_root(black_box(&leaves));
+        });
+    });
+}
+
+fn bench_vrf_verify(c: &mut Criterion) {
+    let vk: VrfPk = [0u8; 32];
+    let alpha = [0u8; 32];
+    let pi: VrfPi = [0u8; 80];
+    c.bench_function("ecvrf_tai_verify", |b| {
+        b.iter(|| {
+            let _ = ecvrf_verify_beta_tai(black_box(&vk), black_box(&alpha), black_box(&pi));
+        });
+    });
+}
+
+fn bench_ticket_leaf(c: &mut Criterion) {
+    let rec = TicketRecord {
+        ticket_id: [0u8; 32],
+        txid: [1u8; 32],
+        sender: [2u8; 32],
+        nonce: 0,
+        amount_u: 1000,
+        fee_u: 10,
+        s_admit: 1,
+        s_exec: 1,
+        commit_hash: [3u8; 32],
+    };
+    c.bench_function("ticket_leaf_encode", |b| {
+        b.iter(|| {
+            let _ = enc_ticket_leaf(black_box(&rec));
+        });
+    });
+}
+
+fn bench_ticket_root(c: &mut Criterion) {
+    let mut recs: Vec<TicketRecord> = Vec::new();
+    for i in 0..200u64 {
+        recs.push(TicketRecord {
+            ticket_id: [0u8; 32],
+            txid: [i as u8; 32],
+            sender: [1u8; 32],
+            nonce: i,
+            amount_u: 1000,
+            fee_u: 10,
+            s_admit: 1,
+            s_exec: 1,
+            commit_hash: [2u8; 32],
+        });
+    }
+    let leaves: Vec<Vec<u8>> = recs.iter().map(enc_ticket_leaf).collect();
+    c.bench_function("ticket_root_200", |b| {
+        b.iter(|| {
+            let _ = merkle_root(black_box(&leaves));
+        });
+    });
+}
+
+fn bench_validate_header(c: &mut Criterion) {
+    struct BeaconOk;
+    impl BeaconVerifier for BeaconOk {
+        fn verify(&self, _i: &BeaconInputs<'_>) -> bool {
+            true
+        }
+    }
+    struct Zero;
+    impl TicketRootProvider for Zero {
+        fn compute_ticket_root(&self, _s: u64) -> Hash256 {
+            [0u8; 32]
+        }
+    }
+    impl PartRootProvider for Zero {
+        fn compute_part_root(&self, _s: u64) -> Hash256 {
+            [0u8; 32]
+        }
+    }
+    impl TxRootProvider for Zero {
+        fn compute_txroot(&self, _s: u64) -> Hash256 {
+            [0u8; 32]
+        }
+    }
+
+    let parent = Header {
+        parent_id: [9u8; 32],
+        slot: 7,
+        obex_version: OBEX_ALPHA_II_VERSION,
+        seed_commit: [1u8; 32],
+        vdf_y_core: [2u8; 32],
+        vdf_y_edge: [3u8; 32],
+        vdf_pi: vec![],
+        vdf_ell: vec![],
+        ticket_root: [0u8; 32],
+        part_root: [0u8; 32],
+        txroot_prev: [0u8; 32],
+    };
+    let providers = Zero;
+    let seed_commit = obex_primitives::h_tag(
+        obex_primitives::constants::TAG_SLOT_SEED,
+        &[
+            &obex_alpha_ii::obex_header_id(&parent),
+            &obex_primitives::le_bytes::<8>(u128::from(parent.slot + 1)),
+        ],
+    );
+    let h = build_header(
+        &parent,
+        (seed_commit, [5u8; 32], [6u8; 32], vec![], vec![]),
+        &providers,
+        &providers,
+        &providers,
+        OBEX_ALPHA_II_VERSION,
+    );
+    let beacon = BeaconOk;
+    c.bench_function("validate_header_ok", |b| {
+        b.iter(|| {
+            validate_header(
+                black_box(&h),
+                black_box(&parent),
+                &beacon,
+                &providers,
+                &providers,
+                &providers,
+                OBEX_ALPHA_II_VERSION,
+            )
+            .unwrap();
+        });
+    });
+}
+
+fn bench_partrec_decode(c: &mut Criterion) {
+    use obex_alpha_i::{decode_partrec, decode_partrec_view};
+    // Fully populated record (96 challenges × 4 paths × 24 siblings)
+    let bytes = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../obex_alpha_i/tests/golden/partrec_valid_v1.bin"
+    ))
+    .unwrap();
+    // Owned decode: one Vec per Merkle path (4 × 96) plus vrf_y/vrf_pi/challenges
+    let owned = allocs(|| decode_partrec(&bytes).unwrap());
+    assert!(owned >= 4 * 96 + 3, "owned decode: {owned} allocations");
+    // Borrowed decode: a single allocation (the challenge list)
+    let view = allocs(|| decode_partrec_view(&bytes).unwrap());
+    assert_eq!(view, 1, "view decode: {view} allocations");
+    println!("partrec decode allocations: owned {owned}, view {view}");
+    c.bench_function("partrec_decode_owned", |b| {
+        b.iter(|| {
+            let _ = decode_partrec(black_box(&bytes)).unwrap();
+        });
+    });
+    c.bench_function("partrec_decode_view", |b| {
+        b.iter(|| {
+            let _ = decode_partrec_view(black_box(&bytes)).unwrap();
+        });
+    });
+}
+
+fn bench_merkle_verify_path(c: &mut Criterion) {
+    use obex_primitives::{merkle_verify_leaf, merkle_verify_leaf_slice, MerklePath};
+    // One challenge opening: 24 siblings, as in a full-size label tree
+    let siblings: Vec<Hash256> = (0..24u8).map(|i| [i; 32]).collect();
+    let leaf = [7u8; 32];
+    let root = [0u8; 32];
+    // Previous shape: a fresh MerklePath (sibling Vec clone) per opened path
+    let cloned = allocs(|| {
+        let mp = MerklePath {
+            siblings: siblings.clone(),
+            index: 12_345,
+        };
+        merkle_verify_leaf(&root, &leaf, &mp)
+    });
+    assert!(cloned >= 1, "cloned path: {cloned} allocations");
+    // Borrowed siblings: no allocation per path
+    let borrowed = allocs(|| merkle_verify_leaf_slice(&root, &leaf, &siblings, 12_345));
+    assert_eq!(borrowed, 0, "slice path: {borrowed} allocations");
+    println!("merkle path verify allocations: cloned {cloned}, slice {borrowed}");
+    c.bench_function("merkle_verify_cloned_path", |b| {
+        b.iter(|| {
+            let mp = MerklePath {
+                siblings: siblings.clone(),
+                index: 12_345,
+            };
+            let _ = merkle_verify_leaf(black_box(&root), black_box(&leaf), &mp);
+        });
+    });
+    c.bench_function("merkle_verify_slice", |b| {
+        b.iter(|| {
+            let _ = merkle_verify_leaf_slice(
+                black_box(&root),
+                black_box(&leaf),
+                black_box(&siblings),
+                12_345,
+            );
+        });
+    });
+}
+
+// Drop legacy registration benches; covered by crate tests and E2E.
+
+criterion_group!(
+    benches,
+    bench_merkle_root,
+    bench_vrf_verify,
+    bench_ticket_leaf,
+    bench_ticket_root,
+    bench_validate_header,
+    bench_partrec_decode,
+    bench_merkle_verify_path
+);
+criterion_main!(benches);
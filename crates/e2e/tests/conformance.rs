@@ -1,13 +1,18 @@
 //! Conformance checks: recompute and compare golden artifacts.
 
+use obex_alpha_i::{build_participation_set, EcVrfVerifier, ObexPartRec};
 use obex_alpha_ii::{deserialize_header, obex_header_id};
 use obex_alpha_iii::{build_ticket_root_for_slot, AlphaIIIState, TicketRecord};
-use obex_alpha_i::{build_participation_set, EcVrfVerifier, ObexPartRec};
 use obex_primitives::Hash256;
 
 struct NeverVrf;
 impl EcVrfVerifier for NeverVrf {
-    fn verify(&self, _vrf_pubkey: &[u8; 32], _alpha: &Hash256, _vrf_proof: &[u8]) -> Option<Vec<u8>> {
+    fn verify(
+        &self,
+        _vrf_pubkey: &[u8; 32],
+        _alpha: &Hash256,
+        _vrf_proof: &[u8],
+    ) -> Option<Vec<u8>> {
         None
     }
 }
@@ -15,7 +20,9 @@ impl EcVrfVerifier for NeverVrf {
 #[test]
 fn header_id_matches_golden_child_if_present() {
     // Optional: skip if files not present
-    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden");
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden");
     let child = dir.join("header_v2_slot1.bin");
     if let Ok(bytes) = std::fs::read(child) {
         let h = deserialize_header(&bytes).expect("decode child");
@@ -59,5 +66,3 @@ fn participation_root_recomputes_empty() {
     let (_pks, root) = build_participation_set(slot, &parent_id, it, &vrf);
     assert_eq!(root, obex_primitives::merkle_root(&[]));
 }
-
-
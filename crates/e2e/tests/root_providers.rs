@@ -0,0 +1,153 @@
+//! `SlotRoots` (α-II concrete provider) against the hand-rolled leaf layouts the e2e
+//! harness mocks use, fed from real α-III ticket records, and its txroot against α-III
+//! `txroot_prev_from_settlement` for a settled slot.
+
+use obex_alpha_ii::{
+    build_header, part_root_from_pks, Header, PartRootProvider, SlotRoots, TicketRootProvider,
+    TxRootProvider, OBEX_ALPHA_II_VERSION,
+};
+use obex_alpha_iii::{
+    build_txroot_for_slot, enc_ticket_leaf, txroot_prev_from_settlement, AlphaIIIState,
+    TicketRecord,
+};
+use obex_alpha_t::{enc_sys_tx, settle_slot, SysTx, TokenomicsState};
+use obex_primitives::{constants, h_tag, le_bytes, merkle_root, Hash256, Pk32};
+
+fn mock_part_root(pks: &[Pk32]) -> Hash256 {
+    let leaves: Vec<Vec<u8>> = pks
+        .iter()
+        .map(|pk| {
+            let mut b = Vec::with_capacity(64);
+            b.extend_from_slice(&h_tag(constants::TAG_PART_LEAF, &[]));
+            b.extend_from_slice(pk);
+            b
+        })
+        .collect();
+    merkle_root(&leaves)
+}
+
+fn mock_ticket_root(records: &[TicketRecord]) -> Hash256 {
+    let mut sorted_records = records.to_vec();
+    sorted_records.sort_by_key(|a| a.txid);
+    let leaves: Vec<Vec<u8>> = sorted_records
+        .iter()
+        .map(|record| {
+            let mut payload = Vec::new();
+            payload.extend_from_slice(&h_tag(constants::TAG_TICKET_LEAF, &[]));
+            payload.extend_from_slice(&record.ticket_id);
+            payload.extend_from_slice(&record.txid);
+            payload.extend_from_slice(&record.sender);
+            payload.extend_from_slice(&le_bytes::<8>(u128::from(record.nonce)));
+            payload.extend_from_slice(&le_bytes::<16>(record.amount_u));
+            payload.extend_from_slice(&le_bytes::<16>(record.fee_u));
+            payload.extend_from_slice(&le_bytes::<8>(u128::from(record.s_admit)));
+            payload.extend_from_slice(&le_bytes::<8>(u128::from(record.s_exec)));
+            payload.extend_from_slice(&record.commit_hash);
+            payload
+        })
+        .collect();
+    merkle_root(&leaves)
+}
+
+fn record(i: u8) -> TicketRecord {
+    TicketRecord {
+        ticket_id: [i; 32],
+        txid: [0xF0 - i; 32],
+        sender: [i ^ 0x0F; 32],
+        nonce: u64::from(i),
+        amount_u: 1_000 * u128::from(i),
+        fee_u: 10,
+        s_admit: 5,
+        s_exec: 5,
+        commit_hash: [i.wrapping_mul(3); 32],
+    }
+}
+
+#[test]
+fn slot_roots_match_hand_rolled_mocks() {
+    let pks: Vec<Pk32> = (1u8..=5).map(|i| [i; 32]).collect();
+    let records: Vec<TicketRecord> = (1u8..=4).map(record).collect();
+    let mut sorted = records.clone();
+    sorted.sort_by_key(|r| r.txid);
+    let leaves: Vec<Vec<u8>> = sorted.iter().map(enc_ticket_leaf).collect();
+
+    let mut roots = SlotRoots::new();
+    let mut shuffled = pks.clone();
+    shuffled.reverse();
+    roots.set_part_pks(5, &shuffled);
+    roots.set_ticket_leaves(5, &leaves);
+
+    assert_eq!(roots.compute_part_root(5), mock_part_root(&pks));
+    assert_eq!(roots.compute_ticket_root(5), mock_ticket_root(&records));
+    assert_eq!(part_root_from_pks(&pks), mock_part_root(&pks));
+    // α II shares α I's builder, so a repeated pk is one participant.
+    let mut doubled = pks.clone();
+    doubled.extend_from_slice(&pks);
+    assert_eq!(
+        part_root_from_pks(&doubled),
+        obex_alpha_i::part_root_from_pks(&pks)
+    );
+    roots.set_part_pks(7, &doubled);
+    assert_eq!(roots.compute_part_root(7), mock_part_root(&pks));
+
+    let empty = h_tag(constants::TAG_MERKLE_EMPTY, &[]);
+    assert_eq!(roots.compute_part_root(6), empty);
+    assert_eq!(roots.compute_ticket_root(6), empty);
+    assert_eq!(roots.compute_txroot(5), empty);
+}
+
+#[test]
+fn slot_roots_txroot_matches_settlement_helper() {
+    let slot = 5u64;
+    let records: Vec<TicketRecord> = (1u8..=3).map(record).collect();
+    let mut st = AlphaIIIState::default();
+    for r in &records {
+        st.tickets_by_txid.insert(r.txid, r.clone());
+    }
+    let y_edge = h_tag(constants::TAG_VDF_EDGE, &[&[9u8; 32]]);
+    let amounts: Vec<u128> = records.iter().map(|r| r.amount_u).collect();
+    let mut sys_txs: Vec<SysTx> = Vec::new();
+    settle_slot(
+        slot,
+        &y_edge,
+        &amounts,
+        &[[7u8; 32]],
+        &mut TokenomicsState::default(),
+        &mut sys_txs,
+    )
+    .expect("settle slot");
+    assert!(!sys_txs.is_empty());
+    let sys_leaves: Vec<Vec<u8>> = sys_txs.iter().map(enc_sys_tx).collect();
+
+    let mut roots = SlotRoots::new();
+    roots.set_txroot(slot, build_txroot_for_slot(slot, &st, &sys_leaves).1);
+
+    let parent = Header {
+        parent_id: [0u8; 32],
+        slot,
+        obex_version: OBEX_ALPHA_II_VERSION,
+        seed_commit: [0u8; 32],
+        vdf_y_core: [0u8; 32],
+        vdf_y_edge: y_edge,
+        vdf_pi: vec![],
+        vdf_ell: vec![],
+        ticket_root: merkle_root(&[]),
+        part_root: merkle_root(&[]),
+        txroot_prev: merkle_root(&[]),
+    };
+    let child = build_header(
+        &parent,
+        ([0u8; 32], [0u8; 32], [0u8; 32], vec![], vec![]),
+        &roots,
+        &roots,
+        &roots,
+        OBEX_ALPHA_II_VERSION,
+    );
+    let mut shuffled = records;
+    shuffled.reverse();
+    assert_eq!(
+        child.txroot_prev,
+        txroot_prev_from_settlement(slot, &shuffled, &sys_leaves)
+    );
+    assert_ne!(child.txroot_prev, build_txroot_for_slot(slot, &st, &[]).1);
+}
@@ -3,7 +3,8 @@
 //! This test implements the full protocol pipeline as described in solutions B7:
 //! - s−1 settlement: produces α-I proofs targeting s
 //! - s finality: builds `P_s/part_root_s`, admits txs → `ticket_root_s`, recomputes txroot_{s−1}, builds Header s; validates
-//! - s settlement: runs α-T (escrow, splits, emission, DRP), emits system tx, computes `txroot_s`
+//! - s settlement: runs α-T `settle_slot` (escrow, splits, emission, DRP), computes `txroot_s`
+//!   over the executed txids followed by the emitted system txs
 //! - s+1 finality: builds Header s+1 committing `txroot_s`; validates
 //! - Asserts only one valid header for the fixed (parent, s)
 
@@ -15,6 +16,7 @@ use obex_alpha_ii::{
 use obex_alpha_iii::{
     admit_slot_canonical, fee_int_uobx, AccessList, AlphaIIIState, Sig, TicketRecord, TxBodyV1,
 };
+use obex_alpha_t::{enc_sys_tx, settle_slot, SysTx, SysTxKind, TokenomicsState};
 use obex_primitives::{constants, h_tag, le_bytes, merkle_root, Hash256, Pk32};
 use std::collections::HashMap;
 
@@ -82,7 +84,7 @@ impl TicketRootProvider for MockProviders {
             .get(&slot)
             .map_or_else(empty_root, |records| {
                 let mut sorted_records = records.clone();
-                sorted_records.sort_by(|a, b| a.txid.cmp(&b.txid));
+                sorted_records.sort_by_key(|a| a.txid);
                 let leaves: Vec<Vec<u8>> = sorted_records
                     .iter()
                     .map(|record| {
@@ -204,6 +206,7 @@ fn three_slot_end_to_end_pipeline() {
 
     let parent = mk_parent();
     let mut h_prev = parent;
+    let mut tokenomics = TokenomicsState::default();
 
     // Process 3 slots in the pipeline
     for slot in 1..=3u64 {
@@ -278,10 +281,24 @@ fn three_slot_end_to_end_pipeline() {
         )
         .is_ok());
 
-        // === s settlement: simplified α-T integration ===
+        // === s settlement: α-T (escrow, splits, emission, DRP) → system txs ===
 
-        // For this test, we'll create a simple mock txroot based on admitted tickets
-        // In a real implementation, this would involve complex α-T settlement logic
+        let transfers: Vec<u128> = admitted_tickets.iter().map(|t| t.amount_u).collect();
+        let mut part_sorted = part_pks.clone();
+        part_sorted.sort_unstable();
+        let mut sys_txs: Vec<SysTx> = Vec::new();
+        settle_slot(
+            slot,
+            &y_edge,
+            &transfers,
+            &part_sorted,
+            &mut tokenomics,
+            &mut sys_txs,
+        )
+        .expect("settle slot");
+        assert!(sys_txs.iter().any(|t| t.kind == SysTxKind::EmissionCredit));
+
+        // txroot_s: executed user txids, then system txs in canonical order
         let tx_leaves: Vec<Vec<u8>> = admitted_tickets
             .iter()
             .map(|ticket| {
@@ -290,6 +307,7 @@ fn three_slot_end_to_end_pipeline() {
                 payload.extend_from_slice(&ticket.txid);
                 payload
             })
+            .chain(sys_txs.iter().map(enc_sys_tx))
             .collect();
 
         let txroot_s = if tx_leaves.is_empty() {
@@ -319,7 +337,10 @@ fn three_slot_end_to_end_pipeline() {
             &le_bytes::<8>(u128::from(final_slot)),
         ],
     );
-    let y_core_final = h_tag(constants::TAG_VDF_YCORE, &[&[u8::try_from(final_slot).unwrap_or(0); 32]]);
+    let y_core_final = h_tag(
+        constants::TAG_VDF_YCORE,
+        &[&[u8::try_from(final_slot).unwrap_or(0); 32]],
+    );
     let y_edge_final = h_tag(constants::TAG_VDF_EDGE, &[&y_core_final]);
 
     let header_final = build_header(
@@ -389,7 +410,10 @@ fn three_slot_end_to_end_pipeline() {
     );
 
     println!("3-slot end-to-end pipeline completed successfully!");
-    println!("Final header ID: {:?}", hex::encode(obex_header_id(&header_final)));
+    println!(
+        "Final header ID: {:?}",
+        hex::encode(obex_header_id(&header_final))
+    );
 }
 
 #[test]
@@ -409,7 +433,10 @@ fn pipeline_determinism_across_runs() {
                 constants::TAG_SLOT_SEED,
                 &[&obex_header_id(&h_prev), &le_bytes::<8>(u128::from(slot))],
             );
-            let y_core = h_tag(constants::TAG_VDF_YCORE, &[&[u8::try_from(slot).unwrap_or(0); 32]]);
+            let y_core = h_tag(
+                constants::TAG_VDF_YCORE,
+                &[&[u8::try_from(slot).unwrap_or(0); 32]],
+            );
             let y_edge = h_tag(constants::TAG_VDF_EDGE, &[&y_core]);
 
             let header = build_header(
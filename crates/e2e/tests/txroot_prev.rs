@@ -0,0 +1,97 @@
+//! α-III `txroot_prev_from_settlement` against the header a child of a one-transaction slot
+//! commits, with the parent slot's txroot built the way the slot pipeline builds it.
+
+use obex_alpha_ii::{build_header, Header, SlotRoots, TxRootProvider, OBEX_ALPHA_II_VERSION};
+use obex_alpha_iii::{txroot_prev_from_settlement, TicketRecord};
+use obex_alpha_t::{enc_sys_tx, settle_slot, SysTx, TokenomicsState};
+use obex_primitives::{constants, h_tag, merkle_root, Hash256};
+
+/// Parent slot's txroot: executed txids, then system txs in emission order.
+struct PipelineTxRoot {
+    slot: u64,
+    txids: Vec<Hash256>,
+    sys_txs: Vec<SysTx>,
+}
+
+impl TxRootProvider for PipelineTxRoot {
+    fn compute_txroot(&self, slot: u64) -> Hash256 {
+        assert_eq!(slot, self.slot);
+        let leaves: Vec<Vec<u8>> = self
+            .txids
+            .iter()
+            .map(|id| {
+                let mut payload = Vec::new();
+                payload.extend_from_slice(&h_tag(constants::TAG_TXID_LEAF, &[]));
+                payload.extend_from_slice(id);
+                payload
+            })
+            .chain(self.sys_txs.iter().map(enc_sys_tx))
+            .collect();
+        merkle_root(&leaves)
+    }
+}
+
+#[test]
+fn child_header_commits_helper_txroot_prev() {
+    let slot = 1u64;
+    let ticket = TicketRecord {
+        ticket_id: [1u8; 32],
+        txid: [2u8; 32],
+        sender: [3u8; 32],
+        nonce: 0,
+        amount_u: 5_000,
+        fee_u: 50,
+        s_admit: slot,
+        s_exec: slot,
+        commit_hash: [4u8; 32],
+    };
+    let y_edge = h_tag(constants::TAG_VDF_EDGE, &[&[9u8; 32]]);
+    let mut sys_txs: Vec<SysTx> = Vec::new();
+    settle_slot(
+        slot,
+        &y_edge,
+        &[ticket.amount_u],
+        &[[7u8; 32]],
+        &mut TokenomicsState::default(),
+        &mut sys_txs,
+    )
+    .expect("settle slot");
+    assert!(!sys_txs.is_empty());
+
+    let parent = Header {
+        parent_id: [0u8; 32],
+        slot,
+        obex_version: OBEX_ALPHA_II_VERSION,
+        seed_commit: [0u8; 32],
+        vdf_y_core: [0u8; 32],
+        vdf_y_edge: y_edge,
+        vdf_pi: vec![],
+        vdf_ell: vec![],
+        ticket_root: merkle_root(&[]),
+        part_root: merkle_root(&[]),
+        txroot_prev: merkle_root(&[]),
+    };
+    let provider = PipelineTxRoot {
+        slot,
+        txids: vec![ticket.txid],
+        sys_txs: sys_txs.clone(),
+    };
+    let roots = SlotRoots::new();
+    let child = build_header(
+        &parent,
+        ([0u8; 32], [0u8; 32], [0u8; 32], vec![], vec![]),
+        &roots,
+        &roots,
+        &provider,
+        OBEX_ALPHA_II_VERSION,
+    );
+
+    let sys_leaves: Vec<Vec<u8>> = sys_txs.iter().map(enc_sys_tx).collect();
+    let mut late = ticket.clone();
+    late.txid = [0xEE; 32];
+    late.s_exec = slot + 1;
+    assert_eq!(
+        txroot_prev_from_settlement(slot, &[late, ticket], &sys_leaves),
+        child.txroot_prev
+    );
+}
@@ -19,10 +19,11 @@
 //! `obex.alpha I.txt`. Cryptographic primitives (Ed25519 and ECVRF) are
 //! integrated via vetted crates or pluggable trait providers.
 
-use ed25519_dalek::{Signature, VerifyingKey};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
 use obex_primitives::{
-    consensus, ct_eq_hash, le_bytes, merkle_root, merkle_verify_leaf, u64_from_le, Hash256, Pk32,
-    Sig64,
+    consensus, ct_eq_bytes, ct_eq_hash, le_bytes, le_len4, merkle_depth, merkle_leaf, merkle_node,
+    merkle_path_root, merkle_root, merkle_verify_leaf, merkle_verify_leaf_at_depth, u64_from_le,
+    Hash256, LenPrefixOverflow, MerklePath, Pk32, Sig64,
 };
 use thiserror::Error;
 // Anchor to ensure SHA3-256 presence without underscore-binding side effects.
@@ -32,10 +33,25 @@ pub use obex_primitives::OBEX_SHA3_256_ANCHOR as _obex_sha3_anchor_i;
 pub const OBEX_ALPHA_I_VERSION: u32 = 1;
 pub const MEM_MIB: usize = 512; // target RAM per prover instance
 pub const LABEL_BYTES: usize = 32; // SHA3-256 width
-pub const N_LABELS: usize = (MEM_MIB * 1_048_576) / LABEL_BYTES; // 16,777,216
+pub const N_LABELS: usize = labels_for_mib(MEM_MIB); // 16,777,216
 pub const PASSES: u32 = 3; // diffusion passes
 pub const CHALLENGES_Q: usize = 96; // deterministic Q=96, residual cheat ≈ 2^-96
 pub const MAX_PARTREC_SIZE: usize = consensus::MAX_PARTREC_SIZE; // DoS cap on serialized proof
+/// Depth of the label Merkle tree; every challenge path has exactly this many siblings.
+pub const LABEL_TREE_DEPTH: usize = merkle_depth(N_LABELS);
+/// Decode cap on each challenge path: `ceil(log2(N_LABELS)) + 1` siblings.
+pub const MAX_PATH_SIBLINGS: usize = N_LABELS.next_power_of_two().trailing_zeros() as usize + 1;
+
+/// Number of `LABEL_BYTES` labels filling `mib` MiB: `mib · 2^20 / LABEL_BYTES`.
+///
+/// Forks changing `MEM_MIB` must keep the label array an exact multiple of `LABEL_BYTES` with at
+/// least two labels (`chal_index` draws from `[1, N_LABELS)`); both are checked at compile time.
+#[must_use]
+pub const fn labels_for_mib(mib: usize) -> usize {
+    (mib * 1_048_576) / LABEL_BYTES
+}
+const _: () = assert!((MEM_MIB * 1_048_576).is_multiple_of(LABEL_BYTES));
+const _: () = assert!(N_LABELS >= 2);
 
 /// VRF public key type (Ed25519 curve per RFC 9381 ECVRF-EDWARDS25519-SHA512-TAI)
 pub type VrfPk32 = [u8; 32];
@@ -72,7 +88,7 @@ pub struct ObexPartRec {
     pub vrf_pk: VrfPk32,
     pub y_edge_prev: Hash256,
     pub alpha: Hash256,
-    pub vrf_y: Vec<u8>,  // 64 or 32 bytes (network-wide fixed)
+    pub vrf_y: Vec<u8>,  // VRF_Y_LEN bytes (network-wide fixed)
     pub vrf_pi: Vec<u8>, // RFC 9381
     pub seed: Hash256,
     pub root: Hash256,
@@ -82,7 +98,8 @@ pub struct ObexPartRec {
 
 /// VRF verifier provider interface (pluggable for RFC 9381 ECVRF)
 pub trait EcVrfVerifier {
-    /// Verify (`vrf_pk`, `alpha`, `vrf_pi`) and return canonical `vrf_y` bytes (64 or network rehash 32).
+    /// Verify (`vrf_pk`, `alpha`, `vrf_pi`) and return the canonical `vrf_y`, exactly
+    /// `VRF_Y_LEN` bytes (raw 64-byte β, or the 32-byte network rehash).
     fn verify(&self, vrf_pubkey: &VrfPk32, alpha: &Hash256, vrf_proof: &[u8]) -> Option<Vec<u8>>;
 }
 
@@ -97,14 +114,17 @@ fn obex_alpha(parent_id: &Hash256, slot: u64, y_prev: &Hash256, vrf_pk: &VrfPk32
     )
 }
 
+/// Label-chain seed `H("obex.seed",[y_prev, pk, vrf_y])`, as recomputed by the verifier.
 #[inline]
-fn obex_seed(y_prev: &Hash256, pk: &Pk32, vrf_y: &[u8]) -> Hash256 {
+#[must_use]
+pub fn obex_seed(y_prev: &Hash256, pk: &Pk32, vrf_y: &[u8]) -> Hash256 {
     consensus::h_tag("obex.seed", &[y_prev, pk, vrf_y])
 }
 
+/// First label `L[0] = H("obex.l0",[seed])`; the label chain of `generate_labels` starts here.
 #[inline]
-#[allow(dead_code)]
-fn lbl0(seed: &Hash256) -> Hash256 {
+#[must_use]
+pub fn lbl0(seed: &Hash256) -> Hash256 {
     consensus::h_tag("obex.l0", &[seed])
 }
 
@@ -224,34 +244,249 @@ pub fn obex_check_partrec(
     parent_id: &Hash256,
     vrf: &impl EcVrfVerifier,
 ) -> Result<(), VerifyErr> {
-    if rec.version != OBEX_ALPHA_I_VERSION {
+    obex_check_partrec_detailed(rec, slot, parent_id, vrf).map_err(|(e, _)| e)
+}
+
+/// `obex_check_partrec` with a deployment-chosen challenge count `q` (residual cheat ≈ 2^-q).
+///
+/// Requires exactly `q` openings, checked against the first `q` indices of `chal_index`.
+/// Consensus uses `CHALLENGES_Q`; the canonical codec only carries that count.
+pub fn obex_check_partrec_q(
+    rec: &ObexPartRec,
+    slot: u64,
+    parent_id: &Hash256,
+    vrf: &impl EcVrfVerifier,
+    q: usize,
+) -> Result<(), VerifyErr> {
+    check_owned(rec, slot, parent_id, vrf, q).map_err(|(e, _)| e)
+}
+
+/// As `obex_check_partrec`, additionally reporting the challenge ordinal `t` when the failure
+/// occurs inside the challenge loop (`None` for transcript-level failures).
+pub fn obex_check_partrec_detailed(
+    rec: &ObexPartRec,
+    slot: u64,
+    parent_id: &Hash256,
+    vrf: &impl EcVrfVerifier,
+) -> Result<(), (VerifyErr, Option<u32>)> {
+    check_owned(rec, slot, parent_id, vrf, CHALLENGES_Q)
+}
+
+fn check_owned(
+    rec: &ObexPartRec,
+    slot: u64,
+    parent_id: &Hash256,
+    vrf: &impl EcVrfVerifier,
+    q: usize,
+) -> Result<(), (VerifyErr, Option<u32>)> {
+    let r = RecRef {
+        version: rec.version,
+        slot: rec.slot,
+        pk: &rec.pk_ed25519,
+        vrf_pk: &rec.vrf_pk,
+        y_edge_prev: &rec.y_edge_prev,
+        alpha: &rec.alpha,
+        vrf_y: &rec.vrf_y,
+        vrf_pi: &rec.vrf_pi,
+        seed: &rec.seed,
+        root: &rec.root,
+        sig: &rec.sig,
+        n_challenges: rec.challenges.len(),
+        expected_q: q,
+    };
+    let chals = rec.challenges.iter().map(|ch| ChalRef {
+        idx: ch.idx,
+        li: &ch.li,
+        pi: &ch.pi.siblings,
+        lim1: &ch.lim1,
+        pim1: &ch.pim1.siblings,
+        lj: &ch.lj,
+        pj: &ch.pj.siblings,
+        lk: &ch.lk,
+        pk_: &ch.pk_.siblings,
+    });
+    check_rec(&r, chals, slot, parent_id, vrf)
+}
+
+/// Borrowed record fields shared by the owned (`ObexPartRec`) and view (`PartRecView`) paths.
+struct RecRef<'a> {
+    version: u32,
+    slot: u64,
+    pk: &'a Pk32,
+    vrf_pk: &'a VrfPk32,
+    y_edge_prev: &'a Hash256,
+    alpha: &'a Hash256,
+    vrf_y: &'a [u8],
+    vrf_pi: &'a [u8],
+    seed: &'a Hash256,
+    root: &'a Hash256,
+    sig: &'a Sig64,
+    n_challenges: usize,
+    expected_q: usize,
+}
+
+/// Borrowed challenge opening; sibling paths are concatenated 32-byte hashes.
+struct ChalRef<'a> {
+    idx: u64,
+    li: &'a Hash256,
+    pi: &'a [Hash256],
+    lim1: &'a Hash256,
+    pim1: &'a [Hash256],
+    lj: &'a Hash256,
+    pj: &'a [Hash256],
+    lk: &'a Hash256,
+    pk_: &'a [Hash256],
+}
+
+fn check_rec<'a>(
+    r: &RecRef<'_>,
+    chals: impl Iterator<Item = ChalRef<'a>>,
+    slot: u64,
+    parent_id: &Hash256,
+    vrf: &impl EcVrfVerifier,
+) -> Result<(), (VerifyErr, Option<u32>)> {
+    check_transcript(r, slot, parent_id, vrf).map_err(|e| (e, None))?;
+    for (t, ch) in chals.enumerate() {
+        let Ok(t_u32) = u32::try_from(t) else {
+            return Err((VerifyErr::ChalIndexBounds, None));
+        };
+        check_challenge(r, &ch, t_u32).map_err(|e| (e, Some(t_u32)))?;
+    }
+    Ok(())
+}
+
+/// Steps 0–3: version/slot/count, VRF, seed, and transcript signature.
+fn check_transcript(
+    r: &RecRef<'_>,
+    slot: u64,
+    parent_id: &Hash256,
+    vrf: &impl EcVrfVerifier,
+) -> Result<(), VerifyErr> {
+    if r.version != OBEX_ALPHA_I_VERSION {
         return Err(VerifyErr::VersionMismatch);
     }
-    if rec.slot != slot {
+    if r.slot != slot {
         return Err(VerifyErr::SlotMismatch);
     }
-    if rec.challenges.len() != CHALLENGES_Q {
+    if r.n_challenges != r.expected_q {
         return Err(VerifyErr::ChallengesLen);
     }
 
     // 1) VRF
-    let alpha = obex_alpha(parent_id, slot, &rec.y_edge_prev, &rec.vrf_pk);
-    if !ct_eq_hash(&alpha, &rec.alpha) {
+    let alpha = obex_alpha(parent_id, slot, r.y_edge_prev, r.vrf_pk);
+    if !ct_eq_hash(&alpha, r.alpha) {
         return Err(VerifyErr::AlphaMismatch);
     }
-    let Some(vrf_y_check) = vrf.verify(&rec.vrf_pk, &alpha, &rec.vrf_pi) else {
+    let Some(vrf_y_check) = vrf.verify(r.vrf_pk, &alpha, r.vrf_pi) else {
         return Err(VerifyErr::VrfVerifyFailed);
     };
-    if vrf_y_check.as_slice() != rec.vrf_y.as_slice() {
+    if !ct_eq_bytes(&vrf_y_check, r.vrf_y) {
         return Err(VerifyErr::VrfOutputMismatch);
     }
 
     // 2) Seed
-    let seed_expected = obex_seed(&rec.y_edge_prev, &rec.pk_ed25519, &rec.vrf_y);
-    if !ct_eq_hash(&seed_expected, &rec.seed) {
+    let seed_expected = obex_seed(r.y_edge_prev, r.pk, r.vrf_y);
+    if !ct_eq_hash(&seed_expected, r.seed) {
         return Err(VerifyErr::SeedMismatch);
     }
 
+    // 3) Signature
+    let msg = partrec_msg(&TranscriptParts {
+        version: r.version,
+        slot: r.slot,
+        pk: r.pk,
+        vrf_pk: r.vrf_pk,
+        y_prev: r.y_edge_prev,
+        alpha: r.alpha,
+        vrf_y: r.vrf_y,
+        root: r.root,
+    });
+    if !verify_sig(r.pk, &msg, r.sig) {
+        return Err(VerifyErr::SigInvalid);
+    }
+    Ok(())
+}
+
+/// Merkle inclusion of label `leaf` at `index` over borrowed siblings, at `LABEL_TREE_DEPTH`.
+fn verify_path(root: &Hash256, leaf: &Hash256, siblings: &[Hash256], index: u64) -> bool {
+    merkle_verify_leaf_at_depth(root, leaf, siblings, index, LABEL_TREE_DEPTH)
+}
+
+/// Step 4 for challenge ordinal `t`: index derivation, Merkle openings, label equation.
+fn check_challenge(r: &RecRef<'_>, ch: &ChalRef<'_>, t_u32: u32) -> Result<(), VerifyErr> {
+    let last_pass = PASSES - 1;
+    let i = chal_index(r.y_edge_prev, r.root, r.vrf_y, t_u32);
+    if ch.idx != i {
+        return Err(VerifyErr::ChalIndexMismatch);
+    }
+    if !(i > 0 && usize::try_from(i).is_ok_and(|ii| ii < N_LABELS)) {
+        return Err(VerifyErr::ChalIndexBounds);
+    }
+
+    let j = idx_j(r.seed, i, last_pass);
+    let k = idx_k(r.seed, i, last_pass);
+    if !(j < i && k < i) {
+        return Err(VerifyErr::JOrKOutOfRange);
+    }
+
+    // Merkle paths
+    if !verify_path(r.root, ch.li, ch.pi, i) {
+        return Err(VerifyErr::MerkleLiInvalid);
+    }
+    if !verify_path(r.root, ch.lim1, ch.pim1, i - 1) {
+        return Err(VerifyErr::MerkleLim1Invalid);
+    }
+    if !verify_path(r.root, ch.lj, ch.pj, j) {
+        return Err(VerifyErr::MerkleLjInvalid);
+    }
+    if !verify_path(r.root, ch.lk, ch.pk_, k) {
+        return Err(VerifyErr::MerkleLkInvalid);
+    }
+
+    // Label equation
+    let li_check = label_update(r.seed, i, ch.lim1, ch.lj, ch.lk);
+    if !ct_eq_hash(&li_check, ch.li) {
+        return Err(VerifyErr::LabelEquationMismatch);
+    }
+    Ok(())
+}
+
+/// Constant-time-combine variant of `obex_check_partrec` for adversarial settings.
+///
+/// Every check (version, slot, challenge count, alpha, VRF output, seed, signature, and for
+/// each supplied challenge the index, all four Merkle openings and the label equation) is
+/// evaluated; hash comparisons use `subtle` and the outcomes are folded with a constant-time
+/// AND into a single bool. Guarantees are limited to that combine: the amount of hashing still
+/// depends on public sizes (number of challenges, Merkle path lengths, `vrf_y` length), the
+/// VRF provider and Ed25519 verification run in their own time, and `vrf_y` lengths are
+/// compared before contents. Accept/reject always agrees with `obex_check_partrec`.
+#[must_use]
+pub fn obex_check_partrec_ct(
+    rec: &ObexPartRec,
+    slot: u64,
+    parent_id: &Hash256,
+    vrf: &impl EcVrfVerifier,
+) -> bool {
+    use subtle::{Choice, ConstantTimeEq};
+
+    let mut ok = rec.version.ct_eq(&OBEX_ALPHA_I_VERSION);
+    ok &= rec.slot.ct_eq(&slot);
+    ok &= (rec.challenges.len() as u64).ct_eq(&(CHALLENGES_Q as u64));
+
+    // 1) VRF
+    let alpha = obex_alpha(parent_id, slot, &rec.y_edge_prev, &rec.vrf_pk);
+    ok &= alpha.ct_eq(&rec.alpha);
+    let vrf_y_check = vrf.verify(&rec.vrf_pk, &alpha, &rec.vrf_pi);
+    ok &= Choice::from(u8::from(vrf_y_check.is_some()));
+    ok &= vrf_y_check
+        .unwrap_or_default()
+        .as_slice()
+        .ct_eq(rec.vrf_y.as_slice());
+
+    // 2) Seed
+    let seed_expected = obex_seed(&rec.y_edge_prev, &rec.pk_ed25519, &rec.vrf_y);
+    ok &= seed_expected.ct_eq(&rec.seed);
+
     // 3) Signature
     let msg = partrec_msg(&TranscriptParts {
         version: rec.version,
@@ -263,79 +498,28 @@ pub fn obex_check_partrec(
         vrf_y: &rec.vrf_y,
         root: &rec.root,
     });
-    if !verify_sig(&rec.pk_ed25519, &msg, &rec.sig) {
-        return Err(VerifyErr::SigInvalid);
-    }
+    ok &= Choice::from(u8::from(verify_sig(&rec.pk_ed25519, &msg, &rec.sig)));
 
-    // 4) Challenges
+    // 4) Challenges (derived i ∈ [1, N_LABELS) and J/K < i hold by construction)
     let last_pass = PASSES - 1;
-    for (t, ch) in rec.challenges.iter().enumerate() {
-        let Ok(t_u32) = u32::try_from(t) else {
-            return Err(VerifyErr::ChalIndexBounds);
-        };
+    for (t, ch) in rec.challenges.iter().take(CHALLENGES_Q).enumerate() {
+        let t_u32 = u32::try_from(t).unwrap_or(u32::MAX);
         let i = chal_index(&rec.y_edge_prev, &rec.root, &rec.vrf_y, t_u32);
-        if ch.idx != i {
-            return Err(VerifyErr::ChalIndexMismatch);
-        }
-        if !(i > 0 && usize::try_from(i).is_ok_and(|ii| ii < N_LABELS)) {
-            return Err(VerifyErr::ChalIndexBounds);
-        }
-
+        ok &= ch.idx.ct_eq(&i);
         let j = idx_j(&rec.seed, i, last_pass);
         let k = idx_k(&rec.seed, i, last_pass);
-        if !(j < i && k < i) {
-            return Err(VerifyErr::JOrKOutOfRange);
-        }
-
-        // Merkle paths
-        if !merkle_verify_leaf(
-            &rec.root,
-            &ch.li,
-            &obex_primitives::MerklePath {
-                siblings: ch.pi.siblings.clone(),
-                index: i,
-            },
-        ) {
-            return Err(VerifyErr::MerkleLiInvalid);
-        }
-        if !merkle_verify_leaf(
-            &rec.root,
-            &ch.lim1,
-            &obex_primitives::MerklePath {
-                siblings: ch.pim1.siblings.clone(),
-                index: i - 1,
-            },
-        ) {
-            return Err(VerifyErr::MerkleLim1Invalid);
-        }
-        if !merkle_verify_leaf(
-            &rec.root,
-            &ch.lj,
-            &obex_primitives::MerklePath {
-                siblings: ch.pj.siblings.clone(),
-                index: j,
-            },
-        ) {
-            return Err(VerifyErr::MerkleLjInvalid);
-        }
-        if !merkle_verify_leaf(
-            &rec.root,
-            &ch.lk,
-            &obex_primitives::MerklePath {
-                siblings: ch.pk_.siblings.clone(),
-                index: k,
-            },
-        ) {
-            return Err(VerifyErr::MerkleLkInvalid);
-        }
-
-        // Label equation
-        let li_check = label_update(&rec.seed, i, &ch.lim1, &ch.lj, &ch.lk);
-        if !ct_eq_hash(&li_check, &ch.li) {
-            return Err(VerifyErr::LabelEquationMismatch);
+        for (leaf, path, index) in [
+            (&ch.li, &ch.pi, i),
+            (&ch.lim1, &ch.pim1, i - 1),
+            (&ch.lj, &ch.pj, j),
+            (&ch.lk, &ch.pk_, k),
+        ] {
+            ok &= Choice::from(u8::from(path.siblings.len() == LABEL_TREE_DEPTH));
+            ok &= merkle_path_root(leaf, &path.siblings, index).ct_eq(&rec.root);
         }
+        ok &= label_update(&rec.seed, i, &ch.lim1, &ch.lj, &ch.lk).ct_eq(&ch.li);
     }
-    Ok(())
+    ok.into()
 }
 
 /// Verify a received `ObexPartRec` for target slot `slot`.
@@ -349,7 +533,37 @@ pub fn obex_verify_partrec(
     obex_check_partrec(rec, slot, parent_id, vrf).is_ok()
 }
 
+/// Verify a batch of records for target slot `slot`, one precise result per input (no dedup).
+///
+/// With the `rayon` feature enabled records are verified in parallel; result order always
+/// matches `recs`.
+pub fn obex_verify_partrecs_batch(
+    recs: &[ObexPartRec],
+    slot: u64,
+    parent_id: &Hash256,
+    vrf: &(impl EcVrfVerifier + Sync),
+) -> Vec<Result<(), VerifyErr>> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        recs.par_iter()
+            .map(|rec| obex_check_partrec(rec, slot, parent_id, vrf))
+            .collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        recs.iter()
+            .map(|rec| obex_check_partrec(rec, slot, parent_id, vrf))
+            .collect()
+    }
+}
+
 /// Build the participation set `P_s` and its commitment root for a slot, given an iterator of submissions.
+///
+/// `P_s` is returned sorted ascending by the raw 32-byte pk, compared lexicographically (i.e. as
+/// a big-endian integer), and `part_root` is the Merkle root over `part_leaf(pk)` in that order;
+/// the result is independent of submission order. This ordering is consensus-critical: see
+/// `part_root_from_pks`.
 #[must_use]
 pub fn build_participation_set<'a>(
     slot: u64,
@@ -357,42 +571,412 @@ pub fn build_participation_set<'a>(
     submissions: impl Iterator<Item = &'a ObexPartRec>,
     vrf: &impl EcVrfVerifier,
 ) -> (Vec<Pk32>, Hash256) {
+    let (pks, _leaves, part_root) =
+        build_participation_set_with_leaves(slot, parent_id, submissions, vrf);
+    (pks, part_root)
+}
+
+/// As `build_participation_set`, also returning the `part_root` leaf payloads (one
+/// `part_leaf(pk)` per entry of `P_s`, same order) for `merkle_prove`.
+#[must_use]
+pub fn build_participation_set_with_leaves<'a>(
+    slot: u64,
+    parent_id: &Hash256,
+    submissions: impl Iterator<Item = &'a ObexPartRec>,
+    vrf: &impl EcVrfVerifier,
+) -> (Vec<Pk32>, Vec<Vec<u8>>, Hash256) {
+    let (pks, _stats) = collect_participants(slot, parent_id, submissions, vrf);
+    let leaves: Vec<Vec<u8>> = pks.iter().map(part_leaf).collect();
+    let part_root = merkle_root(&leaves);
+
+    (pks, leaves, part_root)
+}
+
+/// Why submissions to `build_participation_set_report` were kept or dropped.
+///
+/// Every submission lands in exactly one bucket, checked in this order: wrong slot, pk already
+/// accepted, verification failed, accepted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SubmissionStats {
+    pub accepted: usize,
+    pub wrong_slot: usize,
+    pub duplicate_pk: usize,
+    pub verify_failed: usize,
+}
+
+/// As `build_participation_set`, also counting dropped submissions by reason.
+#[must_use]
+pub fn build_participation_set_report<'a>(
+    slot: u64,
+    parent_id: &Hash256,
+    submissions: impl Iterator<Item = &'a ObexPartRec>,
+    vrf: &impl EcVrfVerifier,
+) -> (Vec<Pk32>, Hash256, SubmissionStats) {
+    let (pks, stats) = collect_participants(slot, parent_id, submissions, vrf);
+    let part_root = participation_root(&pks);
+    (pks, part_root, stats)
+}
+
+/// Sorted `P_s` (first verifying submission per pk) and per-reason submission counts.
+fn collect_participants<'a>(
+    slot: u64,
+    parent_id: &Hash256,
+    submissions: impl Iterator<Item = &'a ObexPartRec>,
+    vrf: &impl EcVrfVerifier,
+) -> (Vec<Pk32>, SubmissionStats) {
     use std::collections::BTreeSet;
     let mut seen: BTreeSet<Pk32> = BTreeSet::new();
     let mut pks: Vec<Pk32> = Vec::new();
+    let mut stats = SubmissionStats::default();
 
     for rec in submissions {
         if rec.slot != slot {
+            stats.wrong_slot += 1;
             continue;
         }
         if seen.contains(&rec.pk_ed25519) {
+            stats.duplicate_pk += 1;
             continue;
         }
         if obex_verify_partrec(rec, slot, parent_id, vrf) {
             seen.insert(rec.pk_ed25519);
             pks.push(rec.pk_ed25519);
+            stats.accepted += 1;
+        } else {
+            stats.verify_failed += 1;
         }
     }
+    // pks are unique, so an unstable sort yields the one canonical ascending order.
     pks.sort_unstable();
+    (pks, stats)
+}
 
-    // part_root = Merkle over H("obex.part.leaf",[]) || pk
-    let leaves: Vec<Vec<u8>> = pks
-        .iter()
-        .map(|pk| {
-            let mut b = Vec::with_capacity(32 + 32);
-            b.extend_from_slice(&consensus::h_tag("obex.part.leaf", &[]));
-            b.extend_from_slice(pk);
-            b
+/// `part_root` leaf payload: `H("obex.part.leaf",[]) || pk`.
+#[must_use]
+pub fn part_leaf(pk: &Pk32) -> Vec<u8> {
+    let mut b = Vec::with_capacity(32 + 32);
+    b.extend_from_slice(&consensus::h_tag("obex.part.leaf", &[]));
+    b.extend_from_slice(pk);
+    b
+}
+
+/// Light-client check that `pk` is in the participation set committed by `part_root`.
+///
+/// Rebuilds `part_leaf(pk)` and verifies `proof` (as produced by `merkle_prove` over the leaves
+/// from `build_participation_set_with_leaves`) against the header's `part_root`.
+#[must_use]
+pub fn verify_part_inclusion(part_root: &Hash256, pk: &Pk32, proof: &MerklePath) -> bool {
+    merkle_verify_leaf(part_root, &part_leaf(pk), proof)
+}
+
+/// `part_root` over an arbitrary set of pks: sorted ascending by raw bytes (big-endian value),
+/// leaf = `part_leaf(pk)`. Equals the root `build_participation_set` reports for that `P_s`.
+#[must_use]
+pub fn part_root_from_pks(pks: &[Pk32]) -> Hash256 {
+    let mut sorted = pks.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    participation_root(&sorted)
+}
+
+/// `part_root` over a `P_s` whose records were already verified as they arrived (e.g. through
+/// `ParticipationAccumulator` or `obex_verify_partrecs_batch`): no re-verification, no sort.
+///
+/// `pks` must be strictly ascending, as `build_participation_set` returns them (debug-asserted).
+#[must_use]
+pub fn part_root_from_verified(pks: &[Pk32]) -> Hash256 {
+    debug_assert!(
+        pks.windows(2).all(|w| w[0] < w[1]),
+        "pks must be sorted and deduplicated"
+    );
+    participation_root(pks)
+}
+
+/// `part_root` = Merkle over `part_leaf(pk)` for `pks` in the given order.
+fn participation_root(pks: &[Pk32]) -> Hash256 {
+    let leaves: Vec<Vec<u8>> = pks.iter().map(part_leaf).collect();
+    merkle_root(&leaves)
+}
+
+/// Participation set for one slot built up as submissions arrive.
+///
+/// Accepts the same records as `build_participation_set` (slot match, first record per `pk`,
+/// verified) and keeps `pks` sorted, so `root()` equals the batch `part_root` over the
+/// records accepted so far. The root is cached until the next insertion.
+pub struct ParticipationAccumulator<'v, V: EcVrfVerifier> {
+    slot: u64,
+    parent_id: Hash256,
+    vrf: &'v V,
+    pks: Vec<Pk32>,
+    root: Option<Hash256>,
+}
+
+impl<'v, V: EcVrfVerifier> ParticipationAccumulator<'v, V> {
+    #[must_use]
+    pub const fn new(slot: u64, parent_id: Hash256, vrf: &'v V) -> Self {
+        Self {
+            slot,
+            parent_id,
+            vrf,
+            pks: Vec::new(),
+            root: None,
+        }
+    }
+
+    /// Verify `rec` and insert its `pk` in sorted position; `false` if it is for another
+    /// slot, its `pk` is already present, or it fails verification.
+    pub fn try_add(&mut self, rec: &ObexPartRec) -> bool {
+        if rec.slot != self.slot {
+            return false;
+        }
+        let Err(pos) = self.pks.binary_search(&rec.pk_ed25519) else {
+            return false;
+        };
+        if !obex_verify_partrec(rec, self.slot, &self.parent_id, self.vrf) {
+            return false;
+        }
+        self.pks.insert(pos, rec.pk_ed25519);
+        self.root = None;
+        true
+    }
+
+    /// Accepted keys, ascending.
+    #[must_use]
+    pub fn pks(&self) -> &[Pk32] {
+        &self.pks
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.pks.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.pks.is_empty()
+    }
+
+    /// `part_root` over the accepted set.
+    pub fn root(&mut self) -> Hash256 {
+        *self
+            .root
+            .get_or_insert_with(|| participation_root(&self.pks))
+    }
+
+    /// Consume into `(P_s, part_root)`, as returned by `build_participation_set`.
+    #[must_use]
+    pub fn finish(mut self) -> (Vec<Pk32>, Hash256) {
+        let root = self.root();
+        (self.pks, root)
+    }
+}
+
+// ——— Prover (label fill, Merkle openings, transcript signature) ————————
+
+#[inline]
+fn as_index(x: u64) -> usize {
+    // Label indices are always < n_labels, which is a usize.
+    usize::try_from(x).unwrap_or(usize::MAX)
+}
+
+/// Generate the RAM-hard label array (§4).
+///
+/// Pass 0 builds `L` forward from `L[0] = lbl0(seed)`; passes `1..passes` rewrite `L[1..]`
+/// in place. Every `L[i]` (i ≥ 1) satisfies the label
+/// equation with `J`/`K` drawn at pass `passes - 1`; consensus uses `(N_LABELS, PASSES)`.
+/// `passes == 0` is treated as a single pass.
+#[must_use]
+pub fn generate_labels(seed: &Hash256, n_labels: usize, passes: u32) -> Vec<Hash256> {
+    let mut labels: Vec<Hash256> = Vec::with_capacity(n_labels);
+    if n_labels == 0 {
+        return labels;
+    }
+    labels.push(lbl0(seed));
+    for p in 0..passes.max(1) {
+        for i in 1..n_labels {
+            let iu = i as u64;
+            let j = as_index(idx_j(seed, iu, p));
+            let k = as_index(idx_k(seed, iu, p));
+            let l = label_update(seed, iu, &labels[i - 1], &labels[j], &labels[k]);
+            if p == 0 {
+                labels.push(l);
+            } else {
+                labels[i] = l;
+            }
+        }
+    }
+    labels
+}
+
+/// Merkle tree over raw labels for opening challenge paths. Leaf hashes are recomputed on
+/// demand; only node levels above the leaves are retained (`levels.last()` holds the root).
+struct LabelTree {
+    levels: Vec<Vec<Hash256>>,
+}
+
+impl LabelTree {
+    fn build(labels: &[Hash256]) -> Self {
+        let mut levels: Vec<Vec<Hash256>> = Vec::new();
+        if labels.len() <= 1 {
+            return Self { levels };
+        }
+        let mut level: Vec<Hash256> = labels
+            .chunks(2)
+            .map(|c| {
+                let l = merkle_leaf(&c[0]);
+                let r = c.get(1).map_or(l, |x| merkle_leaf(x));
+                merkle_node(&l, &r)
+            })
+            .collect();
+        while level.len() > 1 {
+            let next: Vec<Hash256> = level
+                .chunks(2)
+                .map(|c| merkle_node(&c[0], c.get(1).unwrap_or(&c[0])))
+                .collect();
+            levels.push(level);
+            level = next;
+        }
+        levels.push(level);
+        Self { levels }
+    }
+
+    fn root(&self, labels: &[Hash256]) -> Hash256 {
+        self.levels
+            .last()
+            .map_or_else(|| merkle_root(&[labels[0].to_vec()]), |top| top[0])
+    }
+
+    /// Sibling path (leaf→root) for `index`, duplicating the last node on odd levels.
+    fn path(&self, labels: &[Hash256], index: usize) -> MerklePathLite {
+        let mut siblings = Vec::with_capacity(self.levels.len());
+        if labels.len() <= 1 {
+            return MerklePathLite { siblings };
+        }
+        let sib = index ^ 1;
+        let sib = if sib < labels.len() { sib } else { index };
+        siblings.push(merkle_leaf(&labels[sib]));
+        let mut pos = index >> 1;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sib = pos ^ 1;
+            siblings.push(if sib < level.len() {
+                level[sib]
+            } else {
+                level[pos]
+            });
+            pos >>= 1;
+        }
+        MerklePathLite { siblings }
+    }
+}
+
+/// Open the four leaves (`i`, `i-1`, `J(i, p_last)`, `K(i, p_last)`) for challenge index `i`.
+fn open_challenge(labels: &[Hash256], tree: &LabelTree, seed: &Hash256, i: u64) -> ChallengeOpen {
+    let last_pass = PASSES - 1;
+    let j = idx_j(seed, i, last_pass);
+    let k = idx_k(seed, i, last_pass);
+    let (iu, ju, ku) = (as_index(i), as_index(j), as_index(k));
+    ChallengeOpen {
+        idx: i,
+        li: labels[iu],
+        pi: tree.path(labels, iu),
+        lim1: labels[iu - 1],
+        pim1: tree.path(labels, iu - 1),
+        lj: labels[ju],
+        pj: tree.path(labels, ju),
+        lk: labels[ku],
+        pk_: tree.path(labels, ku),
+    }
+}
+
+/// Build a complete `ObexPartRec` for target slot `slot` (prover side of §4–§7).
+///
+/// `vrf_prove` receives the slot's VRF input `alpha` and returns `(vrf_y, vrf_pi)` for `vrf_pk`.
+/// The full label array (`N_LABELS` × 32 bytes, i.e. `MEM_MIB`) is generated and committed,
+/// the `CHALLENGES_Q` challenges are opened with real Merkle paths, and the transcript is
+/// signed with `signing_key`. The result passes `obex_check_partrec` for the same inputs.
+#[must_use]
+pub fn obex_build_partrec(
+    slot: u64,
+    parent_id: &Hash256,
+    y_edge_prev: &Hash256,
+    signing_key: &SigningKey,
+    vrf_pk: &VrfPk32,
+    vrf_prove: impl FnOnce(&Hash256) -> (Vec<u8>, Vec<u8>),
+) -> ObexPartRec {
+    obex_build_partrec_q(
+        slot,
+        parent_id,
+        y_edge_prev,
+        signing_key,
+        vrf_pk,
+        vrf_prove,
+        CHALLENGES_Q,
+    )
+}
+
+/// `obex_build_partrec` opening `q` challenges; passes `obex_check_partrec_q` with the same `q`.
+///
+/// The transcript does not commit to `q`, so the first `q` openings of any record are
+/// themselves a valid `q`-challenge record.
+#[must_use]
+pub fn obex_build_partrec_q(
+    slot: u64,
+    parent_id: &Hash256,
+    y_edge_prev: &Hash256,
+    signing_key: &SigningKey,
+    vrf_pk: &VrfPk32,
+    vrf_prove: impl FnOnce(&Hash256) -> (Vec<u8>, Vec<u8>),
+    q: usize,
+) -> ObexPartRec {
+    let pk_ed25519: Pk32 = signing_key.verifying_key().to_bytes();
+    let alpha = obex_alpha(parent_id, slot, y_edge_prev, vrf_pk);
+    let (vrf_y, vrf_proof) = vrf_prove(&alpha);
+    let seed = obex_seed(y_edge_prev, &pk_ed25519, &vrf_y);
+
+    let labels = generate_labels(&seed, N_LABELS, PASSES);
+    let tree = LabelTree::build(&labels);
+    let root = tree.root(&labels);
+
+    let challenges = (0..q)
+        .map(|t| {
+            let t_u32 = u32::try_from(t).unwrap_or(u32::MAX);
+            let i = chal_index(y_edge_prev, &root, &vrf_y, t_u32);
+            open_challenge(&labels, &tree, &seed, i)
         })
         .collect();
-    let part_root = merkle_root(&leaves);
 
-    (pks, part_root)
+    let msg = partrec_msg(&TranscriptParts {
+        version: OBEX_ALPHA_I_VERSION,
+        slot,
+        pk: &pk_ed25519,
+        vrf_pk,
+        y_prev: y_edge_prev,
+        alpha: &alpha,
+        vrf_y: &vrf_y,
+        root: &root,
+    });
+    let sig = signing_key.sign(&msg).to_bytes();
+
+    ObexPartRec {
+        version: OBEX_ALPHA_I_VERSION,
+        slot,
+        pk_ed25519,
+        vrf_pk: *vrf_pk,
+        y_edge_prev: *y_edge_prev,
+        alpha,
+        vrf_y,
+        vrf_pi: vrf_proof,
+        seed,
+        root,
+        challenges,
+        sig,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use obex_primitives::merkle_verify_leaf_slice;
 
     #[test]
     fn chal_index_monotonic_domain_bounds() {
@@ -405,6 +989,48 @@ mod tests {
             assert!(usize::try_from(i).is_ok_and(|ii| ii < N_LABELS));
         }
     }
+
+    #[test]
+    fn generated_labels_satisfy_label_equation() {
+        let seed = [9u8; 32];
+        for passes in [1u32, PASSES] {
+            let l = generate_labels(&seed, 128, passes);
+            assert_eq!(l.len(), 128);
+            assert_eq!(l[0], lbl0(&seed));
+            assert_eq!(l, generate_labels(&seed, 128, passes));
+            for i in 1..l.len() {
+                let iu = i as u64;
+                let j = as_index(idx_j(&seed, iu, passes - 1));
+                let k = as_index(idx_k(&seed, iu, passes - 1));
+                assert_eq!(l[i], label_update(&seed, iu, &l[i - 1], &l[j], &l[k]));
+            }
+        }
+    }
+
+    #[test]
+    fn small_label_tree_openings_verify() {
+        let seed = [42u8; 32];
+        for n in [2usize, 7, 64] {
+            let labels = generate_labels(&seed, n, PASSES);
+            let tree = LabelTree::build(&labels);
+            let root = tree.root(&labels);
+            let payloads: Vec<Vec<u8>> = labels.iter().map(|l| l.to_vec()).collect();
+            assert_eq!(root, merkle_root(&payloads));
+            for i in 1..n as u64 {
+                let ch = open_challenge(&labels, &tree, &seed, i);
+                let (j, k) = (idx_j(&seed, i, PASSES - 1), idx_k(&seed, i, PASSES - 1));
+                for (leaf, path, index) in [
+                    (&ch.li, &ch.pi, i),
+                    (&ch.lim1, &ch.pim1, i - 1),
+                    (&ch.lj, &ch.pj, j),
+                    (&ch.lk, &ch.pk_, k),
+                ] {
+                    assert!(merkle_verify_leaf_slice(&root, leaf, &path.siblings, index));
+                }
+                assert_eq!(ch.li, label_update(&seed, i, &ch.lim1, &ch.lj, &ch.lk));
+            }
+        }
+    }
 }
 
 // ——— Canonical codecs (wire format) ————————————————————————————————
@@ -417,12 +1043,14 @@ pub enum CodecError {
     Trailing,
     #[error("bad vector length")]
     BadLen,
-    #[error("vrf_y must be 64 bytes (deterministic length)")]
+    #[error("vrf_y length does not match the network-fixed length (64 or 32 bytes)")]
     BadVrfY,
     #[error("vrf_pi must be 80 bytes (deterministic length)")]
     BadVrfPi,
     #[error("wrong challenges count")]
     BadChallenges,
+    #[error("{0}")]
+    LenPrefix(#[from] LenPrefixOverflow),
 }
 
 const fn read_exact<'a>(src: &mut &'a [u8], n: usize) -> Result<&'a [u8], CodecError> {
@@ -453,9 +1081,19 @@ fn read_hash(src: &mut &[u8]) -> Result<Hash256, CodecError> {
 
 // removed: read_len_prefixed_bytes (unused)
 
-fn read_hash_vec(src: &mut &[u8]) -> Result<Vec<Hash256>, CodecError> {
+/// Sibling count prefix of a challenge path, rejected above `MAX_PATH_SIBLINGS`.
+fn read_path_len(src: &mut &[u8]) -> Result<usize, CodecError> {
     let n = read_u32(src)? as usize;
-    let mut v = Vec::with_capacity(n);
+    if n > MAX_PATH_SIBLINGS {
+        return Err(CodecError::BadLen);
+    }
+    Ok(n)
+}
+
+fn read_hash_vec(src: &mut &[u8]) -> Result<Vec<Hash256>, CodecError> {
+    let n = read_path_len(src)?;
+    // Never trust the prefix for preallocation: cap by what the input can actually hold.
+    let mut v = Vec::with_capacity(n.min(src.len() / 32));
     for _ in 0..n {
         v.push(read_hash(src)?);
     }
@@ -472,27 +1110,86 @@ fn write_hash(out: &mut Vec<u8>, h: &Hash256) {
     out.extend_from_slice(h);
 }
 
-fn encode_hash_vec(out: &mut Vec<u8>, v: &[Hash256]) {
-    write_le::<4>(out, v.len() as u128);
+fn encode_hash_vec(out: &mut Vec<u8>, v: &[Hash256]) -> Result<(), CodecError> {
+    write_bytes(out, &le_len4(v.len())?);
     for h in v {
         write_hash(out, h);
     }
+    Ok(())
 }
 
-fn encode_challenge(out: &mut Vec<u8>, ch: &ChallengeOpen) {
+fn encode_challenge(out: &mut Vec<u8>, ch: &ChallengeOpen) -> Result<(), CodecError> {
     write_le::<8>(out, u128::from(ch.idx));
     write_hash(out, &ch.li);
-    encode_hash_vec(out, &ch.pi.siblings);
+    encode_hash_vec(out, &ch.pi.siblings)?;
     write_hash(out, &ch.lim1);
-    encode_hash_vec(out, &ch.pim1.siblings);
+    encode_hash_vec(out, &ch.pim1.siblings)?;
     write_hash(out, &ch.lj);
-    encode_hash_vec(out, &ch.pj.siblings);
+    encode_hash_vec(out, &ch.pj.siblings)?;
     write_hash(out, &ch.lk);
-    encode_hash_vec(out, &ch.pk_.siblings);
+    encode_hash_vec(out, &ch.pk_.siblings)
+}
+
+/// Network-fixed `vrf_y` lengths: raw RFC 9381 output (64) or the network rehash (32).
+pub const VRF_Y_LEN_RAW: usize = 64;
+pub const VRF_Y_LEN_REHASH: usize = 32;
+/// The network-fixed `vrf_y` length.
+///
+/// Every `EcVrfVerifier` returns exactly this many bytes and the default codecs
+/// (`encode_partrec`, `decode_partrec`, `decode_partrec_view`) require it. A deployment using
+/// the rehash sets this to `VRF_Y_LEN_REHASH`.
+pub const VRF_Y_LEN: usize = VRF_Y_LEN_RAW;
+const _: () = assert!(VRF_Y_LEN == VRF_Y_LEN_RAW || VRF_Y_LEN == VRF_Y_LEN_REHASH);
+#[cfg(feature = "ecvrf_rfc9381")]
+const _: () = assert!(VRF_Y_LEN_RAW == vrf::VRF_Y_BYTES);
+
+const fn check_vrf_y_len(vrf_y_len: usize) -> Result<(), CodecError> {
+    if vrf_y_len == VRF_Y_LEN_RAW || vrf_y_len == VRF_Y_LEN_REHASH {
+        Ok(())
+    } else {
+        Err(CodecError::BadVrfY)
+    }
 }
 
+/// Encoded bytes of a record outside `vrf_y`, `vrf_pi` and the challenge bodies: `version`,
+/// `slot`, the six 32-byte keys and hashes, the challenge count and `sig`.
+pub const PARTREC_FIXED_LEN: usize = 4 + 8 + 32 * 4 + 32 * 2 + 4 + 64;
+/// Encoded bytes of one challenge outside its sibling hashes: `idx`, four labels and four path
+/// counts.
+pub const CHALLENGE_FIXED_LEN: usize = 8 + 4 * (32 + 4);
+
+/// Exact length of the bytes `encode_partrec` produces for `rec`, without encoding it.
+///
+/// Counts the fields as they are; it does not check lengths, so an oversized record can be
+/// rejected against `MAX_PARTREC_SIZE` before the encoder runs.
+#[must_use]
+pub fn encoded_partrec_size(rec: &ObexPartRec) -> usize {
+    let challenges: usize = rec
+        .challenges
+        .iter()
+        .map(|ch| {
+            let siblings = ch.pi.siblings.len()
+                + ch.pim1.siblings.len()
+                + ch.pj.siblings.len()
+                + ch.pk_.siblings.len();
+            CHALLENGE_FIXED_LEN + 32 * siblings
+        })
+        .sum();
+    PARTREC_FIXED_LEN + rec.vrf_y.len() + rec.vrf_pi.len() + challenges
+}
+
+/// Encode with the network-fixed `vrf_y` length `VRF_Y_LEN`.
 pub fn encode_partrec(rec: &ObexPartRec) -> Result<Vec<u8>, CodecError> {
-    if rec.vrf_y.len() != 64 {
+    encode_partrec_with_vrf_len(rec, VRF_Y_LEN)
+}
+
+/// Encode for a network whose fixed `vrf_y` length is `vrf_y_len` (64 or 32).
+pub fn encode_partrec_with_vrf_len(
+    rec: &ObexPartRec,
+    vrf_y_len: usize,
+) -> Result<Vec<u8>, CodecError> {
+    check_vrf_y_len(vrf_y_len)?;
+    if rec.vrf_y.len() != vrf_y_len {
         return Err(CodecError::BadVrfY);
     }
     if rec.vrf_pi.len() != 80 {
@@ -501,7 +1198,7 @@ pub fn encode_partrec(rec: &ObexPartRec) -> Result<Vec<u8>, CodecError> {
     if rec.challenges.len() != CHALLENGES_Q {
         return Err(CodecError::BadChallenges);
     }
-    let mut out = Vec::new();
+    let mut out = Vec::with_capacity(encoded_partrec_size(rec));
     write_le::<4>(&mut out, u128::from(rec.version));
     write_le::<8>(&mut out, u128::from(rec.slot));
     write_bytes(&mut out, &rec.pk_ed25519);
@@ -513,15 +1210,28 @@ pub fn encode_partrec(rec: &ObexPartRec) -> Result<Vec<u8>, CodecError> {
     write_hash(&mut out, &rec.seed);
     write_hash(&mut out, &rec.root);
     // challenges: LE(4) count then bodies
-    write_le::<4>(&mut out, rec.challenges.len() as u128);
+    write_bytes(&mut out, &le_len4(rec.challenges.len())?);
     for ch in &rec.challenges {
-        encode_challenge(&mut out, ch);
+        encode_challenge(&mut out, ch)?;
     }
     write_bytes(&mut out, &rec.sig);
     Ok(out)
 }
 
-pub fn decode_partrec(mut src: &[u8]) -> Result<ObexPartRec, CodecError> {
+/// Decode with the network-fixed `vrf_y` length `VRF_Y_LEN`.
+///
+/// Any `version` decodes, so legacy records can be inspected or migrated; verification goes
+/// through `obex_check_partrec`, which rejects anything but `OBEX_ALPHA_I_VERSION`.
+pub fn decode_partrec(src: &[u8]) -> Result<ObexPartRec, CodecError> {
+    decode_partrec_with_vrf_len(src, VRF_Y_LEN)
+}
+
+/// Decode for a network whose fixed `vrf_y` length is `vrf_y_len` (64 or 32).
+pub fn decode_partrec_with_vrf_len(
+    mut src: &[u8],
+    vrf_y_len: usize,
+) -> Result<ObexPartRec, CodecError> {
+    check_vrf_y_len(vrf_y_len)?;
     let version = read_u32(&mut src)?;
     let slot = read_u64(&mut src)?;
     let pk_ed25519 = {
@@ -539,7 +1249,7 @@ pub fn decode_partrec(mut src: &[u8]) -> Result<ObexPartRec, CodecError> {
     let y_edge_prev = read_hash(&mut src)?;
     let alpha = read_hash(&mut src)?;
     let vrf_y = {
-        let b = read_exact(&mut src, 64)?;
+        let b = read_exact(&mut src, vrf_y_len)?;
         b.to_vec()
     };
     let vrf_proof = {
@@ -620,6 +1330,192 @@ pub fn decode_partrec(mut src: &[u8]) -> Result<ObexPartRec, CodecError> {
     })
 }
 
+/// Borrowed challenge opening; each path is the concatenation of its 32-byte siblings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChallengeView<'a> {
+    pub idx: u64,
+    pub li: Hash256,
+    pub pi: &'a [u8],
+    pub lim1: Hash256,
+    pub pim1: &'a [u8],
+    pub lj: Hash256,
+    pub pj: &'a [u8],
+    pub lk: Hash256,
+    pub pk_: &'a [u8],
+}
+
+/// Zero-copy view of a canonical `ObexPartRec` encoding: variable-length fields borrow the
+/// input buffer, so decoding performs a single allocation (the challenge list).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartRecView<'a> {
+    pub version: u32,
+    pub slot: u64,
+    pub pk_ed25519: Pk32,
+    pub vrf_pk: VrfPk32,
+    pub y_edge_prev: Hash256,
+    pub alpha: Hash256,
+    pub vrf_y: &'a [u8],
+    pub vrf_pi: &'a [u8],
+    pub seed: Hash256,
+    pub root: Hash256,
+    pub challenges: Vec<ChallengeView<'a>>,
+    pub sig: Sig64,
+}
+
+fn siblings_owned(raw: &[u8]) -> MerklePathLite {
+    MerklePathLite {
+        siblings: raw
+            .chunks_exact(32)
+            .map(|c| {
+                let mut h = [0u8; 32];
+                h.copy_from_slice(c);
+                h
+            })
+            .collect(),
+    }
+}
+
+impl PartRecView<'_> {
+    /// Materialize the owned record (equal to `decode_partrec` on the same bytes).
+    #[must_use]
+    pub fn to_partrec(&self) -> ObexPartRec {
+        ObexPartRec {
+            version: self.version,
+            slot: self.slot,
+            pk_ed25519: self.pk_ed25519,
+            vrf_pk: self.vrf_pk,
+            y_edge_prev: self.y_edge_prev,
+            alpha: self.alpha,
+            vrf_y: self.vrf_y.to_vec(),
+            vrf_pi: self.vrf_pi.to_vec(),
+            seed: self.seed,
+            root: self.root,
+            challenges: self
+                .challenges
+                .iter()
+                .map(|ch| ChallengeOpen {
+                    idx: ch.idx,
+                    li: ch.li,
+                    pi: siblings_owned(ch.pi),
+                    lim1: ch.lim1,
+                    pim1: siblings_owned(ch.pim1),
+                    lj: ch.lj,
+                    pj: siblings_owned(ch.pj),
+                    lk: ch.lk,
+                    pk_: siblings_owned(ch.pk_),
+                })
+                .collect(),
+            sig: self.sig,
+        }
+    }
+}
+
+fn read_hash_vec_raw<'a>(src: &mut &'a [u8]) -> Result<&'a [u8], CodecError> {
+    let n = read_path_len(src)?;
+    let len = n.checked_mul(32).ok_or(CodecError::BadLen)?;
+    read_exact(src, len)
+}
+
+/// Borrowing decode with the network-fixed `vrf_y` length `VRF_Y_LEN`.
+pub fn decode_partrec_view(src: &[u8]) -> Result<PartRecView<'_>, CodecError> {
+    decode_partrec_view_with_vrf_len(src, VRF_Y_LEN)
+}
+
+/// Borrowing decode for a network whose fixed `vrf_y` length is `vrf_y_len` (64 or 32).
+pub fn decode_partrec_view_with_vrf_len(
+    mut src: &[u8],
+    vrf_y_len: usize,
+) -> Result<PartRecView<'_>, CodecError> {
+    check_vrf_y_len(vrf_y_len)?;
+    let version = read_u32(&mut src)?;
+    let slot = read_u64(&mut src)?;
+    let pk_ed25519 = read_hash(&mut src)?;
+    let vrf_pk = read_hash(&mut src)?;
+    let y_edge_prev = read_hash(&mut src)?;
+    let alpha = read_hash(&mut src)?;
+    let vrf_y = read_exact(&mut src, vrf_y_len)?;
+    let vrf_proof = read_exact(&mut src, 80)?;
+    let seed = read_hash(&mut src)?;
+    let root = read_hash(&mut src)?;
+    let n_ch = read_u32(&mut src)? as usize;
+    if n_ch != CHALLENGES_Q {
+        return Err(CodecError::BadChallenges);
+    }
+    let mut challenges = Vec::with_capacity(n_ch);
+    for _ in 0..n_ch {
+        challenges.push(ChallengeView {
+            idx: read_u64(&mut src)?,
+            li: read_hash(&mut src)?,
+            pi: read_hash_vec_raw(&mut src)?,
+            lim1: read_hash(&mut src)?,
+            pim1: read_hash_vec_raw(&mut src)?,
+            lj: read_hash(&mut src)?,
+            pj: read_hash_vec_raw(&mut src)?,
+            lk: read_hash(&mut src)?,
+            pk_: read_hash_vec_raw(&mut src)?,
+        });
+    }
+    let sig = {
+        let b = read_exact(&mut src, 64)?;
+        let mut s = [0u8; 64];
+        s.copy_from_slice(b);
+        s
+    };
+    if !src.is_empty() {
+        return Err(CodecError::Trailing);
+    }
+    Ok(PartRecView {
+        version,
+        slot,
+        pk_ed25519,
+        vrf_pk,
+        y_edge_prev,
+        alpha,
+        vrf_y,
+        vrf_pi: vrf_proof,
+        seed,
+        root,
+        challenges,
+        sig,
+    })
+}
+
+/// Verify a borrowed view; identical results to `obex_check_partrec` on the owned record.
+pub fn obex_check_partrec_view(
+    view: &PartRecView<'_>,
+    slot: u64,
+    parent_id: &Hash256,
+    vrf: &impl EcVrfVerifier,
+) -> Result<(), VerifyErr> {
+    let r = RecRef {
+        version: view.version,
+        slot: view.slot,
+        pk: &view.pk_ed25519,
+        vrf_pk: &view.vrf_pk,
+        y_edge_prev: &view.y_edge_prev,
+        alpha: &view.alpha,
+        vrf_y: view.vrf_y,
+        vrf_pi: view.vrf_pi,
+        seed: &view.seed,
+        root: &view.root,
+        sig: &view.sig,
+        n_challenges: view.challenges.len(),
+        expected_q: CHALLENGES_Q,
+    };
+    let chals = view.challenges.iter().map(|ch| ChalRef {
+        idx: ch.idx,
+        li: &ch.li,
+        pi: ch.pi.as_chunks::<32>().0,
+        lim1: &ch.lim1,
+        pim1: ch.pim1.as_chunks::<32>().0,
+        lj: &ch.lj,
+        pj: ch.pj.as_chunks::<32>().0,
+        lk: &ch.lk,
+        pk_: ch.pk_.as_chunks::<32>().0,
+    });
+    check_rec(&r, chals, slot, parent_id, vrf).map_err(|(e, _)| e)
+}
+
 /// Verify directly from canonical bytes with `MAX_PARTREC_SIZE` enforcement before heavy work.
 pub fn obex_verify_partrec_bytes(
     bytes: &[u8],
@@ -630,8 +1526,8 @@ pub fn obex_verify_partrec_bytes(
     if bytes.len() > MAX_PARTREC_SIZE {
         return false;
     }
-    let Ok(rec) = decode_partrec(bytes) else {
+    let Ok(view) = decode_partrec_view(bytes) else {
         return false;
     };
-    obex_verify_partrec(&rec, slot, parent_id, vrf)
+    obex_check_partrec_view(&view, slot, parent_id, vrf).is_ok()
 }
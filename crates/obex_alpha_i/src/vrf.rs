@@ -7,8 +7,11 @@
 mod rfc9381 {
     use core::fmt;
     use sha2::Sha512;
-    use vrf_rfc9381::ec::edwards25519::{tai::EdVrfEdwards25519TaiPublicKey, EdVrfProof};
-    use vrf_rfc9381::Verifier as _;
+    use vrf_rfc9381::ec::edwards25519::{
+        tai::{EdVrfEdwards25519TaiPublicKey, EdVrfEdwards25519TaiSecretKey},
+        EdVrfProof,
+    };
+    use vrf_rfc9381::{Proof as _, Prover as _, Verifier as _};
 
     pub const VRF_SUITE_NAME: &str = "ECVRF-EDWARDS25519-SHA512-TAI";
     pub const VRF_PK_BYTES: usize = 32; // public key
@@ -18,12 +21,14 @@ mod rfc9381 {
     pub type VrfPk = [u8; VRF_PK_BYTES];
     pub type VrfPi = [u8; VRF_PI_BYTES];
     pub type VrfY = [u8; VRF_Y_BYTES];
+    pub type VrfSk = [u8; 32];
 
     #[derive(Clone, Copy, Debug, Eq, PartialEq)]
     pub enum VrfError {
         BadPublicKey,
         BadProofEncoding,
         VerificationFailed,
+        ProveFailed,
     }
     impl fmt::Display for VrfError {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -31,6 +36,7 @@ mod rfc9381 {
                 Self::BadPublicKey => f.write_str("malformed or non-canonical VRF public key"),
                 Self::BadProofEncoding => f.write_str("malformed VRF proof encoding"),
                 Self::VerificationFailed => f.write_str("VRF verification failed"),
+                Self::ProveFailed => f.write_str("VRF proof generation failed"),
             }
         }
     }
@@ -52,6 +58,40 @@ mod rfc9381 {
         Ok(y)
     }
 
+    /// Public key for a 32-byte VRF secret key (RFC 9381 §5.1: same derivation as Ed25519).
+    #[inline]
+    #[must_use]
+    pub fn public_key(secret_key: &VrfSk) -> VrfPk {
+        ed25519_dalek::SigningKey::from_bytes(secret_key)
+            .verifying_key()
+            .to_bytes()
+    }
+
+    /// Prove a 32-byte consensus alpha: returns the 80-byte proof π and 64-byte output β that
+    /// `verify(&public_key(secret_key), alpha32, &pi)` accepts.
+    #[inline]
+    pub fn prove(secret_key: &VrfSk, alpha32: &[u8; 32]) -> Result<(VrfPi, VrfY), VrfError> {
+        prove_msg_tai(secret_key, alpha32)
+    }
+
+    /// Prove for arbitrary-length alpha message (RFC vectors). Not used in consensus.
+    #[inline]
+    pub fn prove_msg_tai(secret_key: &VrfSk, alpha_msg: &[u8]) -> Result<(VrfPi, VrfY), VrfError> {
+        let sk = EdVrfEdwards25519TaiSecretKey::from_slice(secret_key)
+            .map_err(|_| VrfError::ProveFailed)?;
+        let proof = sk.prove(alpha_msg).map_err(|_| VrfError::ProveFailed)?;
+        let mut pi = [0u8; VRF_PI_BYTES];
+        pi.copy_from_slice(&proof.encode_to_pi());
+        let out = <EdVrfProof as vrf_rfc9381::Proof<Sha512>>::proof_to_hash(
+            &proof,
+            vrf_rfc9381::Ciphersuite::ECVRF_EDWARDS25519_SHA512_TAI,
+        )
+        .map_err(|_| VrfError::ProveFailed)?;
+        let mut y = [0u8; VRF_Y_BYTES];
+        y.copy_from_slice(out.as_slice());
+        Ok((pi, y))
+    }
+
     /// Verify for arbitrary-length alpha message (RFC vectors). Not used in consensus.
     #[inline]
     pub fn verify_msg_tai(vrf_pk: &VrfPk, alpha_msg: &[u8], pi: &VrfPi) -> Result<VrfY, VrfError> {
@@ -70,8 +110,8 @@ mod rfc9381 {
 
 #[cfg(any(feature = "ecvrf_rfc9381", feature = "ecvrf_rfc9381-ed25519"))]
 pub use rfc9381::{
-    verify, verify_msg_tai, VrfError, VrfPi, VrfPk, VrfY, VRF_PI_BYTES, VRF_PK_BYTES,
-    VRF_SUITE_NAME, VRF_Y_BYTES,
+    prove, prove_msg_tai, public_key, verify, verify_msg_tai, VrfError, VrfPi, VrfPk, VrfSk, VrfY,
+    VRF_PI_BYTES, VRF_PK_BYTES, VRF_SUITE_NAME, VRF_Y_BYTES,
 };
 
 /// Convenience wrapper with explicit TAI naming used by tests/vectors.
@@ -119,3 +159,17 @@ pub fn ecvrf_verify_beta_tai_opt(vk: [u8; 32], alpha: [u8; 32], pi: &[u8]) -> Op
     let pk: VrfPk = vk;
     verify(&pk, &alpha, &pi80).ok()
 }
+
+/// Production `EcVrfVerifier` backed by `ecvrf_verify_beta_tai_opt`.
+///
+/// Verifies RFC 9381 ECVRF-EDWARDS25519-SHA512-TAI over the 32-byte α and returns the raw
+/// 64-byte β, so it fits networks with `VRF_Y_LEN == VRF_Y_LEN_RAW`; a rehash deployment wraps
+/// it and hashes β down to 32 bytes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Rfc9381TaiVerifier;
+
+impl crate::EcVrfVerifier for Rfc9381TaiVerifier {
+    fn verify(&self, vrf_pubkey: &[u8; 32], alpha: &[u8; 32], vrf_proof: &[u8]) -> Option<Vec<u8>> {
+        ecvrf_verify_beta_tai_opt(*vrf_pubkey, *alpha, vrf_proof).map(|beta| beta.to_vec())
+    }
+}
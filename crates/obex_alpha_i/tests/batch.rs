@@ -0,0 +1,33 @@
+mod common;
+
+use common::{valid_rec, AcceptY};
+use obex_alpha_i::{obex_verify_partrecs_batch, VerifyErr};
+use obex_primitives::constants;
+
+#[test]
+fn batch_reports_sig_invalid_at_exact_index() {
+    let rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let mut bad = rec.clone();
+    bad.sig[0] ^= 1;
+    let recs = vec![rec.clone(), rec.clone(), bad, rec.clone()];
+    let res = obex_verify_partrecs_batch(&recs, rec.slot, &constants::GENESIS_PARENT_ID, &vrf);
+    assert_eq!(
+        res,
+        vec![Ok(()), Ok(()), Err(VerifyErr::SigInvalid), Ok(())]
+    );
+}
+
+#[test]
+fn batch_keeps_duplicates_and_empty_input() {
+    let rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let res = obex_verify_partrecs_batch(&[], rec.slot, &constants::GENESIS_PARENT_ID, &vrf);
+    assert!(res.is_empty());
+    // Same pk twice: one result per input, no dedup at this layer
+    let mut wrong_slot = rec.clone();
+    wrong_slot.slot += 1;
+    let recs = [rec.clone(), rec.clone(), wrong_slot];
+    let res = obex_verify_partrecs_batch(&recs, rec.slot, &constants::GENESIS_PARENT_ID, &vrf);
+    assert_eq!(res, vec![Ok(()), Ok(()), Err(VerifyErr::SlotMismatch)]);
+}
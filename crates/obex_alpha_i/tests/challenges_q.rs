@@ -0,0 +1,54 @@
+mod common;
+
+use common::{valid_rec, AcceptY};
+use obex_alpha_i::{obex_check_partrec, obex_check_partrec_q, VerifyErr, CHALLENGES_Q};
+use obex_primitives::constants;
+
+#[test]
+fn q8_record_verifies_under_q_aware_verifier() {
+    // The transcript does not commit to Q: the first 8 openings of the golden record are
+    // exactly what `obex_build_partrec_q(.., 8)` produces for the same inputs.
+    let mut rec = valid_rec();
+    rec.challenges.truncate(8);
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let parent = constants::GENESIS_PARENT_ID;
+
+    assert_eq!(
+        obex_check_partrec_q(&rec, rec.slot, &parent, &vrf, 8),
+        Ok(())
+    );
+    assert_eq!(
+        obex_check_partrec(&rec, rec.slot, &parent, &vrf),
+        Err(VerifyErr::ChallengesLen)
+    );
+    assert_eq!(
+        obex_check_partrec_q(&rec, rec.slot, &parent, &vrf, 9),
+        Err(VerifyErr::ChallengesLen)
+    );
+
+    let mut bad = rec.clone();
+    bad.challenges[3].lj[0] ^= 1;
+    assert_eq!(
+        obex_check_partrec_q(&bad, rec.slot, &parent, &vrf, 8),
+        Err(VerifyErr::MerkleLjInvalid)
+    );
+}
+
+#[test]
+fn default_q_agrees_with_obex_check_partrec() {
+    let rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let parent = constants::GENESIS_PARENT_ID;
+    assert_eq!(
+        obex_check_partrec_q(&rec, rec.slot, &parent, &vrf, CHALLENGES_Q),
+        obex_check_partrec(&rec, rec.slot, &parent, &vrf)
+    );
+}
+
+#[test]
+fn n_labels_is_derived_from_mem_mib() {
+    use obex_alpha_i::{labels_for_mib, LABEL_BYTES, MEM_MIB, N_LABELS};
+    assert_eq!(N_LABELS, labels_for_mib(MEM_MIB));
+    assert_eq!(N_LABELS * LABEL_BYTES, MEM_MIB << 20);
+    assert_eq!(labels_for_mib(1), 32_768);
+}
@@ -0,0 +1,126 @@
+use obex_alpha_i::{
+    decode_partrec, decode_partrec_view, encode_partrec, ChallengeOpen, CodecError, MerklePathLite,
+    ObexPartRec, CHALLENGES_Q, MAX_PARTREC_SIZE, MAX_PATH_SIBLINGS, OBEX_ALPHA_I_VERSION,
+    VRF_Y_LEN,
+};
+use obex_primitives::Hash256;
+use proptest::prelude::*;
+
+fn hash() -> impl Strategy<Value = Hash256> {
+    any::<[u8; 32]>()
+}
+
+fn path() -> impl Strategy<Value = MerklePathLite> {
+    prop::collection::vec(hash(), 0..=3).prop_map(|siblings| MerklePathLite { siblings })
+}
+
+fn challenge() -> impl Strategy<Value = ChallengeOpen> {
+    (
+        any::<u64>(),
+        (hash(), path()),
+        (hash(), path()),
+        (hash(), path()),
+        (hash(), path()),
+    )
+        .prop_map(
+            |(idx, (li, pi), (lim1, pim1), (lj, pj), (lk, pk_))| ChallengeOpen {
+                idx,
+                li,
+                pi,
+                lim1,
+                pim1,
+                lj,
+                pj,
+                lk,
+                pk_,
+            },
+        )
+}
+
+fn partrec() -> impl Strategy<Value = ObexPartRec> {
+    (
+        (any::<u64>(), hash(), hash(), hash(), hash()),
+        prop::collection::vec(any::<u8>(), VRF_Y_LEN),
+        prop::collection::vec(any::<u8>(), 80),
+        (hash(), hash()),
+        prop::collection::vec(challenge(), CHALLENGES_Q),
+        prop::collection::vec(any::<u8>(), 64),
+    )
+        .prop_map(
+            |(
+                (slot, pk_ed25519, vrf_pk, y_edge_prev, alpha),
+                vrf_y,
+                vrf_pi,
+                (seed, root),
+                challenges,
+                sig,
+            )| {
+                ObexPartRec {
+                    version: OBEX_ALPHA_I_VERSION,
+                    slot,
+                    pk_ed25519,
+                    vrf_pk,
+                    y_edge_prev,
+                    alpha,
+                    vrf_y,
+                    vrf_pi,
+                    seed,
+                    root,
+                    challenges,
+                    sig: sig.try_into().unwrap(),
+                }
+            },
+        )
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    #[test]
+    fn encode_decode_identity(rec in partrec()) {
+        let bytes = encode_partrec(&rec).unwrap();
+        prop_assert!(bytes.len() <= MAX_PARTREC_SIZE);
+        prop_assert_eq!(decode_partrec(&bytes).unwrap(), rec);
+        prop_assert!(decode_partrec_view(&bytes).is_ok());
+    }
+
+    #[test]
+    fn mutated_encoding_never_panics(
+        rec in partrec(),
+        cut in any::<prop::sample::Index>(),
+        flip in any::<prop::sample::Index>(),
+        bit in 0u8..8,
+    ) {
+        let mut bytes = encode_partrec(&rec).unwrap();
+        let i = flip.index(bytes.len());
+        bytes[i] ^= 1 << bit;
+        bytes.truncate(cut.index(bytes.len() + 1));
+        let _ = decode_partrec(&bytes);
+        let _ = decode_partrec_view(&bytes);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(4096))]
+
+    #[test]
+    fn arbitrary_bytes_never_panic(bytes in prop::collection::vec(any::<u8>(), 0..4096)) {
+        let _ = decode_partrec(&bytes);
+        let _ = decode_partrec_view(&bytes);
+    }
+
+    #[test]
+    fn oversized_sibling_count_is_an_error(extra in 1u32..1_000) {
+        // Header of a record with zero-length fields up to the first sibling count.
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&OBEX_ALPHA_I_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 8 + 4 * 32]);
+        bytes.extend_from_slice(&vec![0u8; VRF_Y_LEN + 80 + 2 * 32]);
+        bytes.extend_from_slice(&u32::try_from(CHALLENGES_Q).unwrap().to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 8 + 32]);
+        let n = u32::try_from(MAX_PATH_SIBLINGS).unwrap() + extra;
+        bytes.extend_from_slice(&n.to_le_bytes());
+        prop_assert!(matches!(decode_partrec(&bytes), Err(CodecError::BadLen)));
+        prop_assert!(matches!(decode_partrec_view(&bytes), Err(CodecError::BadLen)));
+    }
+}
@@ -0,0 +1,137 @@
+use obex_alpha_i::{
+    decode_partrec, decode_partrec_with_vrf_len, encode_partrec, encode_partrec_with_vrf_len,
+    encoded_partrec_size, ChallengeOpen, CodecError, MerklePathLite, ObexPartRec, CHALLENGES_Q,
+    OBEX_ALPHA_I_VERSION, VRF_Y_LEN_RAW, VRF_Y_LEN_REHASH,
+};
+
+fn path(n: u8) -> MerklePathLite {
+    MerklePathLite {
+        siblings: vec![[n; 32]; 3],
+    }
+}
+
+fn rec_with_vrf_y_len(len: usize) -> ObexPartRec {
+    let ch = ChallengeOpen {
+        idx: 7,
+        li: [1u8; 32],
+        pi: path(2),
+        lim1: [3u8; 32],
+        pim1: path(4),
+        lj: [5u8; 32],
+        pj: path(6),
+        lk: [7u8; 32],
+        pk_: path(8),
+    };
+    ObexPartRec {
+        version: OBEX_ALPHA_I_VERSION,
+        slot: 42,
+        pk_ed25519: [9u8; 32],
+        vrf_pk: [10u8; 32],
+        y_edge_prev: [11u8; 32],
+        alpha: [12u8; 32],
+        vrf_y: vec![13u8; len],
+        vrf_pi: vec![14u8; 80],
+        seed: [15u8; 32],
+        root: [16u8; 32],
+        challenges: vec![ch; CHALLENGES_Q],
+        sig: [17u8; 64],
+    }
+}
+
+#[test]
+fn roundtrip_64_byte_vrf_y() {
+    let rec = rec_with_vrf_y_len(VRF_Y_LEN_RAW);
+    let bytes = encode_partrec_with_vrf_len(&rec, VRF_Y_LEN_RAW).unwrap();
+    assert_eq!(bytes, encode_partrec(&rec).unwrap());
+    assert_eq!(
+        decode_partrec_with_vrf_len(&bytes, VRF_Y_LEN_RAW).unwrap(),
+        rec
+    );
+    assert_eq!(decode_partrec(&bytes).unwrap(), rec);
+}
+
+#[test]
+fn roundtrip_32_byte_vrf_y() {
+    let rec = rec_with_vrf_y_len(VRF_Y_LEN_REHASH);
+    let bytes = encode_partrec_with_vrf_len(&rec, VRF_Y_LEN_REHASH).unwrap();
+    assert_eq!(
+        bytes.len() + 32,
+        encode_partrec(&rec_with_vrf_y_len(64)).unwrap().len()
+    );
+    assert_eq!(
+        decode_partrec_with_vrf_len(&bytes, VRF_Y_LEN_REHASH).unwrap(),
+        rec
+    );
+}
+
+#[test]
+fn mismatched_vrf_y_len_rejected() {
+    let rec32 = rec_with_vrf_y_len(VRF_Y_LEN_REHASH);
+    assert!(matches!(encode_partrec(&rec32), Err(CodecError::BadVrfY)));
+    let rec64 = rec_with_vrf_y_len(VRF_Y_LEN_RAW);
+    assert!(matches!(
+        encode_partrec_with_vrf_len(&rec64, VRF_Y_LEN_REHASH),
+        Err(CodecError::BadVrfY)
+    ));
+    assert!(matches!(
+        encode_partrec_with_vrf_len(&rec64, 48),
+        Err(CodecError::BadVrfY)
+    ));
+    // A 32-byte network record does not decode under the 64-byte configuration
+    let bytes32 = encode_partrec_with_vrf_len(&rec32, VRF_Y_LEN_REHASH).unwrap();
+    assert!(decode_partrec(&bytes32).is_err());
+    assert!(matches!(
+        decode_partrec_with_vrf_len(&bytes32, 16),
+        Err(CodecError::BadVrfY)
+    ));
+}
+
+#[cfg(feature = "ecvrf_rfc9381")]
+#[test]
+fn rfc9381_output_is_the_raw_vrf_y_len() {
+    use obex_alpha_i::vrf;
+    assert_eq!(VRF_Y_LEN_RAW, vrf::VRF_Y_BYTES);
+    let sk: vrf::VrfSk = [7u8; 32];
+    let (_pi, y) = vrf::prove(&sk, &[1u8; 32]).unwrap();
+    assert_eq!(y.len(), VRF_Y_LEN_RAW);
+}
+
+#[test]
+fn default_codec_uses_network_vrf_y_len() {
+    use obex_alpha_i::VRF_Y_LEN;
+    // Default codec takes exactly VRF_Y_LEN and nothing else.
+    let rec = rec_with_vrf_y_len(VRF_Y_LEN);
+    let bytes = encode_partrec(&rec).unwrap();
+    assert_eq!(decode_partrec(&bytes).unwrap(), rec);
+    let other = if VRF_Y_LEN == VRF_Y_LEN_RAW {
+        VRF_Y_LEN_REHASH
+    } else {
+        VRF_Y_LEN_RAW
+    };
+    assert!(matches!(
+        encode_partrec(&rec_with_vrf_y_len(other)),
+        Err(CodecError::BadVrfY)
+    ));
+}
+
+#[test]
+fn predicted_size_matches_encoding() {
+    for len in [VRF_Y_LEN_RAW, VRF_Y_LEN_REHASH] {
+        let mut rec = rec_with_vrf_y_len(len);
+        rec.challenges[5].pj.siblings.push([0xAA; 32]);
+        rec.challenges[9].pi.siblings.clear();
+        let bytes = encode_partrec_with_vrf_len(&rec, len).unwrap();
+        assert_eq!(encoded_partrec_size(&rec), bytes.len());
+    }
+    let golden = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/golden/partrec_valid_v1.bin"
+    ))
+    .unwrap();
+    let rec = decode_partrec(&golden).unwrap();
+    assert_eq!(encoded_partrec_size(&rec), golden.len());
+    assert_eq!(
+        encoded_partrec_size(&rec),
+        encode_partrec(&rec).unwrap().len()
+    );
+}
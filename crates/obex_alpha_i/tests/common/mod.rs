@@ -0,0 +1,29 @@
+//! Fixtures shared by the integration tests; each test crate uses a subset.
+#![allow(dead_code)]
+
+use obex_alpha_i::{decode_partrec, EcVrfVerifier, ObexPartRec};
+use obex_primitives::Hash256;
+
+/// VRF mock: accepts every proof and returns the fixed output `y`.
+pub struct AcceptY(pub Vec<u8>);
+
+impl EcVrfVerifier for AcceptY {
+    fn verify(&self, _k: &[u8; 32], _a: &Hash256, _p: &[u8]) -> Option<Vec<u8>> {
+        Some(self.0.clone())
+    }
+}
+
+/// Bytes of `tests/golden/<name>`.
+pub fn golden(name: &str) -> Vec<u8> {
+    let p = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(name);
+    std::fs::read(p).unwrap_or_else(|e| panic!("read golden {name}: {e}"))
+}
+
+/// `partrec_valid_v1.bin`: valid at its slot on `GENESIS_PARENT_ID` under
+/// `AcceptY(rec.vrf_y.clone())`.
+pub fn valid_rec() -> ObexPartRec {
+    decode_partrec(&golden("partrec_valid_v1.bin")).unwrap()
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::{valid_rec, AcceptY};
+use obex_alpha_i::{obex_check_partrec, obex_check_partrec_ct, EcVrfVerifier, ObexPartRec};
+use obex_primitives::{constants, Hash256};
+
+struct RejectVrf;
+impl EcVrfVerifier for RejectVrf {
+    fn verify(&self, _k: &[u8; 32], _a: &Hash256, _p: &[u8]) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+#[test]
+fn ct_verifier_agrees_with_precise_verifier() {
+    let rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let parent = constants::GENESIS_PARENT_ID;
+    let agree = |r: &ObexPartRec, slot: u64| {
+        let precise = obex_check_partrec(r, slot, &parent, &vrf).is_ok();
+        assert_eq!(obex_check_partrec_ct(r, slot, &parent, &vrf), precise);
+        precise
+    };
+    assert!(agree(&rec, rec.slot));
+    assert!(!agree(&rec, rec.slot + 1));
+
+    let mutations: Vec<fn(&mut ObexPartRec)> = vec![
+        |r| r.version += 1,
+        |r| r.alpha[0] ^= 1,
+        |r| r.vrf_y[3] ^= 1,
+        |r| r.seed[1] ^= 1,
+        |r| r.root[2] ^= 1,
+        |r| r.sig[63] ^= 1,
+        |r| {
+            r.challenges.pop();
+        },
+        |r| r.challenges[0].idx ^= 1,
+        |r| r.challenges[95].li[0] ^= 1,
+        |r| r.challenges[10].pim1.siblings[3][0] ^= 1,
+        |r| r.challenges[20].lj[31] ^= 1,
+        |r| r.challenges[30].pk_.siblings[23][7] ^= 1,
+    ];
+    for m in mutations {
+        let mut r = rec.clone();
+        m(&mut r);
+        assert!(!agree(&r, rec.slot));
+    }
+
+    assert!(!obex_check_partrec_ct(&rec, rec.slot, &parent, &RejectVrf));
+}
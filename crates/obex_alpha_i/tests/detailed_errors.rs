@@ -0,0 +1,38 @@
+mod common;
+
+use common::{valid_rec, AcceptY};
+use obex_alpha_i::{obex_check_partrec, obex_check_partrec_detailed, VerifyErr};
+use obex_primitives::constants;
+
+#[test]
+fn corrupted_lj_sibling_reports_challenge_7() {
+    let mut rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    rec.challenges[7].pj.siblings[0][0] ^= 1;
+    let parent = constants::GENESIS_PARENT_ID;
+    assert_eq!(
+        obex_check_partrec_detailed(&rec, rec.slot, &parent, &vrf),
+        Err((VerifyErr::MerkleLjInvalid, Some(7)))
+    );
+    // The plain API keeps reporting the bare kind
+    assert_eq!(
+        obex_check_partrec(&rec, rec.slot, &parent, &vrf),
+        Err(VerifyErr::MerkleLjInvalid)
+    );
+}
+
+#[test]
+fn transcript_failures_carry_no_challenge_index() {
+    let mut rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let parent = constants::GENESIS_PARENT_ID;
+    assert_eq!(
+        obex_check_partrec_detailed(&rec, rec.slot, &parent, &vrf),
+        Ok(())
+    );
+    rec.sig[5] ^= 1;
+    assert_eq!(
+        obex_check_partrec_detailed(&rec, rec.slot, &parent, &vrf),
+        Err((VerifyErr::SigInvalid, None))
+    );
+}
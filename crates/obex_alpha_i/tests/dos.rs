@@ -5,7 +5,12 @@ use obex_primitives::Hash256;
 
 struct NeverVrf;
 impl EcVrfVerifier for NeverVrf {
-    fn verify(&self, _vrf_pubkey: &[u8; 32], _alpha: &Hash256, _vrf_proof: &[u8]) -> Option<Vec<u8>> {
+    fn verify(
+        &self,
+        _vrf_pubkey: &[u8; 32],
+        _alpha: &Hash256,
+        _vrf_proof: &[u8],
+    ) -> Option<Vec<u8>> {
         None
     }
 }
@@ -21,3 +26,25 @@ fn partrec_oversize_rejected_early() {
     assert!(!ok, "oversize partrec must be rejected");
 }
 
+#[test]
+fn over_long_sibling_count_rejected_at_decode() {
+    use obex_alpha_i::{decode_partrec, decode_partrec_view, CodecError, MAX_PATH_SIBLINGS};
+    let mut bytes = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/golden/partrec_valid_v1.bin"
+    ))
+    .unwrap();
+    // version, slot, pk, vrf_pk, y_edge_prev, alpha, vrf_y(64), vrf_pi(80), seed, root, n_ch
+    let ch0 = 4 + 8 + 32 * 4 + 64 + 80 + 32 * 2 + 4;
+    // First challenge: idx(8) || li(32) || LE(4) sibling count of `pi`
+    let off = ch0 + 8 + 32;
+    let n = u32::from_le_bytes(bytes[off..off + 4].try_into().unwrap()) as usize;
+    assert!(n <= MAX_PATH_SIBLINGS);
+    let over = u32::try_from(MAX_PATH_SIBLINGS + 1).unwrap();
+    bytes[off..off + 4].copy_from_slice(&over.to_le_bytes());
+    assert!(matches!(decode_partrec(&bytes), Err(CodecError::BadLen)));
+    assert!(matches!(
+        decode_partrec_view(&bytes),
+        Err(CodecError::BadLen)
+    ));
+}
@@ -1,38 +1,23 @@
-use std::fs;
-use std::path::Path;
+mod common;
 
+use common::{golden, AcceptY};
 use obex_alpha_i::{
-    decode_partrec, encode_partrec, obex_check_partrec, ChallengeOpen, EcVrfVerifier,
-    MerklePathLite, VerifyErr, CHALLENGES_Q, MAX_PARTREC_SIZE, OBEX_ALPHA_I_VERSION,
+    decode_partrec, encode_partrec, obex_check_partrec, ChallengeOpen, MerklePathLite, VerifyErr,
+    CHALLENGES_Q, MAX_PARTREC_SIZE, OBEX_ALPHA_I_VERSION,
 };
-use obex_primitives::{constants, Hash256};
-
-fn read_golden() -> Vec<u8> {
-    let p = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("tests")
-        .join("golden")
-        .join("partrec_v1.bin");
-    fs::read(p).expect("read golden partrec_v1.bin")
-}
+use obex_primitives::constants;
 
 #[test]
 fn golden_partrec_accept_and_roundtrip() {
-    let bytes = read_golden();
+    let bytes = golden("partrec_v1.bin");
     let rec = decode_partrec(&bytes).expect("decode golden");
     let bytes2 = encode_partrec(&rec).expect("re-encode");
     assert_eq!(bytes2, bytes, "golden bytes stable");
 }
 
-struct AcceptY(Vec<u8>);
-impl EcVrfVerifier for AcceptY {
-    fn verify(&self, _k: &[u8; 32], _a: &Hash256, _p: &[u8]) -> Option<Vec<u8>> {
-        Some(self.0.clone())
-    }
-}
-
 #[test]
 fn golden_partrec_flipbit_precise_errors() {
-    let bytes = read_golden();
+    let bytes = golden("partrec_v1.bin");
     let rec = decode_partrec(&bytes).expect("decode golden");
     let vrf = AcceptY(rec.vrf_y.clone());
     // 1) Alpha mismatch
@@ -91,7 +76,7 @@ fn golden_partrec_flipbit_precise_errors() {
 /// This locks the consensus behavior for `ObexPartRec` validation forever
 #[test]
 fn golden_partrec_comprehensive_flipbit_failures() {
-    let bytes = read_golden();
+    let bytes = golden("partrec_v1.bin");
     let rec = decode_partrec(&bytes).expect("decode golden");
     let vrf = AcceptY(rec.vrf_y.clone());
 
@@ -112,8 +97,6 @@ fn golden_partrec_comprehensive_flipbit_failures() {
                 err,
                 VerifyErr::AlphaMismatch,
                 "Alpha bit flip at byte {byte_idx} bit {bit_idx} should cause AlphaMismatch",
-                
-                
             );
         }
     }
@@ -135,8 +118,6 @@ fn golden_partrec_comprehensive_flipbit_failures() {
                 err,
                 VerifyErr::SeedMismatch,
                 "Seed bit flip at byte {byte_idx} bit {bit_idx} should cause SeedMismatch",
-                
-                
             );
         }
     }
@@ -159,8 +140,6 @@ fn golden_partrec_comprehensive_flipbit_failures() {
                 err,
                 VerifyErr::SigInvalid,
                 "Root bit flip at byte {byte_idx} bit {bit_idx} should cause SigInvalid",
-                
-                
             );
         }
     }
@@ -178,8 +157,6 @@ fn golden_partrec_comprehensive_flipbit_failures() {
                 err,
                 VerifyErr::SigInvalid,
                 "Signature bit flip at byte {byte_idx} bit {bit_idx} should cause SigInvalid",
-                
-                
             );
         }
     }
@@ -189,7 +166,7 @@ fn golden_partrec_comprehensive_flipbit_failures() {
 /// This locks the `CHALLENGES_Q` constant behavior forever
 #[test]
 fn golden_partrec_challenges_q_enforcement() {
-    let bytes = read_golden();
+    let bytes = golden("partrec_v1.bin");
     let mut rec = decode_partrec(&bytes).expect("decode golden");
     let vrf = AcceptY(rec.vrf_y.clone());
 
@@ -199,7 +176,7 @@ fn golden_partrec_challenges_q_enforcement() {
     assert_eq!(err, VerifyErr::ChallengesLen);
 
     // Test Q > 96 (too many challenges)
-    let bytes = read_golden();
+    let bytes = golden("partrec_v1.bin");
     let mut rec = decode_partrec(&bytes).expect("decode golden");
     rec.challenges.push(ChallengeOpen {
         idx: 1,
@@ -221,7 +198,7 @@ fn golden_partrec_challenges_q_enforcement() {
 /// This ensures the golden `ObexPartRec` byte representation never changes
 #[test]
 fn golden_partrec_canonical_byte_image() {
-    let bytes = read_golden();
+    let bytes = golden("partrec_v1.bin");
     let rec = decode_partrec(&bytes).expect("decode golden");
 
     // Re-encode and verify byte-for-byte stability
@@ -246,7 +223,7 @@ fn golden_partrec_canonical_byte_image() {
 
 #[test]
 fn golden_partrec_oversize_rejects_decode() {
-    let mut bytes = read_golden();
+    let mut bytes = golden("partrec_v1.bin");
     // Extend to exceed MAX_PARTREC_SIZE by 1
     let pad = MAX_PARTREC_SIZE
         .saturating_sub(bytes.len())
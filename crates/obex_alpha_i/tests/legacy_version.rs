@@ -0,0 +1,36 @@
+mod common;
+
+use common::{golden, AcceptY};
+use obex_alpha_i::{decode_partrec, obex_check_partrec, VerifyErr, OBEX_ALPHA_I_VERSION};
+use obex_primitives::constants;
+
+#[test]
+fn v0_record_decodes_but_does_not_verify() {
+    let v0 = decode_partrec(&golden("partrec_v0.bin")).expect("v0");
+    assert_eq!(v0.version, 0);
+
+    // partrec_v0.bin is partrec_v1.bin with only the version field cleared.
+    let v1 = decode_partrec(&golden("partrec_v1.bin")).expect("v1");
+    assert_eq!(v1.version, OBEX_ALPHA_I_VERSION);
+    let mut migrated = v0.clone();
+    migrated.version = OBEX_ALPHA_I_VERSION;
+    assert_eq!(migrated, v1);
+
+    let err = obex_check_partrec(
+        &v0,
+        v0.slot,
+        &constants::GENESIS_PARENT_ID,
+        &AcceptY(v0.vrf_y.clone()),
+    )
+    .unwrap_err();
+    assert_eq!(err, VerifyErr::VersionMismatch);
+}
+
+#[test]
+fn legacy_record_still_enforces_field_lengths() {
+    let bytes = golden("partrec_v0.bin");
+    assert!(decode_partrec(&bytes[..bytes.len() - 1]).is_err());
+    let mut trailing = bytes;
+    trailing.push(0);
+    assert!(decode_partrec(&trailing).is_err());
+}
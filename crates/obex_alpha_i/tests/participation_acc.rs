@@ -0,0 +1,170 @@
+mod common;
+
+use common::{valid_rec, AcceptY};
+use obex_alpha_i::{build_participation_set, ParticipationAccumulator};
+use obex_primitives::constants;
+
+#[test]
+fn incremental_root_matches_batch_builder() {
+    let rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let parent = constants::GENESIS_PARENT_ID;
+    // Valid, duplicate pk, and a forged record under a different pk.
+    let mut forged = rec.clone();
+    forged.pk_ed25519 = [0u8; 32];
+    let recs = [forged, rec.clone(), rec.clone()];
+
+    let mut acc = ParticipationAccumulator::new(rec.slot, parent, &vrf);
+    let empty_root = acc.root();
+    let accepted: Vec<bool> = recs.iter().map(|r| acc.try_add(r)).collect();
+    assert_eq!(accepted, vec![false, true, false]);
+    assert_eq!(acc.pks(), &[rec.pk_ed25519]);
+    assert_ne!(acc.root(), empty_root);
+
+    let batch = build_participation_set(rec.slot, &parent, recs.iter(), &vrf);
+    assert_eq!(acc.finish(), batch);
+}
+
+#[test]
+fn empty_and_wrong_slot_match_batch_builder() {
+    let rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let parent = constants::GENESIS_PARENT_ID;
+    let slot = rec.slot + 1;
+    let mut acc = ParticipationAccumulator::new(slot, parent, &vrf);
+    assert!(!acc.try_add(&rec));
+    assert!(acc.is_empty());
+    assert_eq!(
+        acc.finish(),
+        build_participation_set(slot, &parent, [rec].iter(), &vrf)
+    );
+}
+
+#[test]
+fn included_pk_proves_against_part_root() {
+    use obex_alpha_i::{build_participation_set_with_leaves, part_leaf};
+    use obex_primitives::{merkle_prove, merkle_verify_leaf};
+
+    let rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let parent = constants::GENESIS_PARENT_ID;
+    let (pks, leaves, root) =
+        build_participation_set_with_leaves(rec.slot, &parent, [rec.clone()].iter(), &vrf);
+    assert_eq!(
+        (pks.clone(), root),
+        build_participation_set(rec.slot, &parent, [rec.clone()].iter(), &vrf)
+    );
+    let i = pks.binary_search(&rec.pk_ed25519).unwrap();
+    assert_eq!(leaves[i], part_leaf(&rec.pk_ed25519));
+    let path = merkle_prove(&leaves, i).unwrap();
+    assert!(merkle_verify_leaf(
+        &root,
+        &part_leaf(&rec.pk_ed25519),
+        &path
+    ));
+    assert!(!merkle_verify_leaf(&root, &part_leaf(&[0u8; 32]), &path));
+}
+
+#[test]
+fn report_counts_each_drop_reason() {
+    use obex_alpha_i::{build_participation_set_report, SubmissionStats};
+    let rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let parent = constants::GENESIS_PARENT_ID;
+    let mut forged = rec.clone();
+    forged.pk_ed25519 = [0u8; 32];
+    let mut late = rec.clone();
+    late.slot += 1;
+    let recs = [
+        forged.clone(),
+        rec.clone(),
+        late,
+        rec.clone(),
+        forged,
+        rec.clone(),
+    ];
+
+    let (pks, root, stats) = build_participation_set_report(rec.slot, &parent, recs.iter(), &vrf);
+    assert_eq!(
+        stats,
+        SubmissionStats {
+            accepted: 1,
+            wrong_slot: 1,
+            duplicate_pk: 2,
+            verify_failed: 2,
+        }
+    );
+    assert_eq!(
+        (pks, root),
+        build_participation_set(rec.slot, &parent, recs.iter(), &vrf)
+    );
+}
+
+#[test]
+fn part_inclusion_proof_verifies_members_only() {
+    use obex_alpha_i::{part_leaf, verify_part_inclusion};
+    use obex_primitives::{merkle_prove, merkle_root};
+
+    let pks: Vec<[u8; 32]> = (1u8..=5).map(|b| [b; 32]).collect();
+    let leaves: Vec<Vec<u8>> = pks.iter().map(part_leaf).collect();
+    let root = merkle_root(&leaves);
+    for (i, pk) in pks.iter().enumerate() {
+        let proof = merkle_prove(&leaves, i).unwrap();
+        assert!(verify_part_inclusion(&root, pk, &proof));
+        assert!(!verify_part_inclusion(&root, &[0xee; 32], &proof));
+    }
+    let mut wrong_index = merkle_prove(&leaves, 2).unwrap();
+    wrong_index.index = 3;
+    assert!(!verify_part_inclusion(&root, &pks[2], &wrong_index));
+}
+
+#[test]
+fn part_root_orders_pks_ascending_by_big_endian_value() {
+    use obex_alpha_i::{part_leaf, part_root_from_pks};
+    use obex_primitives::merkle_root;
+
+    // Differ first in the last byte, then the first: byte-wise (big-endian) order is a < b < c,
+    // whereas a little-endian reading would put c first.
+    let mut a = [0u8; 32];
+    a[31] = 0x02;
+    let mut b = [0u8; 32];
+    b[0] = 0x01;
+    let mut c = [0u8; 32];
+    c[0] = 0x01;
+    c[31] = 0x01;
+    let root = part_root_from_pks(&[c, a, b]);
+    let leaves: Vec<Vec<u8>> = [a, b, c].iter().map(part_leaf).collect();
+    assert_eq!(root, merkle_root(&leaves));
+    assert_eq!(part_root_from_pks(&[b, c, a]), root);
+    let little_endian_order: Vec<Vec<u8>> = [c, a, b].iter().map(part_leaf).collect();
+    assert_ne!(merkle_root(&little_endian_order), root);
+    assert_eq!(
+        hex::encode(root),
+        "d7dd63aad088cc08e6db59348b9ca4dfe620708673fc8dcea1b08602122768e5"
+    );
+
+    let rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let (_, batch_root) = build_participation_set(
+        rec.slot,
+        &constants::GENESIS_PARENT_ID,
+        [rec.clone()].iter(),
+        &vrf,
+    );
+    assert_eq!(batch_root, part_root_from_pks(&[rec.pk_ed25519]));
+}
+
+#[test]
+fn root_from_verified_pks_matches_batch_builder() {
+    use obex_alpha_i::{part_root_from_pks, part_root_from_verified};
+
+    let rec = valid_rec();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let parent = constants::GENESIS_PARENT_ID;
+    let (pks, root) = build_participation_set(rec.slot, &parent, [rec.clone(), rec].iter(), &vrf);
+    assert_eq!(part_root_from_verified(&pks), root);
+    assert_eq!(part_root_from_verified(&[]), part_root_from_pks(&[]));
+
+    let many: Vec<[u8; 32]> = (1u8..=9).map(|b| [b; 32]).collect();
+    assert_eq!(part_root_from_verified(&many), part_root_from_pks(&many));
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::AcceptY;
+use ed25519_dalek::SigningKey;
+use obex_alpha_i::{encode_partrec, obex_build_partrec, obex_check_partrec, CHALLENGES_Q};
+use obex_primitives::constants;
+
+/// Full-size prover run: fills `MEM_MIB` of labels and builds the Merkle tree. The result
+/// is `tests/golden/partrec_valid_v1.bin`, a fully valid record under the `AcceptY` VRF mock.
+/// Memory- and time-heavy; run with `cargo test --release -- --ignored`.
+#[test]
+#[ignore = "allocates ~1 GiB and fills 2^24 labels; run in release with --ignored"]
+fn built_partrec_passes_verifier() {
+    let sk = SigningKey::from_bytes(&[21u8; 32]);
+    let vrf_pk = [2u8; 32];
+    let y_prev = [3u8; 32];
+    let slot = 1u64;
+    let vrf_y = vec![5u8; 64];
+    let rec = obex_build_partrec(
+        slot,
+        &constants::GENESIS_PARENT_ID,
+        &y_prev,
+        &sk,
+        &vrf_pk,
+        |_alpha| (vrf_y.clone(), vec![6u8; 80]),
+    );
+    assert_eq!(rec.challenges.len(), CHALLENGES_Q);
+    let vrf = AcceptY(vrf_y);
+    assert_eq!(
+        obex_check_partrec(&rec, slot, &constants::GENESIS_PARENT_ID, &vrf),
+        Ok(())
+    );
+    // Prover output is deterministic and pinned by the committed fixture
+    let golden = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/golden/partrec_valid_v1.bin"
+    ))
+    .unwrap();
+    assert_eq!(encode_partrec(&rec).unwrap(), golden);
+}
@@ -0,0 +1,19 @@
+use hex::ToHex;
+use obex_alpha_i::{generate_labels, lbl0, obex_seed};
+use obex_primitives::{consensus, constants};
+
+#[test]
+fn lbl0_is_pinned_and_starts_the_label_chain() {
+    let seed = obex_seed(&[1u8; 32], &[2u8; 32], &[3u8; 64]);
+    assert_eq!(
+        seed,
+        consensus::h_tag(constants::TAG_SEED, &[&[1u8; 32], &[2u8; 32], &[3u8; 64]])
+    );
+    let l0 = lbl0(&[7u8; 32]);
+    assert_eq!(
+        l0.encode_hex::<String>(),
+        "32497533cf022395a4515b2753f68c8e582d0cbe3db45c06b3e14d0b83ed4de2"
+    );
+    assert_eq!(l0, consensus::h_tag(constants::TAG_L0, &[&[7u8; 32]]));
+    assert_eq!(generate_labels(&[7u8; 32], 4, 1)[0], l0);
+}
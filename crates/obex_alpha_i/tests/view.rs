@@ -0,0 +1,48 @@
+mod common;
+
+use common::{golden, AcceptY};
+use obex_alpha_i::{
+    decode_partrec, decode_partrec_view, obex_check_partrec, obex_check_partrec_view,
+    obex_verify_partrec_bytes,
+};
+use obex_primitives::constants;
+
+#[test]
+fn view_materializes_to_owned_decode() {
+    for name in ["partrec_v1.bin", "partrec_valid_v1.bin"] {
+        let bytes = golden(name);
+        let view = decode_partrec_view(&bytes).unwrap();
+        assert_eq!(view.to_partrec(), decode_partrec(&bytes).unwrap());
+    }
+}
+
+#[test]
+fn view_and_owned_verification_agree_under_corruption() {
+    let bytes = golden("partrec_valid_v1.bin");
+    let rec = decode_partrec(&bytes).unwrap();
+    let vrf = AcceptY(rec.vrf_y.clone());
+    let parent = constants::GENESIS_PARENT_ID;
+    // Flip one byte at a spread of offsets across header, challenges, and signature
+    for off in (0..bytes.len()).step_by(997).chain([bytes.len() - 1]) {
+        let mut b = bytes.clone();
+        b[off] ^= 0x01;
+        let owned = decode_partrec(&b).map(|r| obex_check_partrec(&r, rec.slot, &parent, &vrf));
+        let view =
+            decode_partrec_view(&b).map(|v| obex_check_partrec_view(&v, rec.slot, &parent, &vrf));
+        match (owned, view) {
+            (Ok(o), Ok(v)) => assert_eq!(o, v, "offset {off}"),
+            (Err(_), Err(_)) => {}
+            (o, v) => panic!("decode disagreement at offset {off}: {o:?} vs {v:?}"),
+        }
+    }
+    assert_eq!(
+        obex_check_partrec_view(
+            &decode_partrec_view(&bytes).unwrap(),
+            rec.slot,
+            &parent,
+            &vrf
+        ),
+        Ok(())
+    );
+    assert!(obex_verify_partrec_bytes(&bytes, rec.slot, &parent, &vrf));
+}
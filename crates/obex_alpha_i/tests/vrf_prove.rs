@@ -0,0 +1,55 @@
+#![cfg(feature = "ecvrf_rfc9381")]
+
+use obex_alpha_i::vrf::{
+    ecvrf_verify_beta_tai_consensus, prove, prove_msg_tai, public_key, verify, VrfError,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn prove_verify_roundtrip_and_beta_crosscheck() {
+    for seed in [1u8, 7, 42, 255] {
+        let sk = [seed; 32];
+        let pk = public_key(&sk);
+        let alpha = [seed.wrapping_mul(3); 32];
+        let (pi, y) = prove(&sk, &alpha).unwrap();
+        assert_eq!(verify(&pk, &alpha, &pi), Ok(y));
+        assert_eq!(ecvrf_verify_beta_tai_consensus(&pk, &alpha, &pi), Some(y));
+        // Deterministic proofs
+        assert_eq!(prove(&sk, &alpha).unwrap(), (pi, y));
+        // Proof does not verify under another alpha or key
+        let mut other_alpha = alpha;
+        other_alpha[0] ^= 1;
+        assert_eq!(
+            verify(&pk, &other_alpha, &pi),
+            Err(VrfError::VerificationFailed)
+        );
+        assert!(verify(&public_key(&[seed ^ 0x80; 32]), &alpha, &pi).is_err());
+    }
+}
+
+#[test]
+fn prove_matches_rfc9381_tai_example() {
+    // RFC 9381 Appendix B.3, example 16 (empty alpha)
+    let sk: [u8; 32] = hex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        public_key(&sk).to_vec(),
+        hex("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+    );
+    let (pi, y) = prove_msg_tai(&sk, &[]).unwrap();
+    assert_eq!(
+        pi.to_vec(),
+        hex("8657106690b5526245a92b003bb079ccd1a92130477671f6fc01ad16f26f723f26f8a57ccaed74ee1b190bed1f479d9727d2d0f9b005a6e456a35d4fb0daab1268a1b0db10836d9826a528ca76567805")
+    );
+    assert_eq!(
+        y.to_vec(),
+        hex("90cf1df3b703cce59e2a35b925d411164068269d7b2d29f3301c03dd757876ff66b71dda49d2de59d03450451af026798e8f81cd2e333de5cdf4f3e140fdd8ae")
+    );
+}
@@ -66,11 +66,7 @@ fn rfc9381_tai_valid_vectors() {
             VRF_Y_BYTES,
             "Beta should be {VRF_Y_BYTES} bytes",
         );
-        assert_eq!(
-            beta.to_vec(),
-            expected_beta,
-            "Beta mismatch in vector {i}",
-        );
+        assert_eq!(beta.to_vec(), expected_beta, "Beta mismatch in vector {i}",);
     }
 }
 
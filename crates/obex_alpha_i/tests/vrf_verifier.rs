@@ -0,0 +1,70 @@
+#![cfg(feature = "ecvrf_rfc9381")]
+
+use obex_alpha_i::vrf::{ecvrf_verify_beta_tai_opt, prove, public_key, Rfc9381TaiVerifier};
+use obex_alpha_i::{decode_partrec, obex_check_partrec, EcVrfVerifier, VerifyErr};
+use obex_primitives::{consensus::h_tag, constants, le_bytes};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn rfc_key_verifies_through_trait_and_partrec_vrf_step() {
+    // RFC 9381 Appendix B.3 example key; α is the record's 32-byte obex.alpha.
+    let sk: [u8; 32] = hex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+        .try_into()
+        .unwrap();
+    let vk = public_key(&sk);
+    let parent = constants::GENESIS_PARENT_ID;
+
+    let mut rec = decode_partrec(
+        &std::fs::read(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/golden/partrec_valid_v1.bin"
+        ))
+        .unwrap(),
+    )
+    .unwrap();
+    rec.vrf_pk = vk;
+    rec.alpha = h_tag(
+        "obex.alpha",
+        &[
+            &parent,
+            &le_bytes::<8>(u128::from(rec.slot)),
+            &rec.y_edge_prev,
+            &vk,
+        ],
+    );
+    let (pi, beta) = prove(&sk, &rec.alpha).unwrap();
+    rec.vrf_pi = pi.to_vec();
+    rec.vrf_y = beta.to_vec();
+
+    let vrf = Rfc9381TaiVerifier;
+    assert_eq!(vrf.verify(&vk, &rec.alpha, &pi), Some(beta.to_vec()));
+    assert_eq!(
+        ecvrf_verify_beta_tai_opt(vk, rec.alpha, &pi).map(|b| b.to_vec()),
+        vrf.verify(&vk, &rec.alpha, &pi)
+    );
+    assert_eq!(vrf.verify(&vk, &rec.alpha, &pi[..79]), None);
+
+    // The VRF step passes; the golden seed was derived from a different vrf_y.
+    assert_eq!(
+        obex_check_partrec(&rec, rec.slot, &parent, &vrf),
+        Err(VerifyErr::SeedMismatch)
+    );
+    let mut forged = rec.clone();
+    forged.vrf_pi[0] ^= 1;
+    assert_eq!(
+        obex_check_partrec(&forged, rec.slot, &parent, &vrf),
+        Err(VerifyErr::VrfVerifyFailed)
+    );
+    let mut wrong_y = rec;
+    wrong_y.vrf_y[0] ^= 1;
+    assert_eq!(
+        obex_check_partrec(&wrong_y, wrong_y.slot, &parent, &vrf),
+        Err(VerifyErr::VrfOutputMismatch)
+    );
+}
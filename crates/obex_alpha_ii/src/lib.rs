@@ -19,7 +19,10 @@
 //! per `obex.alpha II.txt`. Providers for beacon, participation, admission, and tx roots
 //! are passed via traits.
 
-use obex_primitives::{consensus, ct_eq_hash, le_bytes, Hash256};
+use obex_primitives::{
+    consensus, constants, ct_eq_hash, kat, le_bytes, le_len4, merkle_root, Hash256, Pk32,
+};
+use std::collections::BTreeMap;
 use thiserror::Error;
 // Anchor to ensure SHA3-256 presence without underscore-binding side effects.
 pub use obex_primitives::OBEX_SHA3_256_ANCHOR as _obex_sha3_anchor_ii;
@@ -29,6 +32,8 @@ pub const OBEX_ALPHA_II_VERSION: u32 = 2;
 /// Consensus size caps for beacon fields (deployment-defined; enforced before verification).
 pub const MAX_PI_LEN: usize = 1_048_576; // example: 1 MiB
 pub const MAX_ELL_LEN: usize = 65_536; // example: 64 KiB
+/// Largest canonical header encoding: fixed fields, two `LE(4)` prefixes and both capped fields.
+pub const MAX_HEADER_SIZE: usize = 32 + 8 + 4 + 3 * 32 + 4 + MAX_PI_LEN + 4 + MAX_ELL_LEN + 3 * 32;
 
 /// Providers (adapters) for equality checks
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -56,8 +61,72 @@ pub trait TxRootProvider {
     fn compute_txroot(&self, slot: u64) -> Hash256;
 }
 
+// ——— Canonical root builders and a concrete provider ————————————————
+
+/// `part_root` over `P_s`, as α I builds it (sorted ascending, deduplicated).
+pub use obex_alpha_i::part_root_from_pks;
+
+/// Concrete root provider: per-slot roots recorded from real data, empty root for unset slots.
+///
+/// Ticket leaves are α-III `enc_ticket_leaf` payloads (`H("obex.ticket.leaf",[]) || fields`),
+/// supplied in txid order as `build_ticket_root_for_slot` returns them. The txroot covers α-T
+/// system transactions as well, so it is recorded as the root α-III `build_txroot_for_slot` (or
+/// `txroot_prev_from_settlement`) computes rather than rebuilt here.
+#[derive(Clone, Debug, Default)]
+pub struct SlotRoots {
+    part: BTreeMap<u64, Hash256>,
+    ticket: BTreeMap<u64, Hash256>,
+    tx: BTreeMap<u64, Hash256>,
+}
+
+impl SlotRoots {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_part_pks(&mut self, slot: u64, pks: &[Pk32]) {
+        self.part.insert(slot, part_root_from_pks(pks));
+    }
+
+    pub fn set_ticket_leaves(&mut self, slot: u64, leaves_in_txid_order: &[Vec<u8>]) {
+        self.ticket.insert(slot, merkle_root(leaves_in_txid_order));
+    }
+
+    pub fn set_txroot(&mut self, slot: u64, txroot: Hash256) {
+        self.tx.insert(slot, txroot);
+    }
+
+    fn get(map: &BTreeMap<u64, Hash256>, slot: u64) -> Hash256 {
+        map.get(&slot).copied().unwrap_or_else(|| merkle_root(&[]))
+    }
+}
+
+impl TicketRootProvider for SlotRoots {
+    fn compute_ticket_root(&self, slot: u64) -> Hash256 {
+        Self::get(&self.ticket, slot)
+    }
+}
+
+impl PartRootProvider for SlotRoots {
+    fn compute_part_root(&self, slot: u64) -> Hash256 {
+        Self::get(&self.part, slot)
+    }
+}
+
+impl TxRootProvider for SlotRoots {
+    fn compute_txroot(&self, slot: u64) -> Hash256 {
+        Self::get(&self.tx, slot)
+    }
+}
+
 /// Canonical header object
+///
+/// With the `serde` feature, `Header` also derives `Serialize`/`Deserialize` for RPC and
+/// gossip transport. Serde bytes are non-consensus transport only: `serialize_header` /
+/// `deserialize_header` remain the canonical wire format and the input to `obex_header_id`.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Header {
     pub parent_id: Hash256,
     pub slot: u64,
@@ -67,7 +136,9 @@ pub struct Header {
     pub seed_commit: Hash256,
     pub vdf_y_core: Hash256,
     pub vdf_y_edge: Hash256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_hex_bytes"))]
     pub vdf_pi: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_hex_bytes"))]
     pub vdf_ell: Vec<u8>,
 
     // Deterministic commitments
@@ -76,7 +147,67 @@ pub struct Header {
     pub txroot_prev: Hash256,
 }
 
-/// Canonical header ID over field values (not transport bytes)
+/// Serde adapter for variable-length byte fields: hex strings in human-readable formats
+/// (JSON), raw bytes otherwise (bincode). Non-consensus.
+#[cfg(feature = "serde")]
+mod serde_hex_bytes {
+    use core::fmt;
+    use serde::de::{self, SeqAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], ser: S) -> Result<S::Ok, S::Error> {
+        if ser.is_human_readable() {
+            ser.serialize_str(&hex::encode(bytes))
+        } else {
+            ser.serialize_bytes(bytes)
+        }
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a hex string or a byte array")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            hex::decode(v).map_err(E::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(b) = seq.next_element::<u8>()? {
+                out.push(b);
+            }
+            Ok(out)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<Vec<u8>, D::Error> {
+        if de.is_human_readable() {
+            de.deserialize_str(BytesVisitor)
+        } else {
+            de.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+}
+
+/// Canonical header ID over field values (not transport bytes).
+///
+/// # Panics
+///
+/// If `vdf_pi` or `vdf_ell` is `2^32` bytes or longer. Decoded and validated headers are
+/// capped far below that by `MAX_PI_LEN` / `MAX_ELL_LEN`.
 #[must_use]
 pub fn obex_header_id(h: &Header) -> Hash256 {
     consensus::h_tag(
@@ -88,9 +219,9 @@ pub fn obex_header_id(h: &Header) -> Hash256 {
             &h.seed_commit,
             &h.vdf_y_core,
             &h.vdf_y_edge,
-            &le_bytes::<4>(h.vdf_pi.len() as u128),
+            &le_len4(h.vdf_pi.len()).expect("vdf_pi length exceeds u32 prefix"),
             &h.vdf_pi,
-            &le_bytes::<4>(h.vdf_ell.len() as u128),
+            &le_len4(h.vdf_ell.len()).expect("vdf_ell length exceeds u32 prefix"),
             &h.vdf_ell,
             &h.ticket_root,
             &h.part_root,
@@ -99,6 +230,79 @@ pub fn obex_header_id(h: &Header) -> Hash256 {
     )
 }
 
+/// Bytes of `vdf_pi` / `vdf_ell` shown by [`Header::display_hex`] before eliding.
+const DISPLAY_BLOB_PREFIX: usize = 32;
+
+fn display_blob(bytes: &[u8]) -> String {
+    let shown = &bytes[..bytes.len().min(DISPLAY_BLOB_PREFIX)];
+    let ellipsis = if shown.len() < bytes.len() { "..." } else { "" };
+    format!("len={} {}{ellipsis}", bytes.len(), kat::to_hex(shown))
+}
+
+impl Header {
+    /// Multi-line diagnostic dump: one `name: value` line per field, hashes in hex.
+    ///
+    /// Variable-length beacon blobs are shown as their length plus a hex prefix. The final
+    /// line is the computed `obex_header_id`. Non-consensus; meant for test failures and logs.
+    #[must_use]
+    pub fn display_hex(&self) -> String {
+        [
+            format!("parent_id: {}", kat::to_hex(&self.parent_id)),
+            format!("slot: {}", self.slot),
+            format!("obex_version: {}", self.obex_version),
+            format!("seed_commit: {}", kat::to_hex(&self.seed_commit)),
+            format!("vdf_y_core: {}", kat::to_hex(&self.vdf_y_core)),
+            format!("vdf_y_edge: {}", kat::to_hex(&self.vdf_y_edge)),
+            format!("vdf_pi: {}", display_blob(&self.vdf_pi)),
+            format!("vdf_ell: {}", display_blob(&self.vdf_ell)),
+            format!("ticket_root: {}", kat::to_hex(&self.ticket_root)),
+            format!("part_root: {}", kat::to_hex(&self.part_root)),
+            format!("txroot_prev: {}", kat::to_hex(&self.txroot_prev)),
+            format!("header_id: {}", kat::to_hex(&obex_header_id(self))),
+        ]
+        .join("\n")
+    }
+}
+
+/// One field on which two headers disagree, as rendered by `header_field_diff`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderFieldDiff {
+    pub field: &'static str,
+    pub a: String,
+    pub b: String,
+}
+
+/// Fields of `h` in wire order: integers in decimal, hashes and beacon blobs in full hex.
+fn header_fields(h: &Header) -> [(&'static str, String); 11] {
+    [
+        ("parent_id", kat::to_hex(&h.parent_id)),
+        ("slot", h.slot.to_string()),
+        ("obex_version", h.obex_version.to_string()),
+        ("seed_commit", kat::to_hex(&h.seed_commit)),
+        ("vdf_y_core", kat::to_hex(&h.vdf_y_core)),
+        ("vdf_y_edge", kat::to_hex(&h.vdf_y_edge)),
+        ("vdf_pi", kat::to_hex(&h.vdf_pi)),
+        ("vdf_ell", kat::to_hex(&h.vdf_ell)),
+        ("ticket_root", kat::to_hex(&h.ticket_root)),
+        ("part_root", kat::to_hex(&h.part_root)),
+        ("txroot_prev", kat::to_hex(&h.txroot_prev)),
+    ]
+}
+
+/// Every field on which `a` and `b` differ, in wire order, with both values.
+///
+/// Empty iff `a == b`. Non-consensus; for comparing a candidate against a locally rebuilt
+/// header when `validate_header` fails.
+#[must_use]
+pub fn header_field_diff(a: &Header, b: &Header) -> Vec<HeaderFieldDiff> {
+    header_fields(a)
+        .into_iter()
+        .zip(header_fields(b))
+        .filter(|((_, va), (_, vb))| va != vb)
+        .map(|((field, a), (_, b))| HeaderFieldDiff { field, a, b })
+        .collect()
+}
+
 // ——— Canonical header serializer/deserializer (wire layout §4.1) ————
 
 #[derive(Debug, Error)]
@@ -120,6 +324,11 @@ const fn read_exact<'a>(src: &mut &'a [u8], n: usize) -> Result<&'a [u8], CodecE
     Ok(a)
 }
 
+/// Canonical wire bytes of `h`.
+///
+/// # Panics
+///
+/// As `obex_header_id`, if a beacon blob does not fit its `LE(len, 4)` prefix.
 #[must_use]
 pub fn serialize_header(h: &Header) -> Vec<u8> {
     let mut out = Vec::new();
@@ -130,9 +339,9 @@ pub fn serialize_header(h: &Header) -> Vec<u8> {
     out.extend_from_slice(&h.seed_commit);
     out.extend_from_slice(&h.vdf_y_core);
     out.extend_from_slice(&h.vdf_y_edge);
-    out.extend_from_slice(&le_bytes::<4>(h.vdf_pi.len() as u128));
+    out.extend_from_slice(&le_len4(h.vdf_pi.len()).expect("vdf_pi length exceeds u32 prefix"));
     out.extend_from_slice(&h.vdf_pi);
-    out.extend_from_slice(&le_bytes::<4>(h.vdf_ell.len() as u128));
+    out.extend_from_slice(&le_len4(h.vdf_ell.len()).expect("vdf_ell length exceeds u32 prefix"));
     out.extend_from_slice(&h.vdf_ell);
 
     out.extend_from_slice(&h.ticket_root);
@@ -168,6 +377,7 @@ pub fn deserialize_header(mut src: &[u8]) -> Result<Header, CodecError> {
         a.copy_from_slice(b);
         a
     };
+    // Size caps are enforced on the length prefix itself, before any allocation.
     let pi_len = u32::from_le_bytes(read_exact(&mut src, 4)?.try_into().unwrap()) as usize;
     if pi_len > MAX_PI_LEN {
         return Err(CodecError::TooLong);
@@ -214,6 +424,48 @@ pub fn deserialize_header(mut src: &[u8]) -> Result<Header, CodecError> {
     })
 }
 
+// ——— Legacy v1 headers ————————————————————————————————————————————————
+
+/// Version tag of pre-participation headers.
+///
+/// The v1 wire layout is the v2 layout (§4.1) without `part_root`: `ticket_root` is followed
+/// directly by `txroot_prev`.
+pub const OBEX_ALPHA_II_V1_VERSION: u32 = 1;
+
+#[derive(Debug, Error)]
+pub enum MigrateErr {
+    #[error("malformed v1 header: {0}")]
+    Codec(#[from] CodecError),
+    #[error("expected a v1 header, found version {0}")]
+    NotV1(u32),
+}
+
+/// Upgrade stored v1 header bytes to the current `Header`.
+///
+/// v1 had no participation set, so the migrated `part_root` is the empty-set root
+/// `part_root_from_pks(&[])`; every other field is carried over verbatim and `obex_version`
+/// becomes `OBEX_ALPHA_II_VERSION`. The migrated header's `obex_header_id` therefore differs
+/// from the v1 id, and `parent_id` still names the v1 parent: migrated history is for
+/// archival reads, not for re-validation as a v2 chain.
+pub fn migrate_header_v1_to_v2(src: &[u8]) -> Result<Header, MigrateErr> {
+    if src.len() < 32 {
+        return Err(CodecError::Short.into());
+    }
+    // Splice the empty part_root in before the trailing txroot_prev; the v2 decoder then
+    // enforces the length prefixes, so only an exact v1 encoding decodes.
+    let (head, txroot_prev) = src.split_at(src.len() - 32);
+    let mut v2 = Vec::with_capacity(src.len() + 32);
+    v2.extend_from_slice(head);
+    v2.extend_from_slice(&part_root_from_pks(&[]));
+    v2.extend_from_slice(txroot_prev);
+    let mut h = deserialize_header(&v2)?;
+    if h.obex_version != OBEX_ALPHA_II_V1_VERSION {
+        return Err(MigrateErr::NotV1(h.obex_version));
+    }
+    h.obex_version = OBEX_ALPHA_II_VERSION;
+    Ok(h)
+}
+
 /// Build the canonical header for slot s = parent.slot + 1.
 #[must_use]
 pub fn build_header(
@@ -246,12 +498,182 @@ pub fn build_header(
     }
 }
 
+/// Beacon seed for `slot` on top of `parent_id`: `H("obex.slot.seed", [parent_id, LE(slot,8)])`.
+#[must_use]
+pub fn slot_seed_commit(parent_id: &Hash256, slot: u64) -> Hash256 {
+    consensus::h_tag(
+        "obex.slot.seed",
+        &[parent_id, &le_bytes::<8>(u128::from(slot))],
+    )
+}
+
+/// Beacon evaluation for one slot, produced by a `BeaconProver`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BeaconOutput {
+    pub vdf_y_core: Hash256,
+    pub vdf_y_edge: Hash256,
+    pub vdf_pi: Vec<u8>,
+    pub vdf_ell: Vec<u8>,
+}
+
+/// Producer-side beacon adapter: evaluates the VDF for `seed_commit` at `slot`.
+pub trait BeaconProver {
+    fn prove(&self, parent_id: &Hash256, slot: u64, seed_commit: &Hash256) -> BeaconOutput;
+}
+
+/// Beacon edge for a VDF output: `H("obex.vdf.edge", [vdf_y_core])`.
+#[must_use]
+pub fn vdf_edge(vdf_y_core: &Hash256) -> Hash256 {
+    consensus::h_tag(constants::TAG_VDF_EDGE, &[vdf_y_core])
+}
+
+/// Canonical `BeaconVerifier`: checks `seed_commit` against `slot_seed_commit`, `vdf_y_edge`
+/// against `vdf_edge(vdf_y_core)`, then defers the VDF proof itself to `vdf_check`.
+///
+/// `vdf_check(seed_commit, vdf_y_core, vdf_pi, vdf_ell)` is the deployment's VDF verifier; it
+/// is only called once both hash equalities hold.
+#[derive(Clone, Copy, Debug)]
+pub struct SlotSeedBeacon<F> {
+    vdf_check: F,
+}
+
+impl<F> SlotSeedBeacon<F>
+where
+    F: Fn(&Hash256, &Hash256, &[u8], &[u8]) -> bool,
+{
+    #[must_use]
+    pub const fn new(vdf_check: F) -> Self {
+        Self { vdf_check }
+    }
+}
+
+impl<F> BeaconVerifier for SlotSeedBeacon<F>
+where
+    F: Fn(&Hash256, &Hash256, &[u8], &[u8]) -> bool,
+{
+    fn verify(&self, i: &BeaconInputs<'_>) -> bool {
+        ct_eq_hash(i.seed_commit, &slot_seed_commit(i.parent_id, i.slot))
+            && ct_eq_hash(i.vdf_y_edge, &vdf_edge(i.vdf_y_core))
+            && (self.vdf_check)(i.seed_commit, i.vdf_y_core, i.vdf_pi, i.vdf_ell)
+    }
+}
+
+/// Fields of the genesis header that are deployment-chosen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenesisParams {
+    pub obex_version: u32,
+    pub beacon: BeaconOutput,
+    pub ticket_root: Hash256,
+    pub part_root: Hash256,
+}
+
+impl Default for GenesisParams {
+    fn default() -> Self {
+        Self {
+            obex_version: OBEX_ALPHA_II_VERSION,
+            beacon: BeaconOutput::default(),
+            ticket_root: [0u8; 32],
+            part_root: [0u8; 32],
+        }
+    }
+}
+
+/// Genesis header: `GENESIS_PARENT_ID`, `GENESIS_SLOT`, `TXROOT_GENESIS`, and the slot seed
+/// equality applied as for every later slot.
+#[must_use]
+pub fn genesis_header(params: GenesisParams) -> Header {
+    let parent_id = constants::GENESIS_PARENT_ID;
+    let slot = constants::GENESIS_SLOT;
+    Header {
+        parent_id,
+        slot,
+        obex_version: params.obex_version,
+        seed_commit: slot_seed_commit(&parent_id, slot),
+        vdf_y_core: params.beacon.vdf_y_core,
+        vdf_y_edge: params.beacon.vdf_y_edge,
+        vdf_pi: params.beacon.vdf_pi,
+        vdf_ell: params.beacon.vdf_ell,
+        ticket_root: params.ticket_root,
+        part_root: params.part_root,
+        txroot_prev: constants::TXROOT_GENESIS,
+    }
+}
+
+/// Extends a tip header one slot at a time via `build_header`, deriving `seed_commit` from
+/// the tip and the beacon fields from a `BeaconProver`.
+pub struct HeaderChainBuilder<'a, B, T, P, X> {
+    tip: Header,
+    beacon: &'a B,
+    ticket_roots: &'a T,
+    part_roots: &'a P,
+    tx_roots: &'a X,
+    obex_version: u32,
+}
+
+impl<'a, B, T, P, X> HeaderChainBuilder<'a, B, T, P, X>
+where
+    B: BeaconProver,
+    T: TicketRootProvider,
+    P: PartRootProvider,
+    X: TxRootProvider,
+{
+    #[must_use]
+    pub const fn new(
+        tip: Header,
+        beacon: &'a B,
+        ticket_roots: &'a T,
+        part_roots: &'a P,
+        tx_roots: &'a X,
+        obex_version: u32,
+    ) -> Self {
+        Self {
+            tip,
+            beacon,
+            ticket_roots,
+            part_roots,
+            tx_roots,
+            obex_version,
+        }
+    }
+
+    #[must_use]
+    pub const fn tip(&self) -> &Header {
+        &self.tip
+    }
+
+    /// Build the header for `tip.slot + 1`, make it the new tip, and return it.
+    pub fn next_header(&mut self) -> Header {
+        let parent_id = obex_header_id(&self.tip);
+        let slot = self.tip.slot + 1;
+        let seed_commit = slot_seed_commit(&parent_id, slot);
+        let b = self.beacon.prove(&parent_id, slot, &seed_commit);
+        let h = build_header(
+            &self.tip,
+            (seed_commit, b.vdf_y_core, b.vdf_y_edge, b.vdf_pi, b.vdf_ell),
+            self.ticket_roots,
+            self.part_roots,
+            self.tx_roots,
+            self.obex_version,
+        );
+        self.tip = h.clone();
+        h
+    }
+
+    /// Build the next `n` headers in slot order.
+    pub fn build(&mut self, n: usize) -> Vec<Header> {
+        (0..n).map(|_| self.next_header()).collect()
+    }
+}
+
 /// Validation errors
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ValidateErr {
     BadParentLink,
     BadSlot,
+    /// `seed_commit` differs from `H("obex.slot.seed", [parent_id, LE(slot,8)])`; checked
+    /// before the beacon is consulted.
     BadSeedCommit,
+    /// The beacon verifier rejected the VDF output, proof or edge.
     BeaconInvalid,
     TicketRootMismatch,
     PartRootMismatch,
@@ -259,6 +681,8 @@ pub enum ValidateErr {
     VersionMismatch,
     VdfPiTooBig,
     VdfEllTooBig,
+    /// Canonical bytes over `MAX_HEADER_SIZE` or not decodable (`validate_header_bytes` only).
+    Malformed,
 }
 
 /// Validate a candidate header against deterministic equalities.
@@ -270,6 +694,22 @@ pub fn validate_header(
     part_roots: &impl PartRootProvider,
     tx_roots: &impl TxRootProvider,
     expected_version: u32,
+) -> Result<(), ValidateErr> {
+    check_header_local(h, parent, beacon, expected_version)?;
+    check_header_roots(
+        h,
+        &ticket_roots.compute_ticket_root(h.slot),
+        &part_roots.compute_part_root(h.slot),
+        &tx_roots.compute_txroot(parent.slot),
+    )
+}
+
+/// Steps 1–4 of `validate_header`: everything not needing a root provider.
+fn check_header_local(
+    h: &Header,
+    parent: &Header,
+    beacon: &impl BeaconVerifier,
+    expected_version: u32,
 ) -> Result<(), ValidateErr> {
     // 1) Parent linkage & slot progression
     let parent_id_expected = obex_header_id(parent);
@@ -291,12 +731,8 @@ pub fn validate_header(
         return Err(ValidateErr::VdfEllTooBig);
     }
 
-    // 3) Beacon verification
     // 3) Seed commit equality: seed_commit == H("obex.slot.seed", [parent_id, LE(slot,8)])
-    let seed_commit_local = consensus::h_tag(
-        "obex.slot.seed",
-        &[&h.parent_id, &le_bytes::<8>(u128::from(h.slot))],
-    );
+    let seed_commit_local = slot_seed_commit(&h.parent_id, h.slot);
     if !ct_eq_hash(&h.seed_commit, &seed_commit_local) {
         return Err(ValidateErr::BadSeedCommit);
     }
@@ -314,27 +750,181 @@ pub fn validate_header(
         return Err(ValidateErr::BeaconInvalid);
     }
 
+    Ok(())
+}
+
+/// Steps 5–7 of `validate_header`: equality against locally computed roots.
+fn check_header_roots(
+    h: &Header,
+    ticket_root_local: &Hash256,
+    part_root_local: &Hash256,
+    txroot_prev_local: &Hash256,
+) -> Result<(), ValidateErr> {
     // 5) Ticket root equality (slot s)
-    let ticket_root_local = ticket_roots.compute_ticket_root(h.slot);
-    if !ct_eq_hash(&h.ticket_root, &ticket_root_local) {
+    if !ct_eq_hash(&h.ticket_root, ticket_root_local) {
         return Err(ValidateErr::TicketRootMismatch);
     }
 
     // 6) Participation root equality (slot s)
-    let part_root_local = part_roots.compute_part_root(h.slot);
-    if !ct_eq_hash(&h.part_root, &part_root_local) {
+    if !ct_eq_hash(&h.part_root, part_root_local) {
         return Err(ValidateErr::PartRootMismatch);
     }
 
     // 7) Transaction root equality (slot s-1)
-    let txroot_prev_local = tx_roots.compute_txroot(parent.slot);
-    if !ct_eq_hash(&h.txroot_prev, &txroot_prev_local) {
+    if !ct_eq_hash(&h.txroot_prev, txroot_prev_local) {
         return Err(ValidateErr::TxRootPrevMismatch);
     }
 
     Ok(())
 }
 
+// ——— Async providers (feature `async`) ——————————————————————————————
+
+/// Async counterpart of `TicketRootProvider` for roots backed by async storage.
+#[cfg(feature = "async")]
+pub trait AsyncTicketRootProvider: Sync {
+    fn compute_ticket_root(&self, slot: u64) -> impl core::future::Future<Output = Hash256> + Send;
+}
+/// Async counterpart of `PartRootProvider`.
+#[cfg(feature = "async")]
+pub trait AsyncPartRootProvider: Sync {
+    fn compute_part_root(&self, slot: u64) -> impl core::future::Future<Output = Hash256> + Send;
+}
+/// Async counterpart of `TxRootProvider`.
+#[cfg(feature = "async")]
+pub trait AsyncTxRootProvider: Sync {
+    fn compute_txroot(&self, slot: u64) -> impl core::future::Future<Output = Hash256> + Send;
+}
+
+/// Adapter exposing a synchronous provider through the async provider traits.
+#[cfg(feature = "async")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SyncProvider<P>(pub P);
+
+#[cfg(feature = "async")]
+impl<P: TicketRootProvider + Sync> AsyncTicketRootProvider for SyncProvider<P> {
+    async fn compute_ticket_root(&self, slot: u64) -> Hash256 {
+        self.0.compute_ticket_root(slot)
+    }
+}
+#[cfg(feature = "async")]
+impl<P: PartRootProvider + Sync> AsyncPartRootProvider for SyncProvider<P> {
+    async fn compute_part_root(&self, slot: u64) -> Hash256 {
+        self.0.compute_part_root(slot)
+    }
+}
+#[cfg(feature = "async")]
+impl<P: TxRootProvider + Sync> AsyncTxRootProvider for SyncProvider<P> {
+    async fn compute_txroot(&self, slot: u64) -> Hash256 {
+        self.0.compute_txroot(slot)
+    }
+}
+
+/// `validate_header` with async root providers.
+///
+/// The local checks (linkage, slot, version, sizes, seed commit, beacon) run first, so no root
+/// is fetched for a header that fails them; then the three roots are awaited in order and
+/// compared exactly as in `validate_header`.
+#[cfg(feature = "async")]
+pub async fn validate_header_async(
+    h: &Header,
+    parent: &Header,
+    beacon: &(impl BeaconVerifier + Sync),
+    ticket_roots: &impl AsyncTicketRootProvider,
+    part_roots: &impl AsyncPartRootProvider,
+    tx_roots: &impl AsyncTxRootProvider,
+    expected_version: u32,
+) -> Result<(), ValidateErr> {
+    check_header_local(h, parent, beacon, expected_version)?;
+    let ticket_root_local = ticket_roots.compute_ticket_root(h.slot).await;
+    let part_root_local = part_roots.compute_part_root(h.slot).await;
+    let txroot_prev_local = tx_roots.compute_txroot(parent.slot).await;
+    check_header_roots(h, &ticket_root_local, &part_root_local, &txroot_prev_local)
+}
+
+/// Validate a header received as canonical bytes: rejects input over `MAX_HEADER_SIZE` or
+/// failing `deserialize_header` with `Malformed` before any provider or beacon work.
+pub fn validate_header_bytes(
+    bytes: &[u8],
+    parent: &Header,
+    beacon: &impl BeaconVerifier,
+    ticket_roots: &impl TicketRootProvider,
+    part_roots: &impl PartRootProvider,
+    tx_roots: &impl TxRootProvider,
+    expected_version: u32,
+) -> Result<(), ValidateErr> {
+    if bytes.len() > MAX_HEADER_SIZE {
+        return Err(ValidateErr::Malformed);
+    }
+    let h = deserialize_header(bytes).map_err(|_| ValidateErr::Malformed)?;
+    validate_header(
+        &h,
+        parent,
+        beacon,
+        ticket_roots,
+        part_roots,
+        tx_roots,
+        expected_version,
+    )
+}
+
+/// Validate a contiguous segment of headers on top of a trusted anchor (e.g. genesis).
+///
+/// Each header is checked with `validate_header` against its predecessor, so parent linkage
+/// via `obex_header_id` and slot progression by exactly 1 are enforced along the segment.
+/// Returns the index (within `headers`) and error of the first failing header.
+pub fn validate_header_chain(
+    headers: &[Header],
+    genesis: &Header,
+    beacon: &impl BeaconVerifier,
+    ticket_roots: &impl TicketRootProvider,
+    part_roots: &impl PartRootProvider,
+    tx_roots: &impl TxRootProvider,
+    expected_version: u32,
+) -> Result<(), (usize, ValidateErr)> {
+    let mut parent = genesis;
+    for (i, h) in headers.iter().enumerate() {
+        validate_header(
+            h,
+            parent,
+            beacon,
+            ticket_roots,
+            part_roots,
+            tx_roots,
+            expected_version,
+        )
+        .map_err(|e| (i, e))?;
+        parent = h;
+    }
+    Ok(())
+}
+
+/// Evidence that two distinct headers were produced for the same `(parent_id, slot)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EquivocationProof {
+    pub parent_id: Hash256,
+    pub slot: u64,
+    pub id_a: Hash256,
+    pub id_b: Hash256,
+}
+
+/// Detect equivocation: `Some` iff `a` and `b` share `parent_id` and `slot` but have different
+/// `obex_header_id`s. Validity of either header is not checked here.
+#[must_use]
+pub fn detect_equivocation(a: &Header, b: &Header) -> Option<EquivocationProof> {
+    if a.parent_id != b.parent_id || a.slot != b.slot {
+        return None;
+    }
+    let id_a = obex_header_id(a);
+    let id_b = obex_header_id(b);
+    (id_a != id_b).then_some(EquivocationProof {
+        parent_id: a.parent_id,
+        slot: a.slot,
+        id_a,
+        id_b,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
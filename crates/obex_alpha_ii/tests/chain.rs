@@ -0,0 +1,138 @@
+use obex_alpha_ii::*;
+use obex_primitives::{constants, h_tag, le_bytes, Hash256};
+
+struct BeaconOk;
+impl BeaconVerifier for BeaconOk {
+    fn verify(&self, _i: &BeaconInputs<'_>) -> bool {
+        true
+    }
+}
+struct Zero;
+impl TicketRootProvider for Zero {
+    fn compute_ticket_root(&self, _: u64) -> Hash256 {
+        [0; 32]
+    }
+}
+impl PartRootProvider for Zero {
+    fn compute_part_root(&self, _: u64) -> Hash256 {
+        [0; 32]
+    }
+}
+impl TxRootProvider for Zero {
+    fn compute_txroot(&self, _: u64) -> Hash256 {
+        [0; 32]
+    }
+}
+
+fn genesis() -> Header {
+    Header {
+        parent_id: constants::GENESIS_PARENT_ID,
+        slot: constants::GENESIS_SLOT,
+        obex_version: OBEX_ALPHA_II_VERSION,
+        seed_commit: [1; 32],
+        vdf_y_core: [2; 32],
+        vdf_y_edge: [3; 32],
+        vdf_pi: vec![],
+        vdf_ell: vec![],
+        ticket_root: [0; 32],
+        part_root: [0; 32],
+        txroot_prev: [0; 32],
+    }
+}
+
+fn extend(parent: &Header) -> Header {
+    let s = parent.slot + 1;
+    let seed_commit = h_tag(
+        constants::TAG_SLOT_SEED,
+        &[&obex_header_id(parent), &le_bytes::<8>(u128::from(s))],
+    );
+    build_header(
+        parent,
+        (seed_commit, [5; 32], [6; 32], vec![], vec![]),
+        &Zero,
+        &Zero,
+        &Zero,
+        OBEX_ALPHA_II_VERSION,
+    )
+}
+
+fn segment(g: &Header, n: usize) -> Vec<Header> {
+    let mut out: Vec<Header> = Vec::with_capacity(n);
+    for _ in 0..n {
+        let next = extend(out.last().unwrap_or(g));
+        out.push(next);
+    }
+    out
+}
+
+#[test]
+fn chain_of_three_validates() {
+    let g = genesis();
+    let headers = segment(&g, 3);
+    assert_eq!(
+        validate_header_chain(
+            &headers,
+            &g,
+            &BeaconOk,
+            &Zero,
+            &Zero,
+            &Zero,
+            OBEX_ALPHA_II_VERSION
+        ),
+        Ok(())
+    );
+    // Empty segment is trivially valid
+    assert_eq!(
+        validate_header_chain(
+            &[],
+            &g,
+            &BeaconOk,
+            &Zero,
+            &Zero,
+            &Zero,
+            OBEX_ALPHA_II_VERSION
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn chain_reports_first_failing_index() {
+    let g = genesis();
+    let mut headers = segment(&g, 3);
+    headers[2].parent_id[0] ^= 1;
+    assert_eq!(
+        validate_header_chain(
+            &headers,
+            &g,
+            &BeaconOk,
+            &Zero,
+            &Zero,
+            &Zero,
+            OBEX_ALPHA_II_VERSION
+        ),
+        Err((2, ValidateErr::BadParentLink))
+    );
+}
+
+#[test]
+fn chain_rejects_skipped_slot() {
+    let g = genesis();
+    let mut headers = segment(&g, 2);
+    // Re-link a header whose slot skips ahead: parent link holds but slot must be +1
+    let mut skipped = extend(&headers[1]);
+    skipped.slot += 1;
+    headers.push(skipped);
+    assert_eq!(
+        validate_header_chain(
+            &headers,
+            &g,
+            &BeaconOk,
+            &Zero,
+            &Zero,
+            &Zero,
+            OBEX_ALPHA_II_VERSION
+        ),
+        Err((2, ValidateErr::BadSlot))
+    );
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use obex_alpha_ii::*;
+use obex_primitives::constants;
+
+use common::{HashBeacon, Roots};
+
+#[test]
+fn three_slot_chain_from_builder_validates() {
+    let g = genesis_header(GenesisParams::default());
+    assert_eq!(g.parent_id, constants::GENESIS_PARENT_ID);
+    assert_eq!(g.slot, constants::GENESIS_SLOT);
+    assert_eq!(g.txroot_prev, constants::TXROOT_GENESIS);
+
+    let mut builder = HeaderChainBuilder::new(
+        g.clone(),
+        &HashBeacon,
+        &Roots,
+        &Roots,
+        &Roots,
+        OBEX_ALPHA_II_VERSION,
+    );
+    let headers = builder.build(3);
+    assert_eq!(
+        headers.iter().map(|h| h.slot).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(builder.tip(), &headers[2]);
+    assert_eq!(
+        validate_header_chain(
+            &headers,
+            &g,
+            &HashBeacon,
+            &Roots,
+            &Roots,
+            &Roots,
+            OBEX_ALPHA_II_VERSION
+        ),
+        Ok(())
+    );
+
+    // Continuing from the tip keeps the chain valid
+    let next = builder.next_header();
+    assert_eq!(
+        validate_header(
+            &next,
+            &headers[2],
+            &HashBeacon,
+            &Roots,
+            &Roots,
+            &Roots,
+            OBEX_ALPHA_II_VERSION
+        ),
+        Ok(())
+    );
+}
@@ -0,0 +1,41 @@
+use obex_alpha_ii::*;
+
+/// Fixed-width prefix up to (but excluding) the `vdf_pi` length field.
+fn fixed_prefix() -> Vec<u8> {
+    let mut b = Vec::new();
+    b.extend_from_slice(&[9u8; 32]); // parent_id
+    b.extend_from_slice(&1u64.to_le_bytes()); // slot
+    b.extend_from_slice(&OBEX_ALPHA_II_VERSION.to_le_bytes()); // obex_version
+    b.extend_from_slice(&[1u8; 32]); // seed_commit
+    b.extend_from_slice(&[2u8; 32]); // vdf_y_core
+    b.extend_from_slice(&[3u8; 32]); // vdf_y_edge
+    b
+}
+
+#[test]
+fn oversized_pi_len_prefix_rejected_before_alloc() {
+    for claimed in [u32::MAX, u32::try_from(MAX_PI_LEN + 1).unwrap()] {
+        let mut b = fixed_prefix();
+        b.extend_from_slice(&claimed.to_le_bytes());
+        // No payload follows: a cap-less decoder would report Short (or try to allocate).
+        assert!(matches!(deserialize_header(&b), Err(CodecError::TooLong)));
+    }
+}
+
+#[test]
+fn oversized_ell_len_prefix_rejected_before_alloc() {
+    for claimed in [u32::MAX, u32::try_from(MAX_ELL_LEN + 1).unwrap()] {
+        let mut b = fixed_prefix();
+        b.extend_from_slice(&0u32.to_le_bytes()); // empty vdf_pi
+        b.extend_from_slice(&claimed.to_le_bytes());
+        assert!(matches!(deserialize_header(&b), Err(CodecError::TooLong)));
+    }
+}
+
+#[test]
+fn pi_len_at_cap_is_not_too_large() {
+    let mut b = fixed_prefix();
+    b.extend_from_slice(&u32::try_from(MAX_PI_LEN).unwrap().to_le_bytes());
+    // At the cap the prefix is accepted; the truncated body then fails as Short.
+    assert!(matches!(deserialize_header(&b), Err(CodecError::Short)));
+}
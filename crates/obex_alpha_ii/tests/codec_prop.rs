@@ -0,0 +1,102 @@
+use obex_alpha_ii::{
+    deserialize_header, obex_header_id, serialize_header, Header, MAX_ELL_LEN, MAX_HEADER_SIZE,
+    MAX_PI_LEN,
+};
+use obex_primitives::Hash256;
+use proptest::prelude::*;
+
+fn hash() -> impl Strategy<Value = Hash256> {
+    any::<[u8; 32]>()
+}
+
+/// Mostly short random blobs, occasionally a filled blob of any length up to `cap`.
+fn blob(cap: usize) -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        60 => prop::collection::vec(any::<u8>(), 0..512),
+        1 => (any::<u8>(), 0..=cap).prop_map(|(b, n)| vec![b; n]),
+        1 => any::<u8>().prop_map(move |b| vec![b; cap]),
+    ]
+}
+
+fn header_with(pi_cap: usize, ell_cap: usize) -> impl Strategy<Value = Header> {
+    (
+        (hash(), any::<u64>(), any::<u32>()),
+        (hash(), hash(), hash()),
+        (blob(pi_cap), blob(ell_cap)),
+        (hash(), hash(), hash()),
+    )
+        .prop_map(
+            |(
+                (parent_id, slot, obex_version),
+                (seed_commit, vdf_y_core, vdf_y_edge),
+                (vdf_pi, vdf_ell),
+                (ticket_root, part_root, txroot_prev),
+            )| Header {
+                parent_id,
+                slot,
+                obex_version,
+                seed_commit,
+                vdf_y_core,
+                vdf_y_edge,
+                vdf_pi,
+                vdf_ell,
+                ticket_root,
+                part_root,
+                txroot_prev,
+            },
+        )
+}
+
+fn header() -> impl Strategy<Value = Header> {
+    header_with(MAX_PI_LEN, MAX_ELL_LEN)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2048))]
+
+    #[test]
+    fn serialize_deserialize_identity(h in header()) {
+        let bytes = serialize_header(&h);
+        prop_assert!(bytes.len() <= MAX_HEADER_SIZE);
+        let back = deserialize_header(&bytes).unwrap();
+        prop_assert_eq!(obex_header_id(&back), obex_header_id(&h));
+        prop_assert_eq!(back, h);
+    }
+
+    #[test]
+    fn arbitrary_bytes_never_panic(bytes in prop::collection::vec(any::<u8>(), 0..1024)) {
+        let _ = deserialize_header(&bytes);
+    }
+
+    #[test]
+    fn mutated_encoding_never_panics(
+        h in header_with(1_024, 1_024),
+        cut in any::<prop::sample::Index>(),
+        flip in any::<prop::sample::Index>(),
+        bit in 0u8..8,
+    ) {
+        let mut bytes = serialize_header(&h);
+        let i = flip.index(bytes.len());
+        bytes[i] ^= 1 << bit;
+        bytes.truncate(cut.index(bytes.len() + 1));
+        if let Ok(h2) = deserialize_header(&bytes) {
+            prop_assert_eq!(serialize_header(&h2), bytes);
+        }
+    }
+
+    #[test]
+    fn untrusted_length_prefixes_never_panic(
+        prefix in prop::collection::vec(any::<u8>(), 116),
+        pi_len in any::<u32>(),
+        ell_len in any::<u32>(),
+        body in prop::collection::vec(any::<u8>(), 0..256),
+    ) {
+        // Fixed fields, then attacker-chosen `vdf_pi` / `vdf_ell` length prefixes.
+        let mut bytes = prefix;
+        bytes.extend_from_slice(&pi_len.to_le_bytes());
+        bytes.extend_from_slice(&body);
+        bytes.extend_from_slice(&ell_len.to_le_bytes());
+        bytes.extend_from_slice(&body);
+        let _ = deserialize_header(&bytes);
+    }
+}
@@ -0,0 +1,51 @@
+//! Fixtures shared by the integration tests; each test crate uses a subset.
+#![allow(dead_code)]
+
+use obex_alpha_ii::{
+    BeaconInputs, BeaconOutput, BeaconProver, BeaconVerifier, PartRootProvider, TicketRootProvider,
+    TxRootProvider,
+};
+use obex_primitives::{constants, h_tag, Hash256};
+
+/// Toy beacon: y_core = H(ycore, [seed]), y_edge = H(edge, [y_core]).
+pub struct HashBeacon;
+
+impl BeaconProver for HashBeacon {
+    fn prove(&self, _parent_id: &Hash256, _slot: u64, seed_commit: &Hash256) -> BeaconOutput {
+        let vdf_y_core = h_tag(constants::TAG_VDF_YCORE, &[seed_commit]);
+        BeaconOutput {
+            vdf_y_core,
+            vdf_y_edge: h_tag(constants::TAG_VDF_EDGE, &[&vdf_y_core]),
+            vdf_pi: vec![1, 2, 3],
+            vdf_ell: vec![4],
+        }
+    }
+}
+
+impl BeaconVerifier for HashBeacon {
+    fn verify(&self, i: &BeaconInputs<'_>) -> bool {
+        let expected = self.prove(i.parent_id, i.slot, i.seed_commit);
+        *i.vdf_y_core == expected.vdf_y_core
+            && *i.vdf_y_edge == expected.vdf_y_edge
+            && i.vdf_pi == expected.vdf_pi.as_slice()
+            && i.vdf_ell == expected.vdf_ell.as_slice()
+    }
+}
+
+/// Slot-derived roots: distinct per slot and per root kind.
+pub struct Roots;
+impl TicketRootProvider for Roots {
+    fn compute_ticket_root(&self, slot: u64) -> Hash256 {
+        [u8::try_from(slot).unwrap(); 32]
+    }
+}
+impl PartRootProvider for Roots {
+    fn compute_part_root(&self, slot: u64) -> Hash256 {
+        [u8::try_from(slot).unwrap() ^ 0xFF; 32]
+    }
+}
+impl TxRootProvider for Roots {
+    fn compute_txroot(&self, slot: u64) -> Hash256 {
+        [u8::try_from(slot).unwrap() ^ 0x55; 32]
+    }
+}
@@ -0,0 +1,43 @@
+use obex_alpha_ii::*;
+
+fn header() -> Header {
+    Header {
+        parent_id: [9u8; 32],
+        slot: 7,
+        obex_version: OBEX_ALPHA_II_VERSION,
+        seed_commit: [1u8; 32],
+        vdf_y_core: [2u8; 32],
+        vdf_y_edge: [3u8; 32],
+        vdf_pi: vec![],
+        vdf_ell: vec![],
+        ticket_root: [4u8; 32],
+        part_root: [5u8; 32],
+        txroot_prev: [6u8; 32],
+    }
+}
+
+#[test]
+fn headers_differing_in_txroot_prev_are_flagged() {
+    let a = header();
+    let mut b = header();
+    b.txroot_prev = [0xEE; 32];
+    let proof = detect_equivocation(&a, &b).expect("equivocation");
+    assert_eq!(proof.parent_id, a.parent_id);
+    assert_eq!(proof.slot, a.slot);
+    assert_eq!(proof.id_a, obex_header_id(&a));
+    assert_eq!(proof.id_b, obex_header_id(&b));
+    assert_ne!(proof.id_a, proof.id_b);
+}
+
+#[test]
+fn identical_or_unrelated_headers_are_not_flagged() {
+    let a = header();
+    assert_eq!(detect_equivocation(&a, &a.clone()), None);
+    let mut next_slot = header();
+    next_slot.slot += 1;
+    next_slot.txroot_prev = [0xEE; 32];
+    assert_eq!(detect_equivocation(&a, &next_slot), None);
+    let mut other_parent = header();
+    other_parent.parent_id = [8u8; 32];
+    assert_eq!(detect_equivocation(&a, &other_parent), None);
+}
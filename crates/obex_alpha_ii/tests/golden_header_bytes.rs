@@ -6,9 +6,10 @@ use obex_alpha_ii::{
     deserialize_header, obex_header_id, validate_header, ValidateErr, OBEX_ALPHA_II_VERSION,
 };
 use obex_alpha_ii::{
-    BeaconInputs, BeaconVerifier, PartRootProvider, TicketRootProvider, TxRootProvider,
+    BeaconInputs, BeaconVerifier, PartRootProvider, SlotSeedBeacon, TicketRootProvider,
+    TxRootProvider,
 };
-use obex_primitives::{constants, h_tag, le_bytes, Hash256};
+use obex_primitives::{constants, h_tag, hash_from_hex, hash_to_hex, le_bytes, Hash256};
 
 fn golden_dir() -> std::path::PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -33,9 +34,10 @@ fn golden_header_child_id_matches_hex() {
     let dir = golden_dir();
     let bytes = fs::read(dir.join("header_v2_slot1.bin")).expect("read child");
     let h = deserialize_header(&bytes).expect("decode child");
-    let id_hex = obex_header_id(&h).encode_hex::<String>();
     let exp_hex = fs::read_to_string(dir.join("header_v2_slot1.id.hex")).expect("read id hex");
-    assert_eq!(id_hex, exp_hex);
+    let exp_id = hash_from_hex(exp_hex.trim()).expect("parse id hex");
+    assert_eq!(obex_header_id(&h), exp_id);
+    assert_eq!(hash_to_hex(&exp_id), exp_hex.trim());
 }
 
 #[test]
@@ -450,3 +452,114 @@ fn golden_header_canonical_byte_image_stability() {
     let exp_hex = fs::read_to_string(dir.join("header_v2_slot1.id.hex")).expect("read id hex");
     assert_eq!(child_id_hex, exp_hex, "Header ID must be stable");
 }
+
+#[test]
+fn golden_child_display_hex_is_labelled() {
+    let dir = golden_dir();
+    let bytes = fs::read(dir.join("header_v2_slot1.bin")).expect("read child");
+    let h = deserialize_header(&bytes).expect("decode child");
+    let exp_hex = fs::read_to_string(dir.join("header_v2_slot1.id.hex")).expect("read id hex");
+    let dump = h.display_hex();
+    assert!(dump.contains(&format!("header_id: {}", exp_hex.trim())));
+    assert!(dump.contains(&format!("slot: {}", h.slot)));
+    assert!(dump.contains(&format!("obex_version: {OBEX_ALPHA_II_VERSION}")));
+    let parent_hex = h.parent_id.encode_hex::<String>();
+    assert!(dump.contains(&format!("parent_id: {parent_hex}")));
+    assert!(dump.contains(&format!("vdf_pi: len={}", h.vdf_pi.len())));
+}
+
+struct BeaconRef<'a>(&'a dyn BeaconVerifier);
+impl BeaconVerifier for BeaconRef<'_> {
+    fn verify(&self, i: &BeaconInputs<'_>) -> bool {
+        self.0.verify(i)
+    }
+}
+
+struct BeaconAcceptAll;
+impl BeaconVerifier for BeaconAcceptAll {
+    fn verify(&self, _: &BeaconInputs<'_>) -> bool {
+        true
+    }
+}
+
+#[test]
+fn seed_commit_is_checked_before_the_beacon() {
+    let dir = golden_dir();
+    let parent = deserialize_header(&fs::read(dir.join("header_v2_parent.bin")).unwrap()).unwrap();
+    let child = deserialize_header(&fs::read(dir.join("header_v2_slot1.bin")).unwrap()).unwrap();
+    let providers = ConstRoots {
+        t: child.ticket_root,
+        p: child.part_root,
+        xprev: child.txroot_prev,
+    };
+    let mut bad_seed = child.clone();
+    bad_seed.seed_commit[31] ^= 0x80;
+    let mut bad_core = child.clone();
+    bad_core.vdf_y_core[31] ^= 0x80;
+
+    let run = |h: &obex_alpha_ii::Header, beacon: &dyn BeaconVerifier| {
+        validate_header(
+            h,
+            &parent,
+            &BeaconRef(beacon),
+            &providers,
+            &providers,
+            &providers,
+            OBEX_ALPHA_II_VERSION,
+        )
+    };
+
+    // A beacon that accepts everything must not mask a wrong slot-seed commitment.
+    assert_eq!(
+        run(&bad_seed, &BeaconAcceptAll),
+        Err(ValidateErr::BadSeedCommit)
+    );
+    assert_eq!(run(&bad_seed, &BeaconOk), Err(ValidateErr::BadSeedCommit));
+    assert_eq!(run(&bad_core, &BeaconOk), Err(ValidateErr::BeaconInvalid));
+    assert_eq!(run(&bad_core, &BeaconAcceptAll), Ok(()));
+}
+
+#[test]
+fn slot_seed_beacon_accepts_golden_child_and_rejects_flipped_edge() {
+    let dir = golden_dir();
+    let parent = deserialize_header(&fs::read(dir.join("header_v2_parent.bin")).unwrap()).unwrap();
+    let child = deserialize_header(&fs::read(dir.join("header_v2_slot1.bin")).unwrap()).unwrap();
+    let providers = ConstRoots {
+        t: child.ticket_root,
+        p: child.part_root,
+        xprev: child.txroot_prev,
+    };
+    let checked = std::cell::Cell::new(0u32);
+    let beacon = SlotSeedBeacon::new(|seed: &Hash256, y_core: &Hash256, pi: &[u8], ell: &[u8]| {
+        checked.set(checked.get() + 1);
+        *seed == child.seed_commit
+            && *y_core == child.vdf_y_core
+            && pi == child.vdf_pi.as_slice()
+            && ell == child.vdf_ell.as_slice()
+    });
+    let run = |h: &obex_alpha_ii::Header| {
+        validate_header(
+            h,
+            &parent,
+            &beacon,
+            &providers,
+            &providers,
+            &providers,
+            OBEX_ALPHA_II_VERSION,
+        )
+    };
+
+    assert_eq!(run(&child), Ok(()));
+    assert_eq!(checked.get(), 1);
+
+    let mut bad_edge = child.clone();
+    bad_edge.vdf_y_edge[0] ^= 1;
+    assert_eq!(run(&bad_edge), Err(ValidateErr::BeaconInvalid));
+    // The VDF check is not reached once an equality already failed.
+    assert_eq!(checked.get(), 1);
+
+    let mut bad_pi = child.clone();
+    bad_pi.vdf_pi.push(0);
+    assert_eq!(run(&bad_pi), Err(ValidateErr::BeaconInvalid));
+    assert_eq!(checked.get(), 2);
+}
@@ -0,0 +1,32 @@
+use obex_alpha_ii::{deserialize_header, header_field_diff, HeaderFieldDiff};
+use obex_primitives::kat;
+
+#[test]
+fn diff_reports_exactly_the_changed_fields() {
+    let bytes = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/golden/header_v2_slot1.bin"
+    ))
+    .unwrap();
+    let a = deserialize_header(&bytes).unwrap();
+    assert!(header_field_diff(&a, &a.clone()).is_empty());
+
+    let mut b = a.clone();
+    b.slot += 1;
+    b.part_root = [0xAB; 32];
+    assert_eq!(
+        header_field_diff(&a, &b),
+        vec![
+            HeaderFieldDiff {
+                field: "slot",
+                a: a.slot.to_string(),
+                b: b.slot.to_string(),
+            },
+            HeaderFieldDiff {
+                field: "part_root",
+                a: kat::to_hex(&a.part_root),
+                b: "ab".repeat(32),
+            },
+        ]
+    );
+}
@@ -0,0 +1,84 @@
+use std::fs;
+use std::path::Path;
+
+use obex_alpha_ii::{
+    deserialize_header, migrate_header_v1_to_v2, part_root_from_pks, serialize_header, MigrateErr,
+    OBEX_ALPHA_II_VERSION,
+};
+
+fn golden(name: &str) -> Vec<u8> {
+    fs::read(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("golden")
+            .join(name),
+    )
+    .expect("read golden")
+}
+
+#[test]
+fn v1_fixture_migrates_to_a_canonical_v2_header() {
+    let v1 = golden("header_v1_slot1.bin");
+    let v2_ref = deserialize_header(&golden("header_v2_slot1.bin")).unwrap();
+
+    let h = migrate_header_v1_to_v2(&v1).expect("migrate v1");
+    assert_eq!(h.obex_version, OBEX_ALPHA_II_VERSION);
+    assert_eq!(h.part_root, part_root_from_pks(&[]));
+    // All non-participation fields carry over from the same slot's data.
+    assert_eq!(
+        (
+            h.parent_id,
+            h.slot,
+            h.seed_commit,
+            h.vdf_y_core,
+            h.vdf_y_edge
+        ),
+        (
+            v2_ref.parent_id,
+            v2_ref.slot,
+            v2_ref.seed_commit,
+            v2_ref.vdf_y_core,
+            v2_ref.vdf_y_edge
+        )
+    );
+    assert_eq!((&h.vdf_pi, &h.vdf_ell), (&v2_ref.vdf_pi, &v2_ref.vdf_ell));
+    assert_eq!(
+        (h.ticket_root, h.txroot_prev),
+        (v2_ref.ticket_root, v2_ref.txroot_prev)
+    );
+
+    let enc = serialize_header(&h);
+    assert_eq!(enc.len(), v1.len() + 32);
+    assert_eq!(deserialize_header(&enc).unwrap(), h);
+}
+
+#[test]
+fn migration_rejects_non_v1_and_malformed_input() {
+    let v1 = golden("header_v1_slot1.bin");
+    assert!(matches!(
+        migrate_header_v1_to_v2(&golden("header_v2_slot1.bin")),
+        Err(MigrateErr::Codec(_))
+    ));
+
+    let mut wrong_version = v1.clone();
+    wrong_version[40..44].copy_from_slice(&OBEX_ALPHA_II_VERSION.to_le_bytes());
+    assert!(matches!(
+        migrate_header_v1_to_v2(&wrong_version),
+        Err(MigrateErr::NotV1(v)) if v == OBEX_ALPHA_II_VERSION
+    ));
+
+    assert!(matches!(
+        migrate_header_v1_to_v2(&v1[..v1.len() - 1]),
+        Err(MigrateErr::Codec(_))
+    ));
+    let mut trailing = v1;
+    trailing.push(0);
+    assert!(matches!(
+        migrate_header_v1_to_v2(&trailing),
+        Err(MigrateErr::Codec(_))
+    ));
+    assert!(matches!(
+        migrate_header_v1_to_v2(&[0u8; 8]),
+        Err(MigrateErr::Codec(_))
+    ));
+}
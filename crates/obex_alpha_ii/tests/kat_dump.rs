@@ -1,5 +1,5 @@
-use hex::ToHex;
 use obex_alpha_ii::*;
+use obex_primitives::kat;
 
 #[test]
 fn dump_header_id_hex() {
@@ -16,6 +16,5 @@ fn dump_header_id_hex() {
         part_root: [6u8; 32],
         txroot_prev: [7u8; 32],
     };
-    let id_hex = obex_header_id(&h).encode_hex::<String>();
-    println!("HEADER_ID_HEX:{id_hex}");
+    println!("{}", kat::dump_hash("HEADER_ID_HEX", &obex_header_id(&h)));
 }
@@ -0,0 +1,117 @@
+#![cfg(feature = "async")]
+
+use std::fs;
+use std::path::Path;
+
+use obex_alpha_ii::{
+    deserialize_header, validate_header, validate_header_async, AsyncTicketRootProvider,
+    BeaconInputs, BeaconVerifier, Header, PartRootProvider, SyncProvider, TicketRootProvider,
+    TxRootProvider, ValidateErr, OBEX_ALPHA_II_VERSION,
+};
+use obex_primitives::{constants, h_tag, le_bytes, Hash256};
+
+struct BeaconOk;
+impl BeaconVerifier for BeaconOk {
+    fn verify(&self, i: &BeaconInputs<'_>) -> bool {
+        let seed_expected = h_tag(
+            constants::TAG_SLOT_SEED,
+            &[i.parent_id, &le_bytes::<8>(u128::from(i.slot))],
+        );
+        seed_expected == *i.seed_commit
+            && h_tag(constants::TAG_VDF_EDGE, &[i.vdf_y_core]) == *i.vdf_y_edge
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ConstRoots {
+    t: Hash256,
+    p: Hash256,
+    xprev: Hash256,
+}
+impl TicketRootProvider for ConstRoots {
+    fn compute_ticket_root(&self, _: u64) -> Hash256 {
+        self.t
+    }
+}
+impl PartRootProvider for ConstRoots {
+    fn compute_part_root(&self, _: u64) -> Hash256 {
+        self.p
+    }
+}
+impl TxRootProvider for ConstRoots {
+    fn compute_txroot(&self, _: u64) -> Hash256 {
+        self.xprev
+    }
+}
+
+/// Native async provider that yields before answering, as a storage-backed one would.
+struct YieldingTicketRoot(Hash256);
+impl AsyncTicketRootProvider for YieldingTicketRoot {
+    async fn compute_ticket_root(&self, _: u64) -> Hash256 {
+        tokio::task::yield_now().await;
+        self.0
+    }
+}
+
+fn golden() -> (Header, Header) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden");
+    let parent = deserialize_header(&fs::read(dir.join("header_v2_parent.bin")).unwrap()).unwrap();
+    let child = deserialize_header(&fs::read(dir.join("header_v2_slot1.bin")).unwrap()).unwrap();
+    (parent, child)
+}
+
+#[tokio::test]
+async fn async_validation_matches_sync_on_golden_header() {
+    let (parent, h) = golden();
+    let roots = ConstRoots {
+        t: h.ticket_root,
+        p: h.part_root,
+        xprev: h.txroot_prev,
+    };
+    let a = SyncProvider(roots);
+
+    let mut bad_link = h.clone();
+    bad_link.parent_id[0] ^= 1;
+    let mut bad_ticket = h.clone();
+    bad_ticket.ticket_root[0] ^= 1;
+    let mut bad_txroot = h.clone();
+    bad_txroot.txroot_prev[0] ^= 1;
+
+    for (hh, want) in [
+        (&h, Ok(())),
+        (&bad_link, Err(ValidateErr::BadParentLink)),
+        (&bad_ticket, Err(ValidateErr::TicketRootMismatch)),
+        (&bad_txroot, Err(ValidateErr::TxRootPrevMismatch)),
+    ] {
+        let sync = validate_header(
+            hh,
+            &parent,
+            &BeaconOk,
+            &roots,
+            &roots,
+            &roots,
+            OBEX_ALPHA_II_VERSION,
+        );
+        let asy =
+            validate_header_async(hh, &parent, &BeaconOk, &a, &a, &a, OBEX_ALPHA_II_VERSION).await;
+        assert_eq!(sync, want);
+        assert_eq!(asy, sync);
+    }
+
+    let native = YieldingTicketRoot(h.ticket_root);
+    assert_eq!(
+        validate_header_async(
+            &h,
+            &parent,
+            &BeaconOk,
+            &native,
+            &a,
+            &a,
+            OBEX_ALPHA_II_VERSION
+        )
+        .await,
+        Ok(())
+    );
+}
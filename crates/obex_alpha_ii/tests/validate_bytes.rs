@@ -0,0 +1,55 @@
+mod common;
+
+use obex_alpha_ii::*;
+
+use common::{HashBeacon, Roots};
+
+fn check(bytes: &[u8], parent: &Header) -> Result<(), ValidateErr> {
+    validate_header_bytes(
+        bytes,
+        parent,
+        &HashBeacon,
+        &Roots,
+        &Roots,
+        &Roots,
+        OBEX_ALPHA_II_VERSION,
+    )
+}
+
+#[test]
+fn valid_bytes_pass_and_truncations_are_malformed() {
+    let g = genesis_header(GenesisParams::default());
+    let h = HeaderChainBuilder::new(
+        g.clone(),
+        &HashBeacon,
+        &Roots,
+        &Roots,
+        &Roots,
+        OBEX_ALPHA_II_VERSION,
+    )
+    .next_header();
+    let bytes = serialize_header(&h);
+    assert_eq!(check(&bytes, &g), Ok(()));
+
+    for cut in [0, 1, 40, bytes.len() - 1] {
+        assert_eq!(check(&bytes[..cut], &g), Err(ValidateErr::Malformed));
+    }
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(check(&trailing, &g), Err(ValidateErr::Malformed));
+
+    // Decodes fine but fails consensus checks: the validate_header error passes through.
+    let mut other = h;
+    other.part_root[0] ^= 1;
+    assert_eq!(
+        check(&serialize_header(&other), &g),
+        Err(ValidateErr::PartRootMismatch)
+    );
+}
+
+#[test]
+fn oversized_input_rejected_before_decode() {
+    let g = genesis_header(GenesisParams::default());
+    let bytes = vec![0u8; MAX_HEADER_SIZE + 1];
+    assert_eq!(check(&bytes, &g), Err(ValidateErr::Malformed));
+}
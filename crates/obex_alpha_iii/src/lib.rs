@@ -19,7 +19,10 @@
 //! Merkle root, per `obex.alpha III.txt`. Uses Ed25519 for signatures.
 
 use ed25519_dalek::{Signature, VerifyingKey};
-use obex_primitives::{consensus, le_bytes, merkle_root, Hash256, Pk32, Sig64};
+use obex_primitives::{
+    consensus, constants, h_tag, le_bytes, le_len4, merkle_root, Hash256, Pk32, Sig64,
+};
+use thiserror::Error;
 // Anchor to ensure SHA3-256 presence without underscore-binding side effects.
 pub use obex_primitives::OBEX_SHA3_256_ANCHOR as _obex_sha3_anchor_iii;
 
@@ -33,6 +36,9 @@ pub const FLAT_SWITCH_UOBX: u128 = 1_000;
 pub const FLAT_FEE_UOBX: u128 = 10;
 pub const PCT_DEN: u128 = 100;
 
+/// Memo size cap (bytes); bounds worst-case `canonical_tx_bytes` and hashing work per tx.
+pub const MAX_MEMO_BYTES: usize = 256;
+
 #[inline]
 #[must_use]
 pub fn fee_int_uobx(amount_u: u128) -> u128 {
@@ -56,17 +62,47 @@ fn sort_dedup(mut v: Vec<Pk32>) -> Vec<Pk32> {
     v
 }
 
+/// True iff the sorted, deduplicated lists share an account.
+fn overlaps(a: &[Pk32], b: &[Pk32]) -> bool {
+    let (a, b) = (sort_dedup(a.to_vec()), sort_dedup(b.to_vec()));
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            core::cmp::Ordering::Less => i += 1,
+            core::cmp::Ordering::Greater => j += 1,
+            core::cmp::Ordering::Equal => return true,
+        }
+    }
+    false
+}
+
+/// Two access lists conflict when their write sets overlap or either writes an account the
+/// other reads. Read-read sharing is not a conflict.
+#[must_use]
+pub fn access_conflict(a: &AccessList, b: &AccessList) -> bool {
+    overlaps(&a.write_accounts, &b.write_accounts)
+        || overlaps(&a.write_accounts, &b.read_accounts)
+        || overlaps(&a.read_accounts, &b.write_accounts)
+}
+
+/// Canonical access-list bytes, exactly as hashed inside `canonical_tx_bytes`:
+/// `H("obex.tx.access",[]) || LE(|R|,4) || R || LE(|W|,4) || W`, each list sorted ascending
+/// and deduplicated.
+///
+/// # Panics
+///
+/// If either list holds `2^32` or more keys, which no `LE(len, 4)` prefix can encode.
 #[must_use]
 pub fn encode_access(a: &AccessList) -> Vec<u8> {
     let r = sort_dedup(a.read_accounts.clone());
     let w = sort_dedup(a.write_accounts.clone());
     let mut out = Vec::new();
     out.extend_from_slice(&consensus::h_tag("obex.tx.access", &[]));
-    out.extend_from_slice(&le_bytes::<4>(r.len() as u128));
+    out.extend_from_slice(&le_len4(r.len()).expect("read list length exceeds u32 prefix"));
     for pk in &r {
         out.extend_from_slice(pk);
     }
-    out.extend_from_slice(&le_bytes::<4>(w.len() as u128));
+    out.extend_from_slice(&le_len4(w.len()).expect("write list length exceeds u32 prefix"));
     for pk in &w {
         out.extend_from_slice(pk);
     }
@@ -86,6 +122,12 @@ pub struct TxBodyV1 {
     pub memo: Vec<u8>,
 }
 
+/// Canonical body bytes, the preimage of `txid` and `tx_commit`.
+///
+/// # Panics
+///
+/// As `encode_access`, or if `memo` is `2^32` bytes or longer; admission caps it at
+/// `MAX_MEMO_BYTES`.
 #[must_use]
 pub fn canonical_tx_bytes(tx: &TxBodyV1) -> Vec<u8> {
     let mut out = Vec::new();
@@ -98,11 +140,130 @@ pub fn canonical_tx_bytes(tx: &TxBodyV1) -> Vec<u8> {
     out.extend_from_slice(&le_bytes::<8>(u128::from(tx.s_bind)));
     out.extend_from_slice(&tx.y_bind);
     out.extend_from_slice(&encode_access(&tx.access));
-    out.extend_from_slice(&le_bytes::<4>(tx.memo.len() as u128));
+    out.extend_from_slice(&le_len4(tx.memo.len()).expect("memo length exceeds u32 prefix"));
     out.extend_from_slice(&tx.memo);
     out
 }
 
+// ——— Canonical TxBodyV1 codec (wire = canonical_tx_bytes) ——————————————
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum CodecError {
+    #[error("input too short")]
+    Short,
+    #[error("trailing bytes after decode")]
+    Trailing,
+    #[error("domain tag mismatch")]
+    BadTag,
+    #[error("access list not sorted/deduplicated")]
+    NonCanonicalAccess,
+    #[error("memo exceeds MAX_MEMO_BYTES")]
+    MemoTooLarge,
+}
+
+const fn read_exact<'a>(src: &mut &'a [u8], n: usize) -> Result<&'a [u8], CodecError> {
+    if src.len() < n {
+        return Err(CodecError::Short);
+    }
+    let (a, b) = src.split_at(n);
+    *src = b;
+    Ok(a)
+}
+
+fn read_arr<const N: usize>(src: &mut &[u8]) -> Result<[u8; N], CodecError> {
+    let mut a = [0u8; N];
+    a.copy_from_slice(read_exact(src, N)?);
+    Ok(a)
+}
+
+fn read_len(src: &mut &[u8]) -> Result<usize, CodecError> {
+    Ok(u32::from_le_bytes(read_arr::<4>(src)?) as usize)
+}
+
+fn read_tag(src: &mut &[u8], tag: &str) -> Result<(), CodecError> {
+    if read_arr::<32>(src)? == consensus::h_tag(tag, &[]) {
+        Ok(())
+    } else {
+        Err(CodecError::BadTag)
+    }
+}
+
+fn read_pk_list(src: &mut &[u8]) -> Result<Vec<Pk32>, CodecError> {
+    let n = read_len(src)?;
+    let raw = read_exact(src, n.checked_mul(32).ok_or(CodecError::Short)?)?;
+    let v: Vec<Pk32> = raw
+        .chunks_exact(32)
+        .map(|c| {
+            let mut pk = [0u8; 32];
+            pk.copy_from_slice(c);
+            pk
+        })
+        .collect();
+    // Canonical form is strictly ascending (sorted + deduplicated), as emitted by `encode_access`
+    if v.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(CodecError::NonCanonicalAccess);
+    }
+    Ok(v)
+}
+
+fn read_access(src: &mut &[u8]) -> Result<AccessList, CodecError> {
+    read_tag(src, "obex.tx.access")?;
+    let read_accounts = read_pk_list(src)?;
+    let write_accounts = read_pk_list(src)?;
+    Ok(AccessList {
+        read_accounts,
+        write_accounts,
+    })
+}
+
+/// Decode `encode_access` bytes; rejects a bad tag, unsorted or duplicate keys, and trailing
+/// bytes, so `encode_access(&decode_access(b)?) == b`.
+pub fn decode_access(mut src: &[u8]) -> Result<AccessList, CodecError> {
+    let a = read_access(&mut src)?;
+    if !src.is_empty() {
+        return Err(CodecError::Trailing);
+    }
+    Ok(a)
+}
+
+/// Canonical transport encoding of a transaction body (identical to `canonical_tx_bytes`).
+#[must_use]
+pub fn encode_txbody(tx: &TxBodyV1) -> Vec<u8> {
+    canonical_tx_bytes(tx)
+}
+
+/// Decode canonical `TxBodyV1` bytes; rejects bad tags, non-canonical access lists, and trailing bytes.
+pub fn decode_txbody(mut src: &[u8]) -> Result<TxBodyV1, CodecError> {
+    read_tag(&mut src, "obex.tx.body.v1")?;
+    let sender = read_arr::<32>(&mut src)?;
+    let recipient = read_arr::<32>(&mut src)?;
+    let nonce = u64::from_le_bytes(read_arr::<8>(&mut src)?);
+    let amount_u = u128::from_le_bytes(read_arr::<16>(&mut src)?);
+    let fee_u = u128::from_le_bytes(read_arr::<16>(&mut src)?);
+    let s_bind = u64::from_le_bytes(read_arr::<8>(&mut src)?);
+    let y_bind = read_arr::<32>(&mut src)?;
+    let access = read_access(&mut src)?;
+    let memo_len = read_len(&mut src)?;
+    if memo_len > MAX_MEMO_BYTES {
+        return Err(CodecError::MemoTooLarge);
+    }
+    let memo = read_exact(&mut src, memo_len)?.to_vec();
+    if !src.is_empty() {
+        return Err(CodecError::Trailing);
+    }
+    Ok(TxBodyV1 {
+        sender,
+        recipient,
+        nonce,
+        amount_u,
+        fee_u,
+        s_bind,
+        y_bind,
+        access,
+        memo,
+    })
+}
+
 #[must_use]
 pub fn txid(tx: &TxBodyV1) -> Hash256 {
     consensus::h_tag("obex.tx.id", &[&canonical_tx_bytes(tx)])
@@ -113,6 +274,12 @@ pub fn tx_commit(tx: &TxBodyV1) -> Hash256 {
     consensus::h_tag("obex.tx.commit", &[&canonical_tx_bytes(tx)])
 }
 
+/// Message the sender signs: `H("obex.tx.sig", [canonical_tx_bytes(tx)])` (Ed25519, strict).
+#[must_use]
+pub fn tx_sig_msg(tx: &TxBodyV1) -> Hash256 {
+    consensus::h_tag("obex.tx.sig", &[&canonical_tx_bytes(tx)])
+}
+
 #[must_use]
 fn verify_sig(pk: &Pk32, msg: &[u8], sig: &Sig) -> bool {
     match (VerifyingKey::from_bytes(pk), Signature::from_slice(sig)) {
@@ -150,16 +317,279 @@ pub fn enc_ticket_leaf(t: &TicketRecord) -> Vec<u8> {
     out
 }
 
-#[derive(Default, Clone, Debug, PartialEq, Eq)]
+/// Decode `enc_ticket_leaf` bytes; rejects a bad tag, short input and trailing bytes, so
+/// `enc_ticket_leaf(&dec_ticket_leaf(b)?) == b`.
+pub fn dec_ticket_leaf(mut src: &[u8]) -> Result<TicketRecord, CodecError> {
+    read_tag(&mut src, "obex.ticket.leaf")?;
+    let ticket_id = read_arr::<32>(&mut src)?;
+    let txid = read_arr::<32>(&mut src)?;
+    let sender = read_arr::<32>(&mut src)?;
+    let nonce = u64::from_le_bytes(read_arr::<8>(&mut src)?);
+    let amount_u = u128::from_le_bytes(read_arr::<16>(&mut src)?);
+    let fee_u = u128::from_le_bytes(read_arr::<16>(&mut src)?);
+    let s_admit = u64::from_le_bytes(read_arr::<8>(&mut src)?);
+    let s_exec = u64::from_le_bytes(read_arr::<8>(&mut src)?);
+    let commit_hash = read_arr::<32>(&mut src)?;
+    if !src.is_empty() {
+        return Err(CodecError::Trailing);
+    }
+    Ok(TicketRecord {
+        ticket_id,
+        txid,
+        sender,
+        nonce,
+        amount_u,
+        fee_u,
+        s_admit,
+        s_exec,
+        commit_hash,
+    })
+}
+
+/// The record `admit_single` finalizes for `tx` admitted at `s_now`.
+fn ticket_record(tx: &TxBodyV1, s_now: u64) -> TicketRecord {
+    let xid = txid(tx);
+    TicketRecord {
+        ticket_id: consensus::h_tag("obex.ticket.id", &[&xid, &le_bytes::<8>(u128::from(s_now))]),
+        txid: xid,
+        sender: tx.sender,
+        nonce: tx.nonce,
+        amount_u: tx.amount_u,
+        fee_u: tx.fee_u,
+        s_admit: s_now,
+        s_exec: s_now,
+        commit_hash: tx_commit(tx),
+    }
+}
+
+/// First field in which a `TicketRecord` disagrees with the transaction it claims to admit.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum TicketError {
+    #[error("txid does not match the transaction")]
+    TxidMismatch,
+    #[error("commit_hash does not match the transaction")]
+    CommitMismatch,
+    #[error("ticket_id does not match txid and admission slot")]
+    TicketIdMismatch,
+    #[error("sender does not match the transaction")]
+    SenderMismatch,
+    #[error("nonce does not match the transaction")]
+    NonceMismatch,
+    #[error("amount does not match the transaction")]
+    AmountMismatch,
+    #[error("fee does not match the transaction")]
+    FeeMismatch,
+    #[error("s_admit/s_exec do not match the admission slot")]
+    SlotMismatch,
+}
+
+/// Check that `rec` is exactly the record `admit_single` produces for `tx` admitted at `s_now`.
+///
+/// Lets validators reconstructing `ticket_root` from transactions confirm each leaf without
+/// replaying admission state. Fields are compared in declaration order of `TicketError`.
+pub fn verify_ticket_record(
+    rec: &TicketRecord,
+    tx: &TxBodyV1,
+    s_now: u64,
+) -> Result<(), TicketError> {
+    let exp = ticket_record(tx, s_now);
+    if rec.txid != exp.txid {
+        return Err(TicketError::TxidMismatch);
+    }
+    if rec.commit_hash != exp.commit_hash {
+        return Err(TicketError::CommitMismatch);
+    }
+    if rec.ticket_id != exp.ticket_id {
+        return Err(TicketError::TicketIdMismatch);
+    }
+    if rec.sender != exp.sender {
+        return Err(TicketError::SenderMismatch);
+    }
+    if rec.nonce != exp.nonce {
+        return Err(TicketError::NonceMismatch);
+    }
+    if rec.amount_u != exp.amount_u {
+        return Err(TicketError::AmountMismatch);
+    }
+    if rec.fee_u != exp.fee_u {
+        return Err(TicketError::FeeMismatch);
+    }
+    if (rec.s_admit, rec.s_exec) != (exp.s_admit, exp.s_exec) {
+        return Err(TicketError::SlotMismatch);
+    }
+    Ok(())
+}
+
+#[derive(Default, Clone, Debug)]
 pub struct AlphaIIIState {
     pub spendable_u: std::collections::BTreeMap<Pk32, u128>,
     pub reserved_u: std::collections::BTreeMap<Pk32, u128>,
+    /// Next admissible nonce per sender; admission requires equality and increments it, so a
+    /// `(sender, nonce)` pair is admitted at most once across all slots.
     pub next_nonce: std::collections::BTreeMap<Pk32, u64>,
     pub admitted_by_slot: std::collections::BTreeMap<u64, Vec<TicketRecord>>,
     pub tickets_by_txid: std::collections::BTreeMap<Hash256, TicketRecord>,
+    /// Undo journal for open snapshots (empty when none are open).
+    journal: Vec<Undo>,
+    open_snapshots: usize,
+    /// Bumped by `abandon_snapshots`, so tokens it invalidated cannot release later snapshots.
+    snapshot_gen: u64,
+}
+
+/// Equality is over ledger contents only; the undo journal is bookkeeping, so a restored
+/// state compares equal to the state it was snapshotted from.
+impl PartialEq for AlphaIIIState {
+    fn eq(&self, other: &Self) -> bool {
+        self.spendable_u == other.spendable_u
+            && self.reserved_u == other.reserved_u
+            && self.next_nonce == other.next_nonce
+            && self.admitted_by_slot == other.admitted_by_slot
+            && self.tickets_by_txid == other.tickets_by_txid
+    }
+}
+
+impl Eq for AlphaIIIState {}
+
+/// Prior value of one admission mutation, replayed in reverse by `restore`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Undo {
+    Spendable(Pk32, Option<u128>),
+    Reserved(Pk32, Option<u128>),
+    Nonce(Pk32, Option<u64>),
+    SlotPush { slot: u64, created: bool },
+    TicketByTxid(Hash256, Option<TicketRecord>),
+}
+
+/// Rollback point returned by `AlphaIIIState::snapshot`; O(1) to take, restore cost is
+/// proportional to the admissions made since. Snapshots nest and must be released LIFO.
+///
+/// The token does not borrow the state, so dropping it releases nothing: every admission
+/// stays journaled until the snapshot is restored or committed. A caller that lost a token
+/// (early return, panic unwinding past it) must call `AlphaIIIState::abandon_snapshots`, or
+/// the journal grows for the life of the state.
+#[derive(Debug, PartialEq, Eq)]
+#[must_use]
+pub struct StateSnapshot {
+    mark: usize,
+    depth: usize,
+    gen: u64,
+}
+
+/// A snapshot released out of LIFO order or after being abandoned.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// `snap` is not the innermost open snapshot (`open` are currently open).
+    #[error("snapshot at depth {depth} is not innermost ({open} open)")]
+    OutOfOrder { depth: usize, open: usize },
+    /// `snap` was open when `abandon_snapshots` closed it.
+    #[error("snapshot was abandoned")]
+    Abandoned,
+}
+
+fn restore_entry<K: Ord, V>(map: &mut std::collections::BTreeMap<K, V>, k: K, v: Option<V>) {
+    match v {
+        Some(v) => {
+            map.insert(k, v);
+        }
+        None => {
+            map.remove(&k);
+        }
+    }
 }
 
 impl AlphaIIIState {
+    /// Open a rollback point covering all subsequent admission mutations.
+    pub const fn snapshot(&mut self) -> StateSnapshot {
+        let snap = StateSnapshot {
+            mark: self.journal.len(),
+            depth: self.open_snapshots,
+            gen: self.snapshot_gen,
+        };
+        self.open_snapshots += 1;
+        snap
+    }
+
+    /// Undo every admission since `snap` was taken, returning the state to its exact prior contents.
+    ///
+    /// `snap` must be the innermost open snapshot; otherwise the state is left untouched and
+    /// `OutOfOrder` is returned.
+    #[allow(clippy::needless_pass_by_value)] // consumes the snapshot token
+    pub fn restore(&mut self, snap: StateSnapshot) -> Result<(), SnapshotError> {
+        self.check_innermost(&snap)?;
+        while self.journal.len() > snap.mark {
+            let Some(undo) = self.journal.pop() else {
+                break;
+            };
+            match undo {
+                Undo::Spendable(pk, v) => restore_entry(&mut self.spendable_u, pk, v),
+                Undo::Reserved(pk, v) => restore_entry(&mut self.reserved_u, pk, v),
+                Undo::Nonce(pk, v) => restore_entry(&mut self.next_nonce, pk, v),
+                Undo::SlotPush { slot, created } => {
+                    if created {
+                        self.admitted_by_slot.remove(&slot);
+                    } else if let Some(list) = self.admitted_by_slot.get_mut(&slot) {
+                        list.pop();
+                    }
+                }
+                Undo::TicketByTxid(xid, v) => restore_entry(&mut self.tickets_by_txid, xid, v),
+            }
+        }
+        self.release(snap.depth);
+        Ok(())
+    }
+
+    /// Keep every admission since `snap` and close the rollback point.
+    ///
+    /// Like `restore`, `snap` must be the innermost open snapshot.
+    #[allow(clippy::needless_pass_by_value)] // consumes the snapshot token
+    pub fn commit(&mut self, snap: StateSnapshot) -> Result<(), SnapshotError> {
+        self.check_innermost(&snap)?;
+        self.release(snap.depth);
+        Ok(())
+    }
+
+    /// Close every open snapshot, keeping all admissions made under them, and drop the journal.
+    ///
+    /// Recovery for snapshots dropped without `restore` or `commit`; any token still held
+    /// afterwards is rejected with `Abandoned`.
+    pub fn abandon_snapshots(&mut self) {
+        self.release(0);
+        self.snapshot_gen += 1;
+    }
+
+    /// Number of snapshots taken and not yet restored, committed or abandoned.
+    #[must_use]
+    pub const fn open_snapshots(&self) -> usize {
+        self.open_snapshots
+    }
+
+    const fn check_innermost(&self, snap: &StateSnapshot) -> Result<(), SnapshotError> {
+        if snap.gen != self.snapshot_gen {
+            return Err(SnapshotError::Abandoned);
+        }
+        if snap.depth + 1 != self.open_snapshots {
+            return Err(SnapshotError::OutOfOrder {
+                depth: snap.depth,
+                open: self.open_snapshots,
+            });
+        }
+        Ok(())
+    }
+
+    fn release(&mut self, depth: usize) {
+        self.open_snapshots = depth;
+        if depth == 0 {
+            self.journal.clear();
+        }
+    }
+
+    fn record(&mut self, undo: impl FnOnce(&Self) -> Undo) {
+        if self.open_snapshots > 0 {
+            let u = undo(self);
+            self.journal.push(u);
+        }
+    }
+
     #[must_use]
     pub fn spendable_of(&self, pk: &Pk32) -> u128 {
         *self.spendable_u.get(pk).unwrap_or(&0)
@@ -177,12 +607,15 @@ impl AlphaIIIState {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AdmitErr {
     BadSig,
+    /// `tx.s_bind` is not the admitting slot.
     WrongSlot,
+    /// `tx.y_bind` is not the admitting slot's `y_edge_{s-1}`.
     WrongBeacon,
     NonceMismatch,
     BelowMinAmount,
     FeeMismatch,
     InsufficientFunds,
+    MemoTooLarge,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -199,8 +632,11 @@ pub fn admit_single(
     y_prev: &Hash256,
     st: &mut AlphaIIIState,
 ) -> AdmitResult {
-    let msg = consensus::h_tag("obex.tx.sig", &[&canonical_tx_bytes(tx)]);
-    if !verify_sig(&tx.sender, &msg, sig) {
+    // Size cap first: bounds the hashing done for signature verification
+    if tx.memo.len() > MAX_MEMO_BYTES {
+        return AdmitResult::Rejected(AdmitErr::MemoTooLarge);
+    }
+    if !verify_sig(&tx.sender, &tx_sig_msg(tx), sig) {
         return AdmitResult::Rejected(AdmitErr::BadSig);
     }
     if tx.s_bind != s_now {
@@ -223,56 +659,302 @@ pub fn admit_single(
         return AdmitResult::Rejected(AdmitErr::InsufficientFunds);
     }
 
+    let sender = tx.sender;
+    st.record(|st| Undo::Spendable(sender, st.spendable_u.get(&sender).copied()));
+    st.record(|st| Undo::Reserved(sender, st.reserved_u.get(&sender).copied()));
+    st.record(|st| Undo::Nonce(sender, st.next_nonce.get(&sender).copied()));
     *st.spendable_u.entry(tx.sender).or_insert(0) -= total;
     *st.reserved_u.entry(tx.sender).or_insert(0) += total;
     *st.next_nonce.entry(tx.sender).or_insert(0) += 1;
 
-    let xid = txid(tx);
-    let rec = TicketRecord {
-        ticket_id: consensus::h_tag("ticket.id", &[&xid, &le_bytes::<8>(u128::from(s_now))]),
-        txid: xid,
-        sender: tx.sender,
-        nonce: tx.nonce,
-        amount_u: tx.amount_u,
-        fee_u: tx.fee_u,
-        s_admit: s_now,
-        s_exec: s_now,
-        commit_hash: tx_commit(tx),
-    };
+    let rec = ticket_record(tx, s_now);
+    let xid = rec.txid;
+    st.record(|st| Undo::SlotPush {
+        slot: s_now,
+        created: !st.admitted_by_slot.contains_key(&s_now),
+    });
     st.admitted_by_slot
         .entry(s_now)
         .or_default()
         .push(rec.clone());
+    st.record(|st| Undo::TicketByTxid(xid, st.tickets_by_txid.get(&xid).cloned()));
     st.tickets_by_txid.insert(rec.txid, rec.clone());
     AdmitResult::Finalized(rec)
 }
 
+/// How `admit_slot_canonical_with_policy` treats access-list conflicts within a slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictPolicy {
+    /// Admit conflicting transactions; execution follows the canonical candidate order.
+    #[default]
+    Sequence,
+    /// Skip a candidate whose access list conflicts with one already admitted this slot, so
+    /// the admitted set can execute in parallel.
+    Reject,
+}
+
+/// Admit `candidates_sorted` into slot `s_now` in order; rejected candidates are skipped.
+///
+/// Candidates are applied to `st` in the given order (nonces and balances depend on it), but the
+/// returned tickets are sorted ascending by `txid`, the `ticket_root` leaf order, so the output
+/// is a pure function of the admitted set.
+///
+/// Each transaction must be bound to this slot's context (`s_bind == s_now`,
+/// `y_bind == y_prev`), so a signed transaction cannot be replayed into another slot or
+/// beacon history; see `admit_single` for the full check order.
 #[must_use]
 pub fn admit_slot_canonical(
     s_now: u64,
     y_prev: &Hash256,
     candidates_sorted: &[(TxBodyV1, Sig)],
     st: &mut AlphaIIIState,
+) -> Vec<TicketRecord> {
+    admit_slot_canonical_with_policy(
+        s_now,
+        y_prev,
+        candidates_sorted,
+        st,
+        ConflictPolicy::Sequence,
+    )
+}
+
+/// `admit_slot_canonical` with an explicit access-list `ConflictPolicy`. Conflicts are
+/// checked against transactions admitted by this call, before `admit_single` runs.
+///
+/// The output is sorted by `txid`, as for `admit_slot_canonical`.
+#[must_use]
+pub fn admit_slot_canonical_with_policy(
+    s_now: u64,
+    y_prev: &Hash256,
+    candidates_sorted: &[(TxBodyV1, Sig)],
+    st: &mut AlphaIIIState,
+    policy: ConflictPolicy,
 ) -> Vec<TicketRecord> {
     let mut out = Vec::new();
+    let mut admitted: Vec<&AccessList> = Vec::new();
     for (tx, sig) in candidates_sorted {
+        if policy == ConflictPolicy::Reject
+            && admitted.iter().any(|a| access_conflict(a, &tx.access))
+        {
+            continue;
+        }
         match admit_single(tx, sig, s_now, y_prev, st) {
-            AdmitResult::Finalized(rec) => out.push(rec),
+            AdmitResult::Finalized(rec) => {
+                out.push(rec);
+                admitted.push(&tx.access);
+            }
             AdmitResult::Rejected(_) => {}
         }
     }
+    out.sort_by_key(|rec| rec.txid);
     out
 }
 
+// ——— Mempool (non-consensus candidate selection) ————————————————————————
+
+type PendingBySender =
+    std::collections::BTreeMap<Pk32, std::collections::BTreeMap<u64, (TxBodyV1, Sig)>>;
+
+/// Selection priority: higher fee first, then lower `txid`.
+fn mempool_priority(tx: &TxBodyV1) -> (core::cmp::Reverse<u128>, Hash256) {
+    (core::cmp::Reverse(fee_int_uobx(tx.amount_u)), txid(tx))
+}
+
+/// Pending transactions keyed by sender and nonce. Produces the ordered candidate slice that
+/// `admit_slot_canonical` consumes; selection is a pure function of pool contents and state.
+#[derive(Clone, Debug, Default)]
+pub struct Mempool {
+    by_sender: PendingBySender,
+}
+
+impl Mempool {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a pending transaction. Transactions below `MIN_TX_UOBX` or whose `fee_u` differs
+    /// from `fee_int_uobx` are refused. For a duplicate `(sender, nonce)` the entry with the
+    /// higher priority is kept, so contents do not depend on arrival order. Returns whether
+    /// `tx` is now stored.
+    pub fn insert(&mut self, tx: TxBodyV1, sig: Sig) -> bool {
+        if tx.amount_u < MIN_TX_UOBX || tx.fee_u != fee_int_uobx(tx.amount_u) {
+            return false;
+        }
+        let slot = self.by_sender.entry(tx.sender).or_default().entry(tx.nonce);
+        match slot {
+            std::collections::btree_map::Entry::Vacant(v) => {
+                v.insert((tx, sig));
+                true
+            }
+            std::collections::btree_map::Entry::Occupied(mut o) => {
+                if mempool_priority(&tx) < mempool_priority(&o.get().0) {
+                    o.insert((tx, sig));
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.by_sender
+            .values()
+            .map(std::collections::BTreeMap::len)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.by_sender.is_empty()
+    }
+
+    /// Select up to `max_count` candidates bound to `(slot, y_bind)`.
+    ///
+    /// Per sender only the run of consecutive nonces starting at `st.nonce_of(sender)` is
+    /// eligible; a gap or a transaction bound elsewhere ends the run. Across senders the next
+    /// eligible transactions are taken by fee (descending) then `txid`, so a sender's nonces
+    /// always appear in order.
+    #[must_use]
+    pub fn select_for_slot(
+        &self,
+        slot: u64,
+        y_bind: &Hash256,
+        st: &AlphaIIIState,
+        max_count: usize,
+    ) -> Vec<(TxBodyV1, Sig)> {
+        let runs: Vec<Vec<&(TxBodyV1, Sig)>> = self
+            .by_sender
+            .iter()
+            .map(|(sender, pending)| {
+                let mut next = st.nonce_of(sender);
+                pending
+                    .range(next..)
+                    .take_while(|(&n, (tx, _))| {
+                        let ok = n == next && tx.s_bind == slot && tx.y_bind == *y_bind;
+                        next = next.saturating_add(1);
+                        ok
+                    })
+                    .map(|(_, e)| e)
+                    .collect()
+            })
+            .collect();
+        let mut heads: std::collections::BTreeSet<_> = runs
+            .iter()
+            .enumerate()
+            .filter_map(|(r, run)| run.first().map(|(tx, _)| (mempool_priority(tx), r, 0usize)))
+            .collect();
+        let mut out = Vec::with_capacity(max_count.min(self.len()));
+        while out.len() < max_count {
+            let Some((_, r, i)) = heads.pop_first() else {
+                break;
+            };
+            out.push(runs[r][i].clone());
+            if let Some((tx, _)) = runs[r].get(i + 1) {
+                heads.insert((mempool_priority(tx), r, i + 1));
+            }
+        }
+        out
+    }
+}
+
+/// Why a ticket list is not in canonical `ticket_root` order.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum OrderError {
+    /// `records[index].txid` sorts before `records[index - 1].txid`.
+    #[error("ticket {index} is out of txid order")]
+    OutOfOrder { index: usize },
+    /// `records[index]` repeats the txid of `records[index - 1]`.
+    #[error("ticket {index} repeats the previous txid")]
+    DuplicateTxid { index: usize },
+}
+
+/// Check that `records` are in canonical `ticket_root` leaf order: strictly ascending `txid`.
+///
+/// # Errors
+///
+/// Returns the first adjacent pair that is out of order or shares a txid.
+pub fn assert_canonical_ticket_order(records: &[TicketRecord]) -> Result<(), OrderError> {
+    for (i, pair) in records.windows(2).enumerate() {
+        match pair[0].txid.cmp(&pair[1].txid) {
+            core::cmp::Ordering::Less => {}
+            core::cmp::Ordering::Equal => return Err(OrderError::DuplicateTxid { index: i + 1 }),
+            core::cmp::Ordering::Greater => return Err(OrderError::OutOfOrder { index: i + 1 }),
+        }
+    }
+    Ok(())
+}
+
 #[must_use]
 pub fn build_ticket_root_for_slot(s: u64, st: &AlphaIIIState) -> (Vec<Vec<u8>>, Hash256) {
     let mut list = st.admitted_by_slot.get(&s).cloned().unwrap_or_default();
-    list.sort_by(|a, b| a.txid.cmp(&b.txid));
+    list.sort_by_key(|a| a.txid);
+    // Sorting fixes the order but not a txid admitted twice into the slot.
+    debug_assert_eq!(assert_canonical_ticket_order(&list), Ok(()));
     let leaves: Vec<Vec<u8>> = list.iter().map(enc_ticket_leaf).collect();
     let root = merkle_root(&leaves);
     (leaves, root)
 }
 
+/// `txroot_s`, the root the header of slot `s + 1` commits as `txroot_prev`.
+///
+/// Leaves are `H("obex.txid.leaf",[]) || txid` of the tickets executed in `s` (`s_exec == s`) in
+/// ascending txid order, followed by `sys_tx_leaves`: the α-T `enc_sys_tx` encodings of the
+/// system transactions `settle_slot` emitted for `s`, in emission order. Pass an empty
+/// `sys_tx_leaves` only for a slot that settled no system transactions.
+#[must_use]
+pub fn build_txroot_for_slot(
+    s: u64,
+    st: &AlphaIIIState,
+    sys_tx_leaves: &[Vec<u8>],
+) -> (Vec<Vec<u8>>, Hash256) {
+    let leaves = txroot_leaves(s, st.tickets_by_txid.values(), sys_tx_leaves);
+    let root = merkle_root(&leaves);
+    (leaves, root)
+}
+
+/// The `txroot_prev` a header for slot `parent_slot + 1` must commit.
+///
+/// Same root as `build_txroot_for_slot(parent_slot, ..)`, from the parent slot's `tickets`
+/// (others than `s_exec == parent_slot` are ignored, order is irrelevant) instead of the whole
+/// state. Depends only on the parent slot's own outcome, so it can be recomputed after a reorg
+/// from the tickets and settlement of the new parent alone.
+#[must_use]
+pub fn txroot_prev_from_settlement(
+    parent_slot: u64,
+    tickets: &[TicketRecord],
+    sys_tx_leaves: &[Vec<u8>],
+) -> Hash256 {
+    merkle_root(&txroot_leaves(parent_slot, tickets, sys_tx_leaves))
+}
+
+fn txroot_leaves<'a>(
+    s: u64,
+    tickets: impl IntoIterator<Item = &'a TicketRecord>,
+    sys_tx_leaves: &[Vec<u8>],
+) -> Vec<Vec<u8>> {
+    let mut txids: Vec<Hash256> = tickets
+        .into_iter()
+        .filter(|t| t.s_exec == s)
+        .map(|t| t.txid)
+        .collect();
+    txids.sort_unstable();
+    txids
+        .iter()
+        .map(txid_leaf)
+        .chain(sys_tx_leaves.iter().cloned())
+        .collect()
+}
+
+fn txid_leaf(txid: &Hash256) -> Vec<u8> {
+    let mut v = Vec::with_capacity(64);
+    v.extend_from_slice(&h_tag(constants::TAG_TXID_LEAF, &[]));
+    v.extend_from_slice(txid);
+    v
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,95 @@
+mod common;
+
+use ed25519_dalek::SigningKey;
+use obex_alpha_iii::*;
+use obex_primitives::Hash256;
+
+use common::{sign, transfer};
+
+const fn pk(v: u8) -> [u8; 32] {
+    [v; 32]
+}
+
+fn access(read: &[u8], write: &[u8]) -> AccessList {
+    AccessList {
+        read_accounts: read.iter().map(|&v| pk(v)).collect(),
+        write_accounts: write.iter().map(|&v| pk(v)).collect(),
+    }
+}
+
+#[test]
+fn disjoint_and_read_only_sharing_do_not_conflict() {
+    assert!(!access_conflict(&access(&[1], &[2]), &access(&[3], &[4])));
+    assert!(!access_conflict(
+        &access(&[1, 5], &[2]),
+        &access(&[5, 1], &[4])
+    ));
+    assert!(!access_conflict(
+        &AccessList::default(),
+        &access(&[1], &[1])
+    ));
+}
+
+#[test]
+fn overlapping_writes_or_write_read_conflict() {
+    assert!(access_conflict(
+        &access(&[], &[7, 2]),
+        &access(&[], &[3, 7])
+    ));
+    assert!(access_conflict(&access(&[], &[7]), &access(&[7], &[])));
+    assert!(access_conflict(&access(&[7], &[]), &access(&[], &[7])));
+    // Symmetric
+    let (a, b) = (access(&[1], &[9]), access(&[9], &[2]));
+    assert_eq!(access_conflict(&a, &b), access_conflict(&b, &a));
+}
+
+#[test]
+fn reject_policy_skips_conflicting_candidates() {
+    let y_prev: Hash256 = [4u8; 32];
+    let keys: Vec<SigningKey> = (1..=3u8)
+        .map(|i| SigningKey::from_bytes(&[i; 32]))
+        .collect();
+    let candidates = vec![
+        sign(
+            &keys[0],
+            TxBodyV1 {
+                access: access(&[], &[10]),
+                ..transfer(&keys[0], 0, 2_000, 5, &y_prev)
+            },
+        ),
+        sign(
+            &keys[1],
+            TxBodyV1 {
+                access: access(&[], &[10]),
+                ..transfer(&keys[1], 0, 2_000, 5, &y_prev)
+            },
+        ),
+        sign(
+            &keys[2],
+            TxBodyV1 {
+                access: access(&[10], &[11]),
+                ..transfer(&keys[2], 0, 2_000, 5, &y_prev)
+            },
+        ),
+    ];
+    let fund = |st: &mut AlphaIIIState| {
+        for k in &keys {
+            st.spendable_u.insert(k.verifying_key().to_bytes(), 10_000);
+        }
+    };
+
+    let mut st = AlphaIIIState::default();
+    fund(&mut st);
+    let sequenced = admit_slot_canonical(5, &y_prev, &candidates, &mut st);
+    assert_eq!(sequenced.len(), 3);
+
+    let mut st = AlphaIIIState::default();
+    fund(&mut st);
+    let rejected =
+        admit_slot_canonical_with_policy(5, &y_prev, &candidates, &mut st, ConflictPolicy::Reject);
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected[0].txid, txid(&candidates[0].0));
+    // Skipped candidates leave no state behind
+    assert_eq!(st.nonce_of(&candidates[1].0.sender), 0);
+    assert_eq!(st.nonce_of(&candidates[2].0.sender), 0);
+}
@@ -1,6 +1,10 @@
+mod common;
+
 use obex_alpha_iii::*;
 use obex_primitives::{constants, h_tag, Hash256};
 
+use common::{signed, transfer};
+
 const fn pk(v: u8) -> [u8; 32] {
     [v; 32]
 }
@@ -34,3 +38,311 @@ fn nonce_equality_and_insufficient_funds_and_canonical_order() {
     let (_leaves, root) = build_ticket_root_for_slot(7, &st);
     assert_eq!(root, h_tag(constants::TAG_MERKLE_EMPTY, &[]));
 }
+
+#[test]
+fn correctly_signed_tx_admitted_zero_sig_rejected() {
+    use ed25519_dalek::{Signer, SigningKey};
+    let sk = SigningKey::from_bytes(&[11u8; 32]);
+    let y_prev: Hash256 = [4u8; 32];
+    let tx = transfer(&sk, 0, 2_000, 3, &y_prev);
+
+    let mut st = AlphaIIIState::default();
+    st.spendable_u.insert(tx.sender, 10_000);
+    assert_eq!(
+        admit_single(&tx, &[0u8; 64], 3, &y_prev, &mut st),
+        AdmitResult::Rejected(AdmitErr::BadSig)
+    );
+    assert_eq!(st.nonce_of(&tx.sender), 0);
+
+    let sig = sk.sign(&tx_sig_msg(&tx)).to_bytes();
+    let AdmitResult::Finalized(rec) = admit_single(&tx, &sig, 3, &y_prev, &mut st) else {
+        panic!("signed tx must be admitted");
+    };
+    assert_eq!(rec.txid, txid(&tx));
+    assert_eq!(
+        rec.ticket_id,
+        h_tag(
+            constants::TAG_TICKET_ID,
+            &[&rec.txid, &obex_primitives::le_bytes::<8>(3)]
+        )
+    );
+    assert_eq!(st.nonce_of(&tx.sender), 1);
+    assert_eq!(st.reserved_of(&tx.sender), 2_000 + fee_int_uobx(2_000));
+}
+
+#[test]
+fn signature_over_other_messages_rejected() {
+    use ed25519_dalek::{Signer, SigningKey};
+    let sk = SigningKey::from_bytes(&[12u8; 32]);
+    let y_prev: Hash256 = [4u8; 32];
+    let tx = transfer(&sk, 0, 2_000, 3, &y_prev);
+    let mut st = AlphaIIIState::default();
+    st.spendable_u.insert(tx.sender, 10_000);
+    // Only H("obex.tx.sig", canonical bytes) is accepted; tx_commit/txid are not signing messages
+    for msg in [tx_commit(&tx), txid(&tx)] {
+        let sig = sk.sign(&msg).to_bytes();
+        assert_eq!(
+            admit_single(&tx, &sig, 3, &y_prev, &mut st),
+            AdmitResult::Rejected(AdmitErr::BadSig)
+        );
+    }
+    // A different key's valid signature over the right message is rejected too
+    let other = SigningKey::from_bytes(&[13u8; 32]);
+    let sig = other.sign(&tx_sig_msg(&tx)).to_bytes();
+    assert_eq!(
+        admit_single(&tx, &sig, 3, &y_prev, &mut st),
+        AdmitResult::Rejected(AdmitErr::BadSig)
+    );
+}
+
+#[test]
+fn memo_cap_enforced_at_admission_and_decode() {
+    use ed25519_dalek::{Signer, SigningKey};
+    let sk = SigningKey::from_bytes(&[14u8; 32]);
+    let y_prev: Hash256 = [4u8; 32];
+    let mut st = AlphaIIIState::default();
+    let mut tx = transfer(&sk, 0, 2_000, 3, &y_prev);
+    st.spendable_u.insert(tx.sender, 100_000);
+
+    tx.memo = vec![0xAB; MAX_MEMO_BYTES + 1];
+    let sig = sk.sign(&tx_sig_msg(&tx)).to_bytes();
+    assert_eq!(
+        admit_single(&tx, &sig, 3, &y_prev, &mut st),
+        AdmitResult::Rejected(AdmitErr::MemoTooLarge)
+    );
+    assert_eq!(
+        decode_txbody(&encode_txbody(&tx)),
+        Err(CodecError::MemoTooLarge)
+    );
+
+    tx.memo = vec![0xAB; MAX_MEMO_BYTES];
+    let sig = sk.sign(&tx_sig_msg(&tx)).to_bytes();
+    assert!(matches!(
+        admit_single(&tx, &sig, 3, &y_prev, &mut st),
+        AdmitResult::Finalized(_)
+    ));
+    assert_eq!(decode_txbody(&encode_txbody(&tx)).unwrap(), tx);
+}
+
+#[test]
+fn nonces_monotone_across_slots_and_replay_rejected() {
+    use ed25519_dalek::{Signer, SigningKey};
+    let sk = SigningKey::from_bytes(&[15u8; 32]);
+    let y_prev: Hash256 = [4u8; 32];
+    let mut st = AlphaIIIState::default();
+    let sign = |tx: &TxBodyV1| sk.sign(&tx_sig_msg(tx)).to_bytes();
+
+    // Slot 3: nonce 0 admitted
+    let tx0 = transfer(&sk, 0, 2_000, 3, &y_prev);
+    st.spendable_u.insert(tx0.sender, 100_000);
+    assert!(matches!(
+        admit_single(&tx0, &sign(&tx0), 3, &y_prev, &mut st),
+        AdmitResult::Finalized(_)
+    ));
+    // Exact replay in the same slot
+    assert_eq!(
+        admit_single(&tx0, &sign(&tx0), 3, &y_prev, &mut st),
+        AdmitResult::Rejected(AdmitErr::NonceMismatch)
+    );
+    // Replay of nonce 0 re-bound to slot 4
+    let mut replay = tx0.clone();
+    replay.s_bind = 4;
+    assert_eq!(
+        admit_single(&replay, &sign(&replay), 4, &y_prev, &mut st),
+        AdmitResult::Rejected(AdmitErr::NonceMismatch)
+    );
+    // Future nonce rejected, in-order nonce 1 admitted in slot 4
+    let mut future = replay.clone();
+    future.nonce = 5;
+    assert_eq!(
+        admit_single(&future, &sign(&future), 4, &y_prev, &mut st),
+        AdmitResult::Rejected(AdmitErr::NonceMismatch)
+    );
+    let mut tx1 = replay;
+    tx1.nonce = 1;
+    assert!(matches!(
+        admit_single(&tx1, &sign(&tx1), 4, &y_prev, &mut st),
+        AdmitResult::Finalized(_)
+    ));
+    assert_eq!(st.nonce_of(&tx1.sender), 2);
+    assert_eq!(st.admitted_by_slot[&3].len(), 1);
+    assert_eq!(st.admitted_by_slot[&4].len(), 1);
+}
+
+#[test]
+fn ticket_record_verifies_against_its_transaction() {
+    use ed25519_dalek::{Signer, SigningKey};
+    let sk = SigningKey::from_bytes(&[12u8; 32]);
+    let y_prev: Hash256 = [6u8; 32];
+    let tx = transfer(&sk, 0, 2_000, 5, &y_prev);
+    let sig = sk.sign(&tx_sig_msg(&tx)).to_bytes();
+    let mut st = AlphaIIIState::default();
+    st.spendable_u.insert(tx.sender, 10_000);
+    let AdmitResult::Finalized(rec) = admit_single(&tx, &sig, 5, &y_prev, &mut st) else {
+        panic!("signed tx must be admitted");
+    };
+
+    assert_eq!(verify_ticket_record(&rec, &tx, 5), Ok(()));
+    assert_eq!(
+        verify_ticket_record(&rec, &tx, 6),
+        Err(TicketError::TicketIdMismatch)
+    );
+    let mut other_nonce = tx.clone();
+    other_nonce.nonce += 1;
+    assert_eq!(
+        verify_ticket_record(&rec, &other_nonce, 5),
+        Err(TicketError::TxidMismatch)
+    );
+}
+
+#[test]
+fn ticket_record_with_mismatched_amount_is_rejected() {
+    use ed25519_dalek::{Signer, SigningKey};
+    let sk = SigningKey::from_bytes(&[13u8; 32]);
+    let y_prev: Hash256 = [7u8; 32];
+    let tx = transfer(&sk, 0, 2_000, 2, &y_prev);
+    let sig = sk.sign(&tx_sig_msg(&tx)).to_bytes();
+    let mut st = AlphaIIIState::default();
+    st.spendable_u.insert(tx.sender, 10_000);
+    let AdmitResult::Finalized(rec) = admit_single(&tx, &sig, 2, &y_prev, &mut st) else {
+        panic!("signed tx must be admitted");
+    };
+
+    // Record tampered after admission: hashes still match the tx, the amount does not.
+    let mut forged = rec.clone();
+    forged.amount_u += 1;
+    assert_eq!(
+        verify_ticket_record(&forged, &tx, 2),
+        Err(TicketError::AmountMismatch)
+    );
+    // Transaction carrying a different amount: caught by the txid binding.
+    let mut bigger = tx;
+    bigger.amount_u += 1;
+    assert_eq!(
+        verify_ticket_record(&rec, &bigger, 2),
+        Err(TicketError::TxidMismatch)
+    );
+}
+
+#[test]
+fn admission_binds_transactions_to_slot_and_beacon() {
+    use ed25519_dalek::{Signer, SigningKey};
+    let sk = SigningKey::from_bytes(&[14u8; 32]);
+    let y_prev: Hash256 = [8u8; 32];
+    let tx = transfer(&sk, 0, 2_000, 9, &y_prev);
+    let sig = sk.sign(&tx_sig_msg(&tx)).to_bytes();
+    let fresh = || {
+        let mut st = AlphaIIIState::default();
+        st.spendable_u.insert(tx.sender, 10_000);
+        st
+    };
+
+    // Matching context: admitted.
+    let mut st = fresh();
+    let recs = admit_slot_canonical(9, &y_prev, &[(tx.clone(), sig)], &mut st);
+    assert_eq!(recs.len(), 1);
+    assert_eq!(recs[0].txid, txid(&tx));
+
+    // Same signed tx replayed under another beacon edge or into another slot.
+    let other_y: Hash256 = [0x18; 32];
+    let mut st = fresh();
+    assert!(admit_slot_canonical(9, &other_y, &[(tx.clone(), sig)], &mut st).is_empty());
+    assert_eq!(st, fresh());
+    assert_eq!(
+        admit_single(&tx, &sig, 9, &other_y, &mut st),
+        AdmitResult::Rejected(AdmitErr::WrongBeacon)
+    );
+    assert!(admit_slot_canonical(10, &y_prev, &[(tx.clone(), sig)], &mut st).is_empty());
+    assert_eq!(
+        admit_single(&tx, &sig, 10, &y_prev, &mut st),
+        AdmitResult::Rejected(AdmitErr::WrongSlot)
+    );
+    assert_eq!(st, fresh());
+}
+
+#[test]
+fn admitted_tickets_come_back_in_txid_order_every_run() {
+    use ed25519_dalek::SigningKey;
+    let y_prev: Hash256 = [2u8; 32];
+    let keys: Vec<SigningKey> = (20..28u8)
+        .map(|i| SigningKey::from_bytes(&[i; 32]))
+        .collect();
+    let candidates: Vec<(TxBodyV1, Sig)> = keys
+        .iter()
+        .map(|sk| signed(sk, 0, 2_000, 4, &y_prev))
+        .collect();
+    let run = || {
+        let mut st = AlphaIIIState::default();
+        for sk in &keys {
+            st.spendable_u.insert(sk.verifying_key().to_bytes(), 10_000);
+        }
+        let recs = admit_slot_canonical(4, &y_prev, &candidates, &mut st);
+        (recs, st)
+    };
+
+    let (first, st) = run();
+    let (second, _) = run();
+    assert_eq!(first.len(), keys.len());
+    assert_eq!(first, second);
+    assert_eq!(assert_canonical_ticket_order(&first), Ok(()));
+    // Same order as the ticket_root leaves.
+    let (leaves, _) = build_ticket_root_for_slot(4, &st);
+    let from_output: Vec<Vec<u8>> = first.iter().map(enc_ticket_leaf).collect();
+    assert_eq!(leaves, from_output);
+
+    // Reversing the candidates changes nothing about the returned order.
+    let mut st = AlphaIIIState::default();
+    for sk in &keys {
+        st.spendable_u.insert(sk.verifying_key().to_bytes(), 10_000);
+    }
+    let reversed: Vec<(TxBodyV1, Sig)> = candidates.iter().rev().cloned().collect();
+    assert_eq!(admit_slot_canonical(4, &y_prev, &reversed, &mut st), first);
+}
+
+#[test]
+fn out_of_order_or_repeated_tickets_are_not_canonical() {
+    let rec = |b: u8| TicketRecord {
+        ticket_id: [b; 32],
+        txid: [b; 32],
+        sender: pk(b),
+        nonce: 0,
+        amount_u: 1_000,
+        fee_u: 10,
+        s_admit: 3,
+        s_exec: 3,
+        commit_hash: [b; 32],
+    };
+    assert_eq!(assert_canonical_ticket_order(&[]), Ok(()));
+    assert_eq!(
+        assert_canonical_ticket_order(&[rec(1), rec(2), rec(5)]),
+        Ok(())
+    );
+    assert_eq!(
+        assert_canonical_ticket_order(&[rec(1), rec(5), rec(2)]),
+        Err(OrderError::OutOfOrder { index: 2 })
+    );
+    assert_eq!(
+        assert_canonical_ticket_order(&[rec(1), rec(1), rec(2)]),
+        Err(OrderError::DuplicateTxid { index: 1 })
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "DuplicateTxid")]
+fn ticket_root_debug_checks_for_a_repeated_txid() {
+    let rec = TicketRecord {
+        ticket_id: [1; 32],
+        txid: [1; 32],
+        sender: pk(1),
+        nonce: 0,
+        amount_u: 1_000,
+        fee_u: 10,
+        s_admit: 3,
+        s_exec: 3,
+        commit_hash: [1; 32],
+    };
+    let mut st = AlphaIIIState::default();
+    st.admitted_by_slot.insert(3, vec![rec.clone(), rec]);
+    let _ = build_ticket_root_for_slot(3, &st);
+}
@@ -0,0 +1,38 @@
+//! Fixtures shared by the integration tests; each test crate uses a subset.
+#![allow(dead_code)]
+
+use ed25519_dalek::{Signer, SigningKey};
+use obex_alpha_iii::{fee_int_uobx, tx_sig_msg, AccessList, Sig, TxBodyV1};
+use obex_primitives::Hash256;
+
+/// Unsigned transfer from `sk` bound to `(s, y_prev)`, paying the integer fee for `amount_u`.
+pub fn transfer(sk: &SigningKey, nonce: u64, amount_u: u128, s: u64, y_prev: &Hash256) -> TxBodyV1 {
+    TxBodyV1 {
+        sender: sk.verifying_key().to_bytes(),
+        recipient: [0xEE; 32],
+        nonce,
+        amount_u,
+        fee_u: fee_int_uobx(amount_u),
+        s_bind: s,
+        y_bind: *y_prev,
+        access: AccessList::default(),
+        memo: vec![],
+    }
+}
+
+/// `tx` paired with `sk`'s signature over its signing message.
+pub fn sign(sk: &SigningKey, tx: TxBodyV1) -> (TxBodyV1, Sig) {
+    let sig = sk.sign(&tx_sig_msg(&tx)).to_bytes();
+    (tx, sig)
+}
+
+/// [`transfer`] signed by `sk`.
+pub fn signed(
+    sk: &SigningKey,
+    nonce: u64,
+    amount_u: u128,
+    s: u64,
+    y_prev: &Hash256,
+) -> (TxBodyV1, Sig) {
+    sign(sk, transfer(sk, nonce, amount_u, s, y_prev))
+}
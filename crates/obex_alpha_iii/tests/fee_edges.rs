@@ -10,7 +10,14 @@ fn pk(sk: &SigningKey) -> [u8; 32] {
     sk.verifying_key().to_bytes()
 }
 
-fn tx(sender: [u8; 32], recipient: [u8; 32], nonce: u64, amount_u: u128, s_bind: u64, y_bind: [u8; 32]) -> TxBodyV1 {
+fn tx(
+    sender: [u8; 32],
+    recipient: [u8; 32],
+    nonce: u64,
+    amount_u: u128,
+    s_bind: u64,
+    y_bind: [u8; 32],
+) -> TxBodyV1 {
     TxBodyV1 {
         sender,
         recipient,
@@ -50,7 +57,10 @@ fn fee_rule_edges_and_ticket_root() {
 
     let mut st = AlphaIIIState::default();
     // Give sender sufficient balance for all three transfers + fees
-    st.spendable_u.insert(sender, 10 + 1000 + 1001 + fee_int_uobx(10) + fee_int_uobx(1000) + fee_int_uobx(1001));
+    st.spendable_u.insert(
+        sender,
+        10 + 1000 + 1001 + fee_int_uobx(10) + fee_int_uobx(1000) + fee_int_uobx(1001),
+    );
 
     let _recs = admit_slot_canonical(s_now, &y_prev, &candidates, &mut st);
     // With zero sigs, these may be rejected; assert determinism of helpers at least
@@ -64,5 +74,3 @@ fn fee_rule_edges_and_ticket_root() {
     // We assert the call succeeds and returns a 32-byte value different from all-zeroes in general
     assert_eq!(root.len(), 32);
 }
-
-
@@ -68,8 +68,8 @@ fn ticket_and_tx_roots_fixed_hex() {
         commit_hash: tx_commit(&tx2),
     };
 
-    let mut list = vec![rec1.clone(), rec2.clone()];
-    list.sort_by(|a, b| a.txid.cmp(&b.txid));
+    let mut list = [rec1.clone(), rec2.clone()];
+    list.sort_by_key(|a| a.txid);
     let leaves: Vec<Vec<u8>> = list.iter().map(enc_ticket_leaf).collect();
     let ticket_root = merkle_root(&leaves).encode_hex::<String>();
     assert_eq!(
@@ -92,6 +92,28 @@ fn ticket_and_tx_roots_fixed_hex() {
         txroot,
         "217d4936f78adf09a87c9a25171c280395fa185190308c4142a314266854433f"
     );
+
+    // The canonical builder reproduces the same leaves and root from state.
+    let mut st = AlphaIIIState::default();
+    for rec in [rec2, rec1] {
+        st.tickets_by_txid.insert(rec.txid, rec.clone());
+        st.admitted_by_slot.entry(s_now).or_default().push(rec);
+    }
+    let (leaves_built, root_built) = build_txroot_for_slot(s_now, &st, &[]);
+    assert_eq!(leaves_built, leaves_tx);
+    assert_eq!(root_built.encode_hex::<String>(), txroot);
+    assert_eq!(
+        build_txroot_for_slot(s_now + 1, &st, &[]).1,
+        merkle_root(&[])
+    );
+
+    // System-tx leaves follow the txid leaves, and the settlement helper agrees.
+    let sys = vec![vec![0xA5; 49], vec![0x5A; 49]];
+    let (leaves_sys, root_sys) = build_txroot_for_slot(s_now, &st, &sys);
+    assert_eq!(leaves_sys[..2], leaves_tx[..]);
+    assert_eq!(leaves_sys[2..], sys[..]);
+    let tickets: Vec<_> = st.tickets_by_txid.values().cloned().collect();
+    assert_eq!(txroot_prev_from_settlement(s_now, &tickets, &sys), root_sys);
 }
 
 #[test]
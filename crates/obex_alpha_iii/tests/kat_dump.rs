@@ -1,6 +1,5 @@
-use hex::ToHex;
 use obex_alpha_iii::*;
-use obex_primitives::{constants, h_tag, merkle_root, Pk32};
+use obex_primitives::{constants, h_tag, kat, merkle_root, Pk32};
 
 const fn pk(val: u8) -> Pk32 {
     [val; 32]
@@ -71,11 +70,11 @@ fn dump_ticket_and_tx_roots_hex() {
     };
 
     // ticket_root: sort by txid ascending
-    let mut list = vec![rec1.clone(), rec2.clone()];
-    list.sort_by(|a, b| a.txid.cmp(&b.txid));
+    let mut list = [rec1.clone(), rec2.clone()];
+    list.sort_by_key(|a| a.txid);
     let leaves: Vec<Vec<u8>> = list.iter().map(enc_ticket_leaf).collect();
     let ticket_root = merkle_root(&leaves);
-    println!("TICKET_ROOT:{}", ticket_root.encode_hex::<String>());
+    println!("{}", kat::dump_hash("TICKET_ROOT", &ticket_root));
 
     // txroot: leaves = tag || txid for executed (previous slot); here we just use tx ids
     let txids = [rec1.txid, rec2.txid];
@@ -89,5 +88,5 @@ fn dump_ticket_and_tx_roots_hex() {
         })
         .collect();
     let txroot = merkle_root(&leaves_tx);
-    println!("TXROOT:{}", txroot.encode_hex::<String>());
+    println!("{}", kat::dump_hash("TXROOT", &txroot));
 }
@@ -0,0 +1,114 @@
+mod common;
+
+use ed25519_dalek::SigningKey;
+use obex_alpha_iii::*;
+use obex_primitives::Hash256;
+
+use common::signed;
+
+const SLOT: u64 = 9;
+const Y: Hash256 = [3u8; 32];
+
+fn key(i: u8) -> SigningKey {
+    SigningKey::from_bytes(&[i; 32])
+}
+
+fn sample() -> Vec<(TxBodyV1, Sig)> {
+    vec![
+        signed(&key(1), 0, 500, SLOT, &Y),
+        signed(&key(1), 1, 90_000, SLOT, &Y),
+        signed(&key(2), 0, 50_000, SLOT, &Y),
+        signed(&key(2), 1, 2_000, SLOT, &Y),
+        signed(&key(3), 0, 20_000, SLOT, &Y),
+    ]
+}
+
+fn funded() -> AlphaIIIState {
+    let mut st = AlphaIIIState::default();
+    for i in 1..=4 {
+        st.spendable_u
+            .insert(key(i).verifying_key().to_bytes(), 1_000_000);
+    }
+    st
+}
+
+#[test]
+fn selection_is_deterministic_and_respects_nonce_order() {
+    let st = funded();
+    let mut forward = Mempool::new();
+    let mut backward = Mempool::new();
+    for (tx, sig) in sample() {
+        assert!(forward.insert(tx, sig));
+    }
+    for (tx, sig) in sample().into_iter().rev() {
+        assert!(backward.insert(tx, sig));
+    }
+    let a = forward.select_for_slot(SLOT, &Y, &st, 10);
+    let b = backward.select_for_slot(SLOT, &Y, &st, 10);
+    assert_eq!(a, b);
+
+    // Highest-fee head first; sender 1's big tx waits for its nonce-0 predecessor.
+    let order: Vec<(u8, u64)> = a
+        .iter()
+        .map(|(tx, _)| {
+            (
+                (1..=3)
+                    .find(|&i| key(i).verifying_key().to_bytes() == tx.sender)
+                    .unwrap(),
+                tx.nonce,
+            )
+        })
+        .collect();
+    assert_eq!(order, vec![(2, 0), (3, 0), (2, 1), (1, 0), (1, 1)]);
+
+    // Output is accepted as-is by admission
+    let mut st = funded();
+    assert_eq!(admit_slot_canonical(SLOT, &Y, &a, &mut st).len(), a.len());
+
+    assert_eq!(forward.select_for_slot(SLOT, &Y, &funded(), 2).len(), 2);
+}
+
+#[test]
+fn nonce_gaps_and_foreign_bindings_end_a_run() {
+    let mut pool = Mempool::new();
+    for n in [0u64, 1, 3] {
+        let (tx, sig) = signed(&key(4), n, 2_000, SLOT, &Y);
+        assert!(pool.insert(tx, sig));
+    }
+    let selected = pool.select_for_slot(SLOT, &Y, &funded(), 10);
+    assert_eq!(
+        selected.iter().map(|(tx, _)| tx.nonce).collect::<Vec<_>>(),
+        vec![0, 1]
+    );
+
+    // State nonce already at 1: nonce 0 is stale, run starts at 1
+    let mut st = funded();
+    let (tx0, sig0) = signed(&key(4), 0, 2_000, SLOT, &Y);
+    let _ = admit_slot_canonical(SLOT, &Y, &[(tx0, sig0)], &mut st);
+    let selected = pool.select_for_slot(SLOT, &Y, &st, 10);
+    assert_eq!(
+        selected.iter().map(|(tx, _)| tx.nonce).collect::<Vec<_>>(),
+        vec![1]
+    );
+
+    assert!(pool.select_for_slot(SLOT + 1, &Y, &funded(), 10).is_empty());
+    assert!(pool
+        .select_for_slot(SLOT, &[0u8; 32], &funded(), 10)
+        .is_empty());
+}
+
+#[test]
+fn insert_refuses_bad_fee_and_keeps_best_duplicate() {
+    let mut pool = Mempool::new();
+    let (mut bad, sig) = signed(&key(1), 0, 2_000, SLOT, &Y);
+    bad.fee_u += 1;
+    assert!(!pool.insert(bad, sig));
+    assert!(pool.is_empty());
+
+    let (low, low_sig) = signed(&key(1), 0, 2_000, SLOT, &Y);
+    let (high, high_sig) = signed(&key(1), 0, 80_000, SLOT, &Y);
+    assert!(pool.insert(high.clone(), high_sig));
+    assert!(!pool.insert(low, low_sig));
+    assert_eq!(pool.len(), 1);
+    assert_eq!(pool.select_for_slot(SLOT, &Y, &funded(), 1)[0].0, high);
+}
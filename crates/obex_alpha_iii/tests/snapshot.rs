@@ -0,0 +1,130 @@
+mod common;
+
+use ed25519_dalek::SigningKey;
+use obex_alpha_iii::*;
+use obex_primitives::Hash256;
+
+use common::signed;
+
+#[test]
+fn admit_then_restore_returns_exact_prior_state() {
+    let y_prev: Hash256 = [4u8; 32];
+    let a = SigningKey::from_bytes(&[21u8; 32]);
+    let b = SigningKey::from_bytes(&[22u8; 32]);
+    let mut st = AlphaIIIState::default();
+    st.spendable_u.insert(a.verifying_key().to_bytes(), 50_000);
+    st.spendable_u.insert(b.verifying_key().to_bytes(), 50_000);
+    // Pre-existing admission in slot 3 that must survive the rollback
+    let (tx, sig) = signed(&a, 0, 2_000, 3, &y_prev);
+    assert!(matches!(
+        admit_single(&tx, &sig, 3, &y_prev, &mut st),
+        AdmitResult::Finalized(_)
+    ));
+    let before = st.clone();
+
+    let snap = st.snapshot();
+    let cands = vec![
+        signed(&a, 1, 2_000, 3, &y_prev),
+        signed(&b, 0, 2_000, 3, &y_prev),
+        signed(&a, 2, 2_000, 3, &y_prev),
+    ];
+    assert_eq!(admit_slot_canonical(3, &y_prev, &cands, &mut st).len(), 3);
+    let (_, spec_root) = build_ticket_root_for_slot(3, &st);
+    // A new slot entry created under the snapshot
+    let (tx4, sig4) = signed(&b, 1, 2_000, 4, &y_prev);
+    assert!(matches!(
+        admit_single(&tx4, &sig4, 4, &y_prev, &mut st),
+        AdmitResult::Finalized(_)
+    ));
+    assert_ne!(st, before);
+    assert_eq!(st.restore(snap), Ok(()));
+    assert_eq!(st, before);
+    assert_ne!(build_ticket_root_for_slot(3, &st).1, spec_root);
+
+    // Nested snapshots: commit inner, restore outer
+    let outer = st.snapshot();
+    let inner = st.snapshot();
+    let (tx, sig) = signed(&a, 1, 2_000, 3, &y_prev);
+    assert!(matches!(
+        admit_single(&tx, &sig, 3, &y_prev, &mut st),
+        AdmitResult::Finalized(_)
+    ));
+    assert_eq!(st.commit(inner), Ok(()));
+    assert_eq!(st.restore(outer), Ok(()));
+    assert_eq!(st, before);
+
+    // Commit keeps the speculative admissions
+    let snap = st.snapshot();
+    assert_eq!(admit_slot_canonical(3, &y_prev, &cands, &mut st).len(), 3);
+    assert_eq!(st.commit(snap), Ok(()));
+    assert_eq!(build_ticket_root_for_slot(3, &st).1, spec_root);
+}
+
+#[test]
+fn out_of_order_release_is_rejected_and_leaves_state_untouched() {
+    let y_prev: Hash256 = [4u8; 32];
+    let a = SigningKey::from_bytes(&[23u8; 32]);
+    let mut st = AlphaIIIState::default();
+    st.spendable_u.insert(a.verifying_key().to_bytes(), 50_000);
+    let before = st.clone();
+
+    let outer = st.snapshot();
+    let inner = st.snapshot();
+    // Open snapshots are bookkeeping, not ledger contents
+    assert_eq!(st, before);
+    let (tx, sig) = signed(&a, 0, 2_000, 3, &y_prev);
+    assert!(matches!(
+        admit_single(&tx, &sig, 3, &y_prev, &mut st),
+        AdmitResult::Finalized(_)
+    ));
+    let admitted = st.clone();
+    assert_eq!(
+        st.restore(outer),
+        Err(SnapshotError::OutOfOrder { depth: 0, open: 2 })
+    );
+    assert_eq!(st, admitted);
+
+    let innermost = st.snapshot();
+    assert_eq!(
+        st.commit(inner),
+        Err(SnapshotError::OutOfOrder { depth: 1, open: 3 })
+    );
+    assert_eq!(st.restore(innermost), Ok(()));
+    assert_eq!(st, admitted);
+}
+
+#[test]
+fn abandoning_a_dropped_snapshot_closes_it_and_invalidates_held_tokens() {
+    let y_prev: Hash256 = [4u8; 32];
+    let a = SigningKey::from_bytes(&[24u8; 32]);
+    let mut st = AlphaIIIState::default();
+    st.spendable_u.insert(a.verifying_key().to_bytes(), 50_000);
+
+    // One token still held, one nested inside it lost without release
+    let held = st.snapshot();
+    drop(st.snapshot());
+    let (tx, sig) = signed(&a, 0, 2_000, 3, &y_prev);
+    assert!(matches!(
+        admit_single(&tx, &sig, 3, &y_prev, &mut st),
+        AdmitResult::Finalized(_)
+    ));
+    assert_eq!(st.open_snapshots(), 2);
+    let admitted = st.clone();
+
+    st.abandon_snapshots();
+    assert_eq!(st.open_snapshots(), 0);
+    assert_eq!(st, admitted);
+
+    // A fresh snapshot at the held token's depth is not released by the stale token
+    let fresh = st.snapshot();
+    assert_eq!(st.restore(held), Err(SnapshotError::Abandoned));
+    assert_eq!(st.open_snapshots(), 1);
+    let (tx, sig) = signed(&a, 1, 2_000, 3, &y_prev);
+    assert!(matches!(
+        admit_single(&tx, &sig, 3, &y_prev, &mut st),
+        AdmitResult::Finalized(_)
+    ));
+    assert_eq!(st.restore(fresh), Ok(()));
+    assert_eq!(st, admitted);
+    assert_eq!(st.open_snapshots(), 0);
+}
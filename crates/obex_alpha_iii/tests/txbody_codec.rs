@@ -0,0 +1,138 @@
+use hex::ToHex;
+use obex_alpha_iii::*;
+
+fn golden_tx() -> TxBodyV1 {
+    TxBodyV1 {
+        sender: [1u8; 32],
+        recipient: [2u8; 32],
+        nonce: 7,
+        amount_u: 1_234_567,
+        fee_u: fee_int_uobx(1_234_567),
+        s_bind: 42,
+        y_bind: [3u8; 32],
+        access: AccessList {
+            read_accounts: vec![[5u8; 32], [4u8; 32]],
+            write_accounts: vec![[6u8; 32]],
+        },
+        memo: b"obex memo".to_vec(),
+    }
+}
+
+#[test]
+fn txbody_roundtrip_golden() {
+    let tx = golden_tx();
+    let bytes = encode_txbody(&tx);
+    let dec = decode_txbody(&bytes).expect("decode");
+    // Access lists come back in canonical (sorted, deduplicated) order
+    assert_eq!(dec.access.read_accounts, vec![[4u8; 32], [5u8; 32]]);
+    assert_eq!(txid(&dec), txid(&tx));
+    assert_eq!(tx_commit(&dec), tx_commit(&tx));
+    assert_eq!(encode_txbody(&dec), bytes);
+    assert_eq!(
+        bytes.len(),
+        32 + 32 + 32 + 8 + 16 + 16 + 8 + 32 + (32 + 4 + 64 + 4 + 32) + 4 + 9
+    );
+    assert_eq!(txid(&tx).encode_hex::<String>(), GOLDEN_TXID_HEX);
+}
+
+const GOLDEN_TXID_HEX: &str = "c9f0b9056c22ce0e15df51db548e2231280925f532a87095868e263f2a5efafd";
+
+#[test]
+fn txbody_rejects_trailing_short_and_noncanonical() {
+    let bytes = encode_txbody(&golden_tx());
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(decode_txbody(&trailing), Err(CodecError::Trailing));
+    assert_eq!(
+        decode_txbody(&bytes[..bytes.len() - 1]),
+        Err(CodecError::Short)
+    );
+    let mut bad_tag = bytes.clone();
+    bad_tag[0] ^= 1;
+    assert_eq!(decode_txbody(&bad_tag), Err(CodecError::BadTag));
+    // Swap the two read accounts → unsorted list
+    let mut unsorted = bytes;
+    let r0 = 32 + 32 + 32 + 8 + 16 + 16 + 8 + 32 + 32 + 4;
+    let (a, b) = unsorted[r0..r0 + 64].split_at_mut(32);
+    a.swap_with_slice(b);
+    assert_eq!(
+        decode_txbody(&unsorted),
+        Err(CodecError::NonCanonicalAccess)
+    );
+}
+
+/// `H("obex.tx.access", [])`.
+const ACCESS_TAG_HEX: &str = "c712fcfe14ad6ca1a72228ffea3bef61edd6b11d2a9391030877acf35981d4b7";
+
+#[test]
+fn access_list_encoding_golden_and_roundtrip() {
+    let access = golden_tx().access;
+    let bytes = encode_access(&access);
+    let expected = format!(
+        "{ACCESS_TAG_HEX}02000000{}{}01000000{}",
+        "04".repeat(32),
+        "05".repeat(32),
+        "06".repeat(32)
+    );
+    assert_eq!(bytes.encode_hex::<String>(), expected);
+
+    let dec = decode_access(&bytes).expect("decode access");
+    assert_eq!(dec.read_accounts, vec![[4u8; 32], [5u8; 32]]);
+    assert_eq!(dec.write_accounts, vec![[6u8; 32]]);
+    assert_eq!(encode_access(&dec), bytes);
+
+    // The same bytes sit verbatim inside the txid preimage.
+    let body = canonical_tx_bytes(&golden_tx());
+    assert!(body.windows(bytes.len()).any(|w| w == bytes.as_slice()));
+    let tx = TxBodyV1 {
+        access: dec,
+        ..golden_tx()
+    };
+    assert_eq!(txid(&tx), txid(&golden_tx()));
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(decode_access(&trailing), Err(CodecError::Trailing));
+    let dup = AccessList {
+        read_accounts: vec![[4u8; 32]],
+        write_accounts: vec![],
+    };
+    let mut dup_bytes = encode_access(&dup);
+    dup_bytes[32] = 2;
+    dup_bytes.splice(36..36, [4u8; 32]);
+    assert_eq!(
+        decode_access(&dup_bytes),
+        Err(CodecError::NonCanonicalAccess)
+    );
+}
+
+#[test]
+fn ticket_leaf_roundtrip_and_rejects_malformed() {
+    let tx = golden_tx();
+    let xid = txid(&tx);
+    let rec = TicketRecord {
+        ticket_id: [0xA5; 32],
+        txid: xid,
+        sender: tx.sender,
+        nonce: u64::MAX - 1,
+        amount_u: tx.amount_u,
+        fee_u: tx.fee_u,
+        s_admit: 42,
+        s_exec: 43,
+        commit_hash: tx_commit(&tx),
+    };
+    let leaf = enc_ticket_leaf(&rec);
+    assert_eq!(leaf.len(), 32 + 32 + 32 + 32 + 8 + 16 + 16 + 8 + 8 + 32);
+    assert_eq!(dec_ticket_leaf(&leaf), Ok(rec));
+
+    assert_eq!(
+        dec_ticket_leaf(&leaf[..leaf.len() - 1]),
+        Err(CodecError::Short)
+    );
+    let mut trailing = leaf.clone();
+    trailing.push(0);
+    assert_eq!(dec_ticket_leaf(&trailing), Err(CodecError::Trailing));
+    let mut bad_tag = leaf;
+    bad_tag[0] ^= 1;
+    assert_eq!(dec_ticket_leaf(&bad_tag), Err(CodecError::BadTag));
+}
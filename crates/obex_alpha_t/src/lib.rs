@@ -54,6 +54,24 @@ static R0_DEN: Lazy<U256> =
 pub struct EmissionState {
     pub total_emitted_u: u128,
     pub acc_num: U256,
+    /// Last slot applied to this state (0 = none); the resume point for `advance_emission_to`.
+    pub last_slot: u128,
+}
+
+impl EmissionState {
+    /// Supply conservation: `total_emitted_u ≤ TOTAL_SUPPLY_UOBX` (exactly equal once
+    /// `LAST_EMISSION_SLOT` is applied) and the state is the one the schedule yields after
+    /// slots `1..=last_slot`. Costs one pass per halving period, not per slot.
+    #[must_use]
+    pub fn invariant_ok(&self) -> bool {
+        if self.last_slot > LAST_EMISSION_SLOT || self.total_emitted_u > TOTAL_SUPPLY_UOBX {
+            return false;
+        }
+        if self.last_slot == LAST_EMISSION_SLOT && self.total_emitted_u != TOTAL_SUPPLY_UOBX {
+            return false;
+        }
+        *self == emission_state_through(self.last_slot)
+    }
 }
 
 #[inline]
@@ -77,6 +95,7 @@ pub fn on_slot_emission(
     if slot_1based == 0 || slot_1based > LAST_EMISSION_SLOT {
         return;
     }
+    st.last_slot = slot_1based;
     let p = period_index(slot_1based);
     let den = reward_den_for_period(p);
     st.acc_num += *R0_NUM;
@@ -103,6 +122,67 @@ pub fn on_slot_emission(
     }
 }
 
+/// Advance `st` from `st.last_slot` to `target_slot` (clamped to `LAST_EMISSION_SLOT`).
+///
+/// No per-slot replay: within a halving period each slot adds `R0_NUM` and pays `floor(acc / den)`,
+/// so `k` slots pay `floor((r + k·R0_NUM) / den)` and leave the remainder in the accumulator.
+/// `credit_emission` receives one aggregate amount per period window crossed, plus the terminal
+/// flush; the resulting state equals driving `on_slot_emission` over each skipped slot.
+pub fn advance_emission_to(
+    st: &mut EmissionState,
+    target_slot: u128,
+    mut credit_emission: impl FnMut(u128),
+) {
+    let target = target_slot.min(LAST_EMISSION_SLOT);
+    while st.last_slot < target {
+        let start = st.last_slot + 1;
+        let p = period_index(start);
+        let end = target.min(u128::from(p + 1) * SLOTS_PER_HALVING);
+        let den = reward_den_for_period(p);
+        st.acc_num += U256::from(end - start + 1) * *R0_NUM;
+        let pay = (st.acc_num / den)
+            .as_u128()
+            .min(TOTAL_SUPPLY_UOBX - st.total_emitted_u);
+        if pay > 0 {
+            credit_emission(pay);
+            st.total_emitted_u += pay;
+            st.acc_num -= U256::from(pay) * den;
+        }
+        st.last_slot = end;
+        if end == LAST_EMISSION_SLOT {
+            let remaining = TOTAL_SUPPLY_UOBX - st.total_emitted_u;
+            if remaining > 0 {
+                credit_emission(remaining);
+                st.total_emitted_u = TOTAL_SUPPLY_UOBX;
+                st.acc_num = U256::zero();
+            }
+        }
+    }
+}
+
+/// Emission state after slots `1..=slot_1based`.
+fn emission_state_through(slot_1based: u128) -> EmissionState {
+    let mut st = EmissionState::default();
+    advance_emission_to(&mut st, slot_1based, |_| {});
+    st
+}
+
+/// Total emitted through slot `slot_1based` (inclusive), including the terminal flush.
+/// Agrees exactly with driving `on_slot_emission` over `1..=slot_1based`.
+#[must_use]
+pub fn cumulative_emission_through(slot_1based: u128) -> u128 {
+    emission_state_through(slot_1based).total_emitted_u
+}
+
+/// Amount credited by `on_slot_emission` at slot `slot_1based` (0 outside `1..=LAST_EMISSION_SLOT`).
+#[must_use]
+pub fn emission_at_slot(slot_1based: u128) -> u128 {
+    if slot_1based == 0 {
+        return 0;
+    }
+    cumulative_emission_through(slot_1based) - cumulative_emission_through(slot_1based - 1)
+}
+
 pub const MIN_TRANSFER_U: u128 = 10;
 pub const FLAT_SWITCH_U: u128 = 1_000;
 pub const FLAT_FEE_U: u128 = 10;
@@ -118,6 +198,18 @@ pub fn fee_int(amount_u: u128) -> u128 {
     }
 }
 
+/// Non-panicking `fee_int` for untrusted amounts.
+pub const fn try_fee_int(amount_u: u128) -> Result<u128, FeeError> {
+    if amount_u < MIN_TRANSFER_U {
+        return Err(FeeError::BelowMinimum);
+    }
+    Ok(if amount_u <= FLAT_SWITCH_U {
+        FLAT_FEE_U
+    } else {
+        amount_u.div_ceil(100)
+    })
+}
+
 pub const NLB_EPOCH_SLOTS: u64 = 10_000;
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
@@ -137,7 +229,40 @@ pub struct FeeSplitState {
     pub acc_b_num: u128,
     pub fee_escrow_u: u128,
     pub total_burned_u: u128,
+    /// Cumulative escrow releases to the Verifier Pool / Treasury (burns are `total_burned_u`).
+    pub total_verifier_u: u128,
+    pub total_treasury_u: u128,
     pub nlb: NlbEpochState,
+    /// Trimming applied by every routing path when releases exceed `fee_escrow_u`.
+    pub deficit_policy: DeficitPolicy,
+}
+
+impl FeeSplitState {
+    /// Fee conservation: every fee seen is either still in escrow or was released
+    /// (`fee_escrow_u + verifier + treasury + burned == total_fees_seen`).
+    #[must_use]
+    pub fn invariant_ok(&self, total_fees_seen: u128) -> bool {
+        self.fee_escrow_u
+            .checked_add(self.total_verifier_u)
+            .and_then(|x| x.checked_add(self.total_treasury_u))
+            .and_then(|x| x.checked_add(self.total_burned_u))
+            == Some(total_fees_seen)
+    }
+
+    /// Sub-unit fee-split remainders `(verifier, treasury, burn)` not yet released, in units
+    /// of `1/10_000` of a base unit.
+    ///
+    /// While the escrow covers every release, `released * 10_000 + dust` equals the exact
+    /// `sum(fee * pct / 100)` (scaled by `10_000`) for each bucket, so no fractional value is
+    /// created or lost.
+    #[must_use]
+    pub const fn pending_dust(&self) -> (u128, u128, u128) {
+        (
+            self.acc_v_num % DEN_10K,
+            self.acc_t_num % DEN_10K,
+            self.acc_b_num % DEN_10K,
+        )
+    }
 }
 
 const TH_500K_OBX: u128 = 500_000 * UOBX_PER_OBX;
@@ -180,11 +305,30 @@ const fn epoch_index(slot: u64) -> u64 {
     slot / NLB_EPOCH_SLOTS
 }
 
+/// Roll `fs.nlb` to the epoch containing `slot`; a no-op within the current epoch.
+///
+/// Jumps straight to the target epoch: the snapshot depends only on `total_burned_u` now, so
+/// skipped epochs need no replay. Slots must not go backward (debug-asserted).
 pub fn nlb_roll_epoch_if_needed(slot: u64, fs: &mut FeeSplitState) {
+    nlb_roll_epochs_through(slot, fs);
+}
+
+/// Explicit multi-epoch catch-up (e.g. after fast sync): roll `fs.nlb` directly to the epoch
+/// of `slot` and return how many epoch boundaries were crossed (0 if already there).
+///
+/// Intermediate epochs get no snapshot of their own; the new split is computed once from the
+/// current effective supply, exactly as a single-step roll would.
+pub fn nlb_roll_epochs_through(slot: u64, fs: &mut FeeSplitState) -> u64 {
     let idx = epoch_index(slot);
-    if idx == fs.nlb.epoch_index {
-        return;
+    debug_assert!(
+        idx >= fs.nlb.epoch_index,
+        "NLB epoch must not go backward ({idx} < {})",
+        fs.nlb.epoch_index
+    );
+    if idx <= fs.nlb.epoch_index {
+        return 0;
     }
+    let crossed = idx - fs.nlb.epoch_index;
     fs.nlb.epoch_index = idx;
     fs.nlb.start_slot = idx * NLB_EPOCH_SLOTS;
     let eff_u = TOTAL_SUPPLY_UOBX.saturating_sub(fs.total_burned_u);
@@ -193,17 +337,79 @@ pub fn nlb_roll_epoch_if_needed(slot: u64, fs: &mut FeeSplitState) {
     fs.nlb.v_pct = v;
     fs.nlb.t_pct = t;
     fs.nlb.b_pct = b;
+    crossed
 }
 
 const DEN_10K: u128 = 10_000; // Constants before statements per clippy
 
+/// How fee releases are trimmed when they exceed `fee_escrow_u`; carried in
+/// `FeeSplitState::deficit_policy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeficitPolicy {
+    /// Cut the burn release first, then treasury, then verifier, each down to zero before
+    /// moving on.
+    #[default]
+    PriorityBurnLast,
+    /// Cut each release by `floor(deficit · rel / total_rel)`; the at most two units left over
+    /// by rounding are cut in `PriorityBurnLast` order.
+    Proportional,
+}
+
+impl DeficitPolicy {
+    /// Trim `(rel_v, rel_t, rel_b)` by exactly `deficit <= rel_v + rel_t + rel_b`.
+    fn reduce(self, rel_v: &mut u128, rel_t: &mut u128, rel_b: &mut u128, mut deficit: u128) {
+        let reduce = |x: &mut u128, d: &mut u128| {
+            let cut = (*x).min(*d);
+            *x -= cut;
+            *d -= cut;
+        };
+        if self == Self::Proportional {
+            let total = U256::from(*rel_v) + U256::from(*rel_t) + U256::from(*rel_b);
+            let share = |x: u128| (U256::from(deficit) * U256::from(x) / total).as_u128();
+            let (cut_v, cut_t, cut_b) = (share(*rel_v), share(*rel_t), share(*rel_b));
+            *rel_v -= cut_v;
+            *rel_t -= cut_t;
+            *rel_b -= cut_b;
+            deficit -= cut_v + cut_t + cut_b;
+        }
+        reduce(rel_b, &mut deficit);
+        reduce(rel_t, &mut deficit);
+        reduce(rel_v, &mut deficit);
+    }
+}
+
+/// Accumulate `fee_num / fee_den` into the NLB splits and release whole units from escrow,
+/// trimming any shortfall by `fs.deficit_policy`.
 pub fn route_fee_with_nlb(
     fs: &mut FeeSplitState,
     fee_num: u128,
     fee_den: u128,
-    mut credit_verifier: impl FnMut(u128),
-    mut credit_treasury: impl FnMut(u128),
-    mut burn: impl FnMut(u128),
+    credit_verifier: impl FnMut(u128),
+    credit_treasury: impl FnMut(u128),
+    burn: impl FnMut(u128),
+) {
+    let policy = fs.deficit_policy;
+    route_fee_with_nlb_with_policy(
+        fs,
+        fee_num,
+        fee_den,
+        policy,
+        credit_verifier,
+        credit_treasury,
+        burn,
+    );
+}
+
+/// As `route_fee_with_nlb`, trimming any release beyond `fee_escrow_u` according to `policy`
+/// instead of `fs.deficit_policy`.
+pub fn route_fee_with_nlb_with_policy(
+    fs: &mut FeeSplitState,
+    fee_num: u128,
+    fee_den: u128,
+    policy: DeficitPolicy,
+    credit_verifier: impl FnMut(u128),
+    credit_treasury: impl FnMut(u128),
+    burn: impl FnMut(u128),
 ) {
     let fee_num_over_100 = if fee_den == 1 {
         fee_num.saturating_mul(100)
@@ -216,33 +422,85 @@ pub fn route_fee_with_nlb(
     fs.acc_v_num = fs.acc_v_num.saturating_add(add_v);
     fs.acc_t_num = fs.acc_t_num.saturating_add(add_t);
     fs.acc_b_num = fs.acc_b_num.saturating_add(add_b);
+    release_fee_accumulators(fs, policy, credit_verifier, credit_treasury, burn);
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum FeeError {
+    #[error("amount below minimum transfer")]
+    BelowMinimum,
+    #[error("fee numerator overflow")]
+    NumeratorOverflow,
+    #[error("fee accumulator overflow")]
+    AccumulatorOverflow,
+}
+
+/// Checked variant of `route_fee_with_nlb`: identical results whenever no intermediate
+/// exceeds `u128`, otherwise returns an error and leaves `fs` untouched (no callbacks fire).
+pub fn route_fee_with_nlb_checked(
+    fs: &mut FeeSplitState,
+    fee_num: u128,
+    fee_den: u128,
+    credit_verifier: impl FnMut(u128),
+    credit_treasury: impl FnMut(u128),
+    burn: impl FnMut(u128),
+) -> Result<(), FeeError> {
+    let fee_num_over_100 = if fee_den == 1 {
+        fee_num
+            .checked_mul(100)
+            .ok_or(FeeError::NumeratorOverflow)?
+    } else {
+        fee_num
+    };
+    let accumulate = |acc: u128, pct: u8| {
+        fee_num_over_100
+            .checked_mul(u128::from(pct))
+            .and_then(|add| acc.checked_add(add))
+            .ok_or(FeeError::AccumulatorOverflow)
+    };
+    let acc_v = accumulate(fs.acc_v_num, fs.nlb.v_pct)?;
+    let acc_t = accumulate(fs.acc_t_num, fs.nlb.t_pct)?;
+    let acc_b = accumulate(fs.acc_b_num, fs.nlb.b_pct)?;
+    fs.acc_v_num = acc_v;
+    fs.acc_t_num = acc_t;
+    fs.acc_b_num = acc_b;
+    let policy = fs.deficit_policy;
+    release_fee_accumulators(fs, policy, credit_verifier, credit_treasury, burn);
+    Ok(())
+}
 
+fn release_fee_accumulators(
+    fs: &mut FeeSplitState,
+    policy: DeficitPolicy,
+    mut credit_verifier: impl FnMut(u128),
+    mut credit_treasury: impl FnMut(u128),
+    mut burn: impl FnMut(u128),
+) {
     let mut rel_v = fs.acc_v_num / DEN_10K;
     let mut rel_t = fs.acc_t_num / DEN_10K;
     let mut rel_b = fs.acc_b_num / DEN_10K;
 
     let total_rel = rel_v.saturating_add(rel_t).saturating_add(rel_b);
     if total_rel > fs.fee_escrow_u {
-        let mut deficit = total_rel - fs.fee_escrow_u;
-        let reduce = |x: &mut u128, d: &mut u128| {
-            let cut = (*x).min(*d);
-            *x -= cut;
-            *d -= cut;
-        };
-        reduce(&mut rel_b, &mut deficit);
-        reduce(&mut rel_t, &mut deficit);
-        reduce(&mut rel_v, &mut deficit);
+        policy.reduce(
+            &mut rel_v,
+            &mut rel_t,
+            &mut rel_b,
+            total_rel - fs.fee_escrow_u,
+        );
     }
 
     if rel_v > 0 {
         credit_verifier(rel_v);
         fs.fee_escrow_u -= rel_v;
         fs.acc_v_num %= DEN_10K;
+        fs.total_verifier_u = fs.total_verifier_u.saturating_add(rel_v);
     }
     if rel_t > 0 {
         credit_treasury(rel_t);
         fs.fee_escrow_u -= rel_t;
         fs.acc_t_num %= DEN_10K;
+        fs.total_treasury_u = fs.total_treasury_u.saturating_add(rel_t);
     }
     if rel_b > 0 {
         burn(rel_b);
@@ -252,6 +510,12 @@ pub fn route_fee_with_nlb(
     }
 }
 
+/// Settle one user transfer: debit `amount + fee`, credit the recipient, escrow the fee and
+/// route it through the NLB splits.
+///
+/// Preconditions (asserted): `amount_μ ≥ MIN_TRANSFER_U` and `sender_balance_μ ≥ amount_μ + fee`.
+/// Admission (α-III) guarantees both on consensus paths; use `try_process_transfer` for
+/// unvalidated input.
 #[allow(clippy::too_many_arguments)]
 pub fn process_transfer(
     slot: u64,
@@ -283,6 +547,305 @@ pub fn process_transfer(
     (total_debit, fee_μ)
 }
 
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum TransferError {
+    #[error("fee: {0}")]
+    Fee(#[from] FeeError),
+    #[error("amount + fee overflows u128")]
+    DebitOverflow,
+    #[error("insufficient balance: need {need}, have {have}")]
+    InsufficientBalance { need: u128, have: u128 },
+    #[error("ledger: {0}")]
+    Ledger(#[from] LedgerError),
+}
+
+/// Checked `process_transfer` for unvalidated input.
+///
+/// Every precondition and overflow is reported as an error before anything happens; on error
+/// `fs` is unchanged and no callback fires. On success the effects and callback order are
+/// exactly those of `process_transfer`.
+#[allow(clippy::too_many_arguments)]
+pub fn try_process_transfer(
+    slot: u64,
+    sender_balance_μ: u128,
+    amount_μ: u128,
+    fs: &mut FeeSplitState,
+    mut debit_sender: impl FnMut(u128),
+    mut credit_recipient: impl FnMut(u128),
+    mut escrow_credit: impl FnMut(u128),
+    mut credit_verifier: impl FnMut(u128),
+    mut credit_treasury: impl FnMut(u128),
+    mut burn: impl FnMut(u128),
+) -> Result<(u128, u128), TransferError> {
+    let fee_μ = try_fee_int(amount_μ)?;
+    let total_debit = amount_μ
+        .checked_add(fee_μ)
+        .ok_or(TransferError::DebitOverflow)?;
+    if sender_balance_μ < total_debit {
+        return Err(TransferError::InsufficientBalance {
+            need: total_debit,
+            have: sender_balance_μ,
+        });
+    }
+    let (fee_num, fee_den) = if amount_μ <= FLAT_SWITCH_U {
+        (FLAT_FEE_U, 1)
+    } else {
+        (amount_μ, 100)
+    };
+    // Stage on a copy so a routing overflow leaves `fs` untouched.
+    let mut next = fs.clone();
+    nlb_roll_epoch_if_needed(slot, &mut next);
+    next.fee_escrow_u = next
+        .fee_escrow_u
+        .checked_add(fee_μ)
+        .ok_or(FeeError::AccumulatorOverflow)?;
+    let (mut rel_v, mut rel_t, mut rel_b) = (0u128, 0u128, 0u128);
+    route_fee_with_nlb_checked(
+        &mut next,
+        fee_num,
+        fee_den,
+        |v| rel_v += v,
+        |t| rel_t += t,
+        |b| rel_b += b,
+    )?;
+    debit_sender(total_debit);
+    credit_recipient(amount_μ);
+    escrow_credit(fee_μ);
+    if rel_v > 0 {
+        credit_verifier(rel_v);
+    }
+    if rel_t > 0 {
+        credit_treasury(rel_t);
+    }
+    if rel_b > 0 {
+        burn(rel_b);
+    }
+    *fs = next;
+    Ok((total_debit, fee_μ))
+}
+
+/// What `process_transfer` would do for a transfer, computed without touching state.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TransferPreview {
+    pub total_debit_u: u128,
+    pub fee_u: u128,
+    /// Releases out of the fee escrow, including any carried sub-unit remainder that this
+    /// transfer tips over a whole unit.
+    pub rel_v: u128,
+    pub rel_t: u128,
+    pub rel_b: u128,
+}
+
+/// Read-only half of `process_transfer`; `fs` is not modified.
+///
+/// Returns the debit, fee and `(v, t, b)` releases the same transfer would produce against `fs`
+/// at `slot`, including an NLB epoch roll if `slot` starts a new epoch. No balance check is made.
+///
+/// Panics like `process_transfer` if `amount_μ < MIN_TRANSFER_U`.
+#[must_use]
+pub fn preview_transfer(slot: u64, amount_μ: u128, fs: &FeeSplitState) -> TransferPreview {
+    let mut next = fs.clone();
+    let (mut rel_v, mut rel_t, mut rel_b) = (0u128, 0u128, 0u128);
+    let (total_debit_u, fee_u) = process_transfer(
+        slot,
+        u128::MAX,
+        amount_μ,
+        &mut next,
+        |_| {},
+        |_| {},
+        |_| {},
+        |v| rel_v += v,
+        |t| rel_t += t,
+        |b| rel_b += b,
+    );
+    TransferPreview {
+        total_debit_u,
+        fee_u,
+        rel_v,
+        rel_t,
+        rel_b,
+    }
+}
+
+/// One fee release: amounts paid out of `fee_escrow_u` by a single transfer at `slot`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeSplitEntry {
+    pub slot: u64,
+    pub rel_v: u128,
+    pub rel_t: u128,
+    pub rel_b: u128,
+}
+
+impl FeeSplitEntry {
+    #[must_use]
+    pub const fn total(&self) -> u128 {
+        self.rel_v + self.rel_t + self.rel_b
+    }
+}
+
+/// Opt-in audit log of fee releases, filled by `process_transfer_logged` (debugging and
+/// escrow reconciliation only; `process_transfer` keeps no record).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FeeSplitLog {
+    pub entries: Vec<FeeSplitEntry>,
+}
+
+impl FeeSplitLog {
+    /// Sum of all logged releases (verifier + treasury + burn).
+    #[must_use]
+    pub fn total_released(&self) -> u128 {
+        self.entries.iter().map(FeeSplitEntry::total).sum()
+    }
+}
+
+/// `process_transfer` that also appends the releases of this transfer to `log`.
+#[allow(clippy::too_many_arguments)]
+pub fn process_transfer_logged(
+    slot: u64,
+    sender_balance_μ: u128,
+    amount_μ: u128,
+    fs: &mut FeeSplitState,
+    log: &mut FeeSplitLog,
+    debit_sender: impl FnMut(u128),
+    credit_recipient: impl FnMut(u128),
+    escrow_credit: impl FnMut(u128),
+    mut credit_verifier: impl FnMut(u128),
+    mut credit_treasury: impl FnMut(u128),
+    mut burn: impl FnMut(u128),
+) -> (u128, u128) {
+    let mut entry = FeeSplitEntry {
+        slot,
+        ..FeeSplitEntry::default()
+    };
+    let out = process_transfer(
+        slot,
+        sender_balance_μ,
+        amount_μ,
+        fs,
+        debit_sender,
+        credit_recipient,
+        escrow_credit,
+        |v| {
+            entry.rel_v += v;
+            credit_verifier(v);
+        },
+        |t| {
+            entry.rel_t += t;
+            credit_treasury(t);
+        },
+        |b| {
+            entry.rel_b += b;
+            burn(b);
+        },
+    );
+    log.entries.push(entry);
+    out
+}
+
+// ——— Balance ledger ————————————————————————————————————————————————
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerError {
+    #[error("insufficient balance: need {need}, have {have}")]
+    Underflow { need: u128, have: u128 },
+    #[error("balance overflows u128")]
+    Overflow,
+}
+
+/// Account balances (base units) keyed by public key, for driving the settlement callbacks.
+///
+/// `debit`/`credit` are checked and leave the ledger unchanged on error. `transfer` runs a user
+/// transfer against the sender's balance; `credit_all` applies the payouts collected from
+/// `distribute_drp_for_slot`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    balances: std::collections::BTreeMap<Hash256, u128>,
+}
+
+impl Ledger {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Balance of `pk` (0 if unknown).
+    #[must_use]
+    pub fn balance(&self, pk: &Hash256) -> u128 {
+        self.balances.get(pk).copied().unwrap_or(0)
+    }
+
+    pub fn credit(&mut self, pk: &Hash256, amt: u128) -> Result<(), LedgerError> {
+        let next = self
+            .balance(pk)
+            .checked_add(amt)
+            .ok_or(LedgerError::Overflow)?;
+        self.balances.insert(*pk, next);
+        Ok(())
+    }
+
+    pub fn debit(&mut self, pk: &Hash256, amt: u128) -> Result<(), LedgerError> {
+        let have = self.balance(pk);
+        let next = have
+            .checked_sub(amt)
+            .ok_or(LedgerError::Underflow { need: amt, have })?;
+        self.balances.insert(*pk, next);
+        Ok(())
+    }
+
+    /// `try_process_transfer` of `amount_u` from `from` to `to`, checked against `from`'s
+    /// balance and `to`'s headroom. Returns `(total_debit, fee)`; on error neither the ledger
+    /// nor `fs` changes and no callback fires.
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer(
+        &mut self,
+        slot: u64,
+        from: &Hash256,
+        to: &Hash256,
+        amount_u: u128,
+        fs: &mut FeeSplitState,
+        credit_verifier: impl FnMut(u128),
+        credit_treasury: impl FnMut(u128),
+        burn: impl FnMut(u128),
+    ) -> Result<(u128, u128), TransferError> {
+        // A self-transfer only shrinks the balance; otherwise the credit must fit before any
+        // state moves.
+        if from != to {
+            self.balance(to)
+                .checked_add(amount_u)
+                .ok_or(LedgerError::Overflow)?;
+        }
+        let (mut debited, mut credited) = (0u128, 0u128);
+        let out = try_process_transfer(
+            slot,
+            self.balance(from),
+            amount_u,
+            fs,
+            |d| debited = d,
+            |c| credited = c,
+            |_| {},
+            credit_verifier,
+            credit_treasury,
+            burn,
+        )?;
+        self.debit(from, debited)
+            .expect("debit checked by try_process_transfer");
+        self.credit(to, credited).expect("credit checked above");
+        Ok(out)
+    }
+
+    /// Credit every `(pk, amt)` in `credits`, e.g. the payouts of `distribute_drp_for_slot`.
+    /// All or nothing: on overflow the ledger is unchanged.
+    pub fn credit_all(&mut self, credits: &[(Hash256, u128)]) -> Result<(), LedgerError> {
+        let mut staged = std::collections::BTreeMap::new();
+        for (pk, amt) in credits {
+            let have = staged.get(pk).copied().unwrap_or_else(|| self.balance(pk));
+            staged.insert(*pk, have.checked_add(*amt).ok_or(LedgerError::Overflow)?);
+        }
+        self.balances.extend(staged);
+        Ok(())
+    }
+}
+
 #[inline]
 fn ctr_draw(y: &Hash256, s: u64, t: u32) -> Hash256 {
     consensus::h_tag(
@@ -295,9 +858,52 @@ fn ctr_draw(y: &Hash256, s: u64, t: u32) -> Hash256 {
     )
 }
 
+/// Deterministic randomness stream for one slot, seeded by its beacon edge.
+///
+/// Draw `t` (counting from 0) is `H("obex.reward.draw", [y_edge_s, LE(slot,8), LE(t,4)])`; the
+/// counter wraps at `u32::MAX`. Consensus features needing per-slot randomness draw from here
+/// so they share this one derivation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlotRng {
+    y_edge_s: Hash256,
+    slot: u64,
+    t: u32,
+}
+
+impl SlotRng {
+    #[must_use]
+    pub const fn new(y_edge_s: &Hash256, slot: u64) -> Self {
+        Self {
+            y_edge_s: *y_edge_s,
+            slot,
+            t: 0,
+        }
+    }
+
+    /// Index of the next draw.
+    #[must_use]
+    pub const fn counter(&self) -> u32 {
+        self.t
+    }
+
+    /// Next 32-byte draw.
+    pub fn next_hash(&mut self) -> Hash256 {
+        let h = ctr_draw(&self.y_edge_s, self.slot, self.t);
+        self.t = self.t.wrapping_add(1);
+        h
+    }
+
+    /// Next draw reduced to its first 8 bytes, little-endian.
+    pub fn next_u64(&mut self) -> u64 {
+        u64_from_le(&self.next_hash()[..8])
+    }
+}
+
 // Items before statements per clippy
 use std::collections::BTreeSet;
 
+/// `winners_k` distinct indices into a set of `set_len`, in draw order: each `SlotRng` draw
+/// taken mod `set_len`, repeats skipped. Requires `winners_k <= set_len` to terminate.
 #[must_use]
 pub fn pick_k_unique_indices(
     y_edge_s: &Hash256,
@@ -310,14 +916,12 @@ pub fn pick_k_unique_indices(
     }
     let mut out = Vec::with_capacity(winners_k);
     let mut seen = BTreeSet::new();
-    let mut t: u32 = 0;
+    let mut rng = SlotRng::new(y_edge_s, slot);
     while out.len() < winners_k {
-        let h = ctr_draw(y_edge_s, slot, t);
-        let idx = usize::try_from(u64_from_le(&h[..8]) % (set_len as u64)).unwrap_or(usize::MAX);
+        let idx = usize::try_from(rng.next_u64() % (set_len as u64)).unwrap_or(usize::MAX);
         if seen.insert(idx) {
             out.push(idx);
         }
-        t = t.wrapping_add(1);
     }
     out
 }
@@ -327,6 +931,29 @@ fn reward_rank(y: &Hash256, pk: &Hash256) -> Hash256 {
     consensus::h_tag("obex.reward.rank", &[y, pk])
 }
 
+/// Payout order key `(rank, pk)`: a total order on distinct pks even if two ranks collide.
+#[inline]
+const fn rank_then_pk(rank: Hash256, pk: &Hash256) -> (Hash256, Hash256) {
+    (rank, *pk)
+}
+
+/// Canonical payout order of DRP winners: ascending `(reward_rank(y_edge_s, pk), pk)`,
+/// independent of the order `winners` is given in. This is the `REWARD_PAYOUT` order of
+/// `canonical_sys_tx_order`.
+#[must_use]
+pub fn drp_winner_order(y_edge_s: &Hash256, winners: &[Hash256]) -> Vec<Hash256> {
+    drp_winner_order_by(winners, |pk| reward_rank(y_edge_s, pk))
+}
+
+fn drp_winner_order_by(winners: &[Hash256], rank: impl Fn(&Hash256) -> Hash256) -> Vec<Hash256> {
+    let mut ranked: Vec<(Hash256, Hash256)> = winners
+        .iter()
+        .map(|pk| rank_then_pk(rank(pk), pk))
+        .collect();
+    ranked.sort_unstable();
+    ranked.into_iter().map(|(_rank, pk)| pk).collect()
+}
+
 pub const DRP_BASELINE_PCT: u8 = 20;
 pub const DRP_K_WINNERS: usize = 16;
 
@@ -335,58 +962,180 @@ pub fn distribute_drp_for_slot(
     s: u64,
     y_edge_s: &Hash256,
     part_set_sorted: &[Hash256],
-    mut read_pool_balance: impl FnMut() -> u128,
-    mut debit_pool: impl FnMut(u128),
-    mut credit_pk: impl FnMut(&Hash256, u128),
-    mut burn_fn: impl FnMut(u128),
+    read_pool_balance: impl FnMut() -> u128,
+    debit_pool: impl FnMut(u128),
+    credit_pk: impl FnMut(&Hash256, u128),
+    burn_fn: impl FnMut(u128),
 ) {
+    distribute_drp_for_slot_k(
+        s,
+        y_edge_s,
+        part_set_sorted,
+        DRP_K_WINNERS,
+        read_pool_balance,
+        debit_pool,
+        credit_pk,
+        burn_fn,
+    );
+}
+
+/// One lottery payout of a `DrpPlan`: index into `part_set_sorted` and amount.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DrpWinner {
+    pub index: usize,
+    pub amount: u128,
+}
+
+/// Side-effect-free description of the DRP distribution for one slot.
+///
+/// `winners` are unique and in payout order (ascending `(reward_rank, pk)`); the list is empty
+/// when the lottery share rounds to zero per winner. An all-zero plan means nothing moves.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DrpPlan {
+    pub per_base: u128,
+    pub base_rem: u128,
+    pub winners: Vec<DrpWinner>,
+    pub lot_rem: u128,
+    /// Debited from the pool: `per_base · m + Σ winners.amount`.
+    pub total_pay: u128,
+}
+
+impl DrpPlan {
+    /// Rounding residue burned by the distribution (`base_rem + lot_rem`).
+    #[must_use]
+    pub const fn burned(&self) -> u128 {
+        self.base_rem + self.lot_rem
+    }
+}
+
+/// `DrpPlan` for slot `s` with the default lottery width `DRP_K_WINNERS`.
+#[must_use]
+pub fn compute_drp_plan(
+    s: u64,
+    y_edge_s: &Hash256,
+    part_set_sorted: &[Hash256],
+    pool_balance: u128,
+) -> DrpPlan {
+    compute_drp_plan_k(s, y_edge_s, part_set_sorted, DRP_K_WINNERS, pool_balance)
+}
+
+/// `DrpPlan` with an explicit lottery width (clamped to `min(k_winners, m)`).
+#[must_use]
+pub fn compute_drp_plan_k(
+    s: u64,
+    y_edge_s: &Hash256,
+    part_set_sorted: &[Hash256],
+    k_winners: usize,
+    pool_balance: u128,
+) -> DrpPlan {
     let m = part_set_sorted.len();
-    let drp = read_pool_balance();
+    let drp = pool_balance;
     if drp == 0 || m == 0 {
-        return;
+        return DrpPlan::default();
     }
     let baseline = (drp * u128::from(DRP_BASELINE_PCT)) / 100;
     let lottery = drp - baseline;
     let per_base = baseline / (m as u128);
     let base_rem = baseline % (m as u128);
-    let k = core::cmp::min(DRP_K_WINNERS, m);
-    if k == 0 {
-        return;
-    }
+    let k = core::cmp::min(k_winners, m);
     let winners_idx = pick_k_unique_indices(y_edge_s, s, m, k);
-    let per_win = lottery / (k as u128);
-    let lot_rem = lottery % (k as u128);
+    let (per_win, lot_rem) = if k == 0 {
+        (0, 0)
+    } else {
+        (lottery / (k as u128), lottery % (k as u128))
+    };
     if per_base == 0 && per_win == 0 {
-        return;
+        return DrpPlan::default();
     }
     let total_pay = per_base * (m as u128) + per_win * (k as u128);
-    debit_pool(total_pay);
-    if per_base > 0 {
+    let winners = if per_win == 0 {
+        Vec::new()
+    } else {
+        let mut ranked: Vec<(usize, (Hash256, Hash256))> = winners_idx
+            .iter()
+            .map(|&i| {
+                let pk = &part_set_sorted[i];
+                (i, rank_then_pk(reward_rank(y_edge_s, pk), pk))
+            })
+            .collect();
+        ranked.sort_by_key(|a| a.1);
+        ranked
+            .into_iter()
+            .map(|(index, _rank)| DrpWinner {
+                index,
+                amount: per_win,
+            })
+            .collect()
+    };
+    DrpPlan {
+        per_base,
+        base_rem,
+        winners,
+        lot_rem,
+        total_pay,
+    }
+}
+
+/// Fate of the DRP pool in a slot whose participation set `P_s` is empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DrpEmptyAction {
+    /// Nothing is paid or burned; the whole balance carries into the next slot's pool.
+    Rollover(u128),
+}
+
+/// Consensus rule for an empty `P_s`: the pool rolls over untouched (no sys txs are emitted).
+///
+/// Burning instead would destroy emission whenever a slot goes unattended; rolling over keeps
+/// it for the next slot that has participants.
+#[must_use]
+pub const fn drp_on_empty_participation(pool_balance: u128) -> DrpEmptyAction {
+    DrpEmptyAction::Rollover(pool_balance)
+}
+
+/// As `distribute_drp_for_slot` with an explicit lottery width (clamped to `min(k_winners, m)`).
+/// With `k_winners == 0` only the baseline is paid; the lottery share stays in the pool.
+#[allow(clippy::too_many_arguments)]
+pub fn distribute_drp_for_slot_k(
+    s: u64,
+    y_edge_s: &Hash256,
+    part_set_sorted: &[Hash256],
+    k_winners: usize,
+    mut read_pool_balance: impl FnMut() -> u128,
+    mut debit_pool: impl FnMut(u128),
+    mut credit_pk: impl FnMut(&Hash256, u128),
+    mut burn_fn: impl FnMut(u128),
+) {
+    let pool_balance = read_pool_balance();
+    if part_set_sorted.is_empty() {
+        // Exhaustive on purpose: a new action must be wired in here.
+        let DrpEmptyAction::Rollover(_) = drp_on_empty_participation(pool_balance);
+        return;
+    }
+    let plan = compute_drp_plan_k(s, y_edge_s, part_set_sorted, k_winners, pool_balance);
+    if plan.total_pay == 0 {
+        return;
+    }
+    debit_pool(plan.total_pay);
+    if plan.per_base > 0 {
         for pk in part_set_sorted {
-            credit_pk(pk, per_base);
+            credit_pk(pk, plan.per_base);
         }
     }
-    if base_rem > 0 {
-        burn_fn(base_rem);
+    if plan.base_rem > 0 {
+        burn_fn(plan.base_rem);
     }
-    if per_win > 0 {
-        let mut winners: Vec<(usize, Hash256)> = winners_idx
-            .iter()
-            .map(|&i| (i, reward_rank(y_edge_s, &part_set_sorted[i])))
-            .collect();
-        winners.sort_by(|a, b| a.1.cmp(&b.1));
-        for (idx, _rank) in winners {
-            credit_pk(&part_set_sorted[idx], per_win);
-        }
+    for w in &plan.winners {
+        credit_pk(&part_set_sorted[w.index], w.amount);
     }
-    if lot_rem > 0 {
-        burn_fn(lot_rem);
+    if plan.lot_rem > 0 {
+        burn_fn(plan.lot_rem);
     }
 }
 
 // ——— System transaction (consensus wire) ——————————————————————————
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SysTxKind {
     EscrowCredit = 0,
     VerifierCredit = 1,
@@ -396,20 +1145,81 @@ pub enum SysTxKind {
     EmissionCredit = 5,
 }
 
+/// System transaction.
+///
+/// With the `serde` feature, `SysTx` also derives `Serialize`/`Deserialize` for audit logs
+/// (`pk` as hex and `amt` as a decimal string in human-readable formats). Serde output is
+/// non-consensus: `enc_sys_tx` / `dec_sys_tx` remain the canonical wire format.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SysTx {
     pub kind: SysTxKind,
     pub slot: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_hex32"))]
     pub pk: Hash256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_u128_str"))]
     pub amt: u128,
 }
 
+/// Serde adapter for 32-byte fields: hex string in human-readable formats, raw array otherwise.
+#[cfg(feature = "serde")]
+mod serde_hex32 {
+    use obex_primitives::Hash256;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(h: &Hash256, ser: S) -> Result<S::Ok, S::Error> {
+        if ser.is_human_readable() {
+            ser.serialize_str(&hex::encode(h))
+        } else {
+            h.serialize(ser)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<Hash256, D::Error> {
+        if de.is_human_readable() {
+            let s = String::deserialize(de)?;
+            let mut out = [0u8; 32];
+            hex::decode_to_slice(s, &mut out).map_err(de::Error::custom)?;
+            Ok(out)
+        } else {
+            Hash256::deserialize(de)
+        }
+    }
+}
+
+/// Serde adapter for `u128` amounts: decimal string in human-readable formats (JSON numbers
+/// are not 128-bit safe across tooling), native integer otherwise.
+#[cfg(feature = "serde")]
+mod serde_u128_str {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(v: &u128, ser: S) -> Result<S::Ok, S::Error> {
+        if ser.is_human_readable() {
+            ser.collect_str(v)
+        } else {
+            ser.serialize_u128(*v)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<u128, D::Error> {
+        if de.is_human_readable() {
+            String::deserialize(de)?.parse().map_err(de::Error::custom)
+        } else {
+            u128::deserialize(de)
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum SysTxCodecError {
     #[error("short")]
     Short,
     #[error("trailing")]
     Trailing,
+    #[error("unknown sys tx kind")]
+    BadKind,
+    #[error("bad sys tx domain tag")]
+    BadTag,
 }
 
 const fn read_exact<'a>(src: &mut &'a [u8], n: usize) -> Result<&'a [u8], SysTxCodecError> {
@@ -433,29 +1243,40 @@ pub fn enc_sys_tx(tx: &SysTx) -> Vec<u8> {
 }
 
 pub fn dec_sys_tx(mut src: &[u8]) -> Result<SysTx, SysTxCodecError> {
-    let _tag = read_exact(&mut src, 32)?; // domain tag bytes
+    let tx = read_sys_tx(&mut src)?;
+    if !src.is_empty() {
+        return Err(SysTxCodecError::Trailing);
+    }
+    Ok(tx)
+}
+
+/// Encoded size of one `SysTx`: tag(32) || kind(1) || LE(slot,8) || pk(32) || LE(amt,16).
+const SYS_TX_LEN: usize = 32 + 1 + 8 + 32 + 16;
+
+fn read_sys_tx(src: &mut &[u8]) -> Result<SysTx, SysTxCodecError> {
+    if read_exact(src, 32)? != consensus::h_tag("obex.sys.tx", &[]) {
+        return Err(SysTxCodecError::BadTag);
+    }
     let kind = {
-        let b = read_exact(&mut src, 1)?[0];
+        let b = read_exact(src, 1)?[0];
         match b {
             0 => SysTxKind::EscrowCredit,
             1 => SysTxKind::VerifierCredit,
             2 => SysTxKind::TreasuryCredit,
+            3 => SysTxKind::Burn,
             4 => SysTxKind::RewardPayout,
             5 => SysTxKind::EmissionCredit,
-            _ => SysTxKind::Burn,
+            _ => return Err(SysTxCodecError::BadKind),
         }
     };
-    let slot = u64::from_le_bytes(read_exact(&mut src, 8)?.try_into().unwrap());
+    let slot = u64::from_le_bytes(read_exact(src, 8)?.try_into().unwrap());
     let pk = {
-        let b = read_exact(&mut src, 32)?;
+        let b = read_exact(src, 32)?;
         let mut a = [0u8; 32];
         a.copy_from_slice(b);
         a
     };
-    let amt = u128::from_le_bytes(read_exact(&mut src, 16)?.try_into().unwrap());
-    if !src.is_empty() {
-        return Err(SysTxCodecError::Trailing);
-    }
+    let amt = u128::from_le_bytes(read_exact(src, 16)?.try_into().unwrap());
     Ok(SysTx {
         kind,
         slot,
@@ -464,35 +1285,381 @@ pub fn dec_sys_tx(mut src: &[u8]) -> Result<SysTx, SysTxCodecError> {
     })
 }
 
+/// Encode a slot's system-transaction list as one blob: `LE(count,4) || enc_sys_tx(tx)*`.
+///
+/// Entries are written in the given order; pass the output of `canonical_sys_tx_order`.
+#[must_use]
+pub fn enc_sys_tx_block(txs: &[SysTx]) -> Vec<u8> {
+    let count = u32::try_from(txs.len()).expect("sys tx block exceeds u32::MAX entries");
+    let mut out = Vec::with_capacity(4 + txs.len() * SYS_TX_LEN);
+    out.extend_from_slice(&le_bytes::<4>(u128::from(count)));
+    for tx in txs {
+        out.extend_from_slice(&enc_sys_tx(tx));
+    }
+    out
+}
+
+/// Inverse of `enc_sys_tx_block`; rejects short input, unknown kinds and trailing bytes.
+pub fn dec_sys_tx_block(mut src: &[u8]) -> Result<Vec<SysTx>, SysTxCodecError> {
+    let count = u32::from_le_bytes(read_exact(&mut src, 4)?.try_into().unwrap()) as usize;
+    // Bound the allocation by the bytes actually present, not the claimed count.
+    let mut txs = Vec::with_capacity(count.min(src.len() / SYS_TX_LEN));
+    for _ in 0..count {
+        txs.push(read_sys_tx(&mut src)?);
+    }
+    if !src.is_empty() {
+        return Err(SysTxCodecError::Trailing);
+    }
+    Ok(txs)
+}
+
+/// Sort key of `tx` in `canonical_sys_tx_order`: kind priority, then `(reward_rank, pk)` for
+/// `REWARD_PAYOUT` (all-zero for other kinds, which keep their relative order).
+#[must_use]
+pub fn sys_tx_order_key(tx: &SysTx, y_edge_s: &Hash256) -> (u8, (Hash256, Hash256)) {
+    sys_tx_order_key_by(tx, |pk| reward_rank(y_edge_s, pk))
+}
+
+fn sys_tx_order_key_by(tx: &SysTx, rank: impl Fn(&Hash256) -> Hash256) -> (u8, (Hash256, Hash256)) {
+    const NONE: (Hash256, Hash256) = ([0u8; 32], [0u8; 32]);
+    match tx.kind {
+        SysTxKind::EscrowCredit => (0, NONE),
+        SysTxKind::EmissionCredit => (1, NONE),
+        SysTxKind::VerifierCredit => (2, NONE),
+        SysTxKind::TreasuryCredit => (3, NONE),
+        SysTxKind::Burn => (4, NONE),
+        SysTxKind::RewardPayout => (5, rank_then_pk(rank(&tx.pk), &tx.pk)),
+    }
+}
+
 /// Canonical ordering for system transactions within a slot (consensus-critical)
 /// Order: `ESCROW_CREDIT` → `EMISSION_CREDIT` → `VERIFIER_CREDIT` → `TREASURY_CREDIT` → `BURN` → `REWARD_PAYOUT` (by rank)
+///
+/// Payouts whose ranks collide fall back to pk order (see `sys_tx_order_key`).
 #[must_use]
-pub fn canonical_sys_tx_order(sys_txs: Vec<SysTx>, y_edge_s: &Hash256) -> Vec<SysTx> {
-    // Separate REWARD_PAYOUT transactions from others
-    let (mut reward_payouts, mut others): (Vec<_>, Vec<_>) = sys_txs
-        .into_iter()
-        .partition(|tx| matches!(tx.kind, SysTxKind::RewardPayout));
-
-    // Sort non-REWARD_PAYOUT transactions by kind priority
-    others.sort_by_key(|tx| match tx.kind {
-        SysTxKind::EscrowCredit => 0,
-        SysTxKind::EmissionCredit => 1,
-        SysTxKind::VerifierCredit => 2,
-        SysTxKind::TreasuryCredit => 3,
-        SysTxKind::Burn => 4,
-        SysTxKind::RewardPayout => 5, // Should not happen due to partition
-    });
-
-    // Sort REWARD_PAYOUT transactions by reward_rank
-    reward_payouts.sort_by(|a, b| {
-        let rank_a = reward_rank(y_edge_s, &a.pk);
-        let rank_b = reward_rank(y_edge_s, &b.pk);
-        rank_a.cmp(&rank_b)
-    });
-
-    // Combine: others first, then reward payouts
-    others.extend(reward_payouts);
-    others
+pub fn canonical_sys_tx_order(mut sys_txs: Vec<SysTx>, y_edge_s: &Hash256) -> Vec<SysTx> {
+    // Stable: equal keys keep their input order.
+    sys_txs.sort_by_cached_key(|tx| sys_tx_order_key(tx, y_edge_s));
+    sys_txs
+}
+
+/// Whether `sys_txs` is already in canonical order (keys non-decreasing); one pass, no sort.
+#[must_use]
+pub fn is_canonically_ordered(sys_txs: &[SysTx], y_edge_s: &Hash256) -> bool {
+    let mut keys = sys_txs.iter().map(|tx| sys_tx_order_key(tx, y_edge_s));
+    let Some(mut prev) = keys.next() else {
+        return true;
+    };
+    keys.all(|k| {
+        let ok = prev <= k;
+        prev = k;
+        ok
+    })
+}
+
+/// Per-slot inputs to settlement beyond the carried `EmissionState` / `FeeSplitState`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SlotSettlementInputs<'a> {
+    /// Amounts of the user transfers executed in the slot, in execution order.
+    pub transfer_amounts: &'a [u128],
+    /// Participation set `P_s`, sorted.
+    pub part_set_sorted: &'a [Hash256],
+    /// `SYS_VERIFIER_POOL` balance before settlement of the slot.
+    pub pool_balance_before: u128,
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum SysTxError {
+    #[error("user transfer: {0}")]
+    Transfer(#[from] TransferError),
+    #[error("sys tx count mismatch: expected {expected}, got {got}")]
+    CountMismatch { expected: usize, got: usize },
+    #[error("sys tx mismatch at canonical index {0}")]
+    Mismatch(usize),
+}
+
+/// System transactions produced by settling `slot` (§8), in canonical order.
+///
+/// `emission_state` and `fee_state` are the states carried into the slot; they are advanced
+/// in place. Fee releases are aggregated into one `VERIFIER_CREDIT` / `TREASURY_CREDIT` each;
+/// the fee-release burn and the DRP rounding residual share a single `BURN`. A transfer that
+/// `try_process_transfer` rejects fails the whole slot with `SysTxError::Transfer`.
+pub fn settle_slot_sys_txs(
+    slot: u64,
+    y_edge_s: &Hash256,
+    emission_state: &mut EmissionState,
+    fee_state: &mut FeeSplitState,
+    inputs: &SlotSettlementInputs<'_>,
+) -> Result<Vec<SysTx>, SysTxError> {
+    settle_slot_flows(slot, y_edge_s, emission_state, fee_state, inputs).map(|(txs, _)| txs)
+}
+
+/// Value moved by one slot's settlement that the sys txs alone leave implicit.
+struct SlotFlows {
+    escrowed: u128,
+    treasury: u128,
+    drp_burned: u128,
+    pool_after: u128,
+}
+
+/// `settle_slot_sys_txs` plus the flows `TokenomicsState` needs to stay consistent.
+fn settle_slot_flows(
+    slot: u64,
+    y_edge_s: &Hash256,
+    emission_state: &mut EmissionState,
+    fee_state: &mut FeeSplitState,
+    inputs: &SlotSettlementInputs<'_>,
+) -> Result<(Vec<SysTx>, SlotFlows), SysTxError> {
+    let sys = |kind: SysTxKind, pk: Hash256, amt: u128| SysTx {
+        kind,
+        slot,
+        pk,
+        amt,
+    };
+    let (mut escrow, mut rel_v, mut rel_t, mut rel_b) = (0u128, 0u128, 0u128, 0u128);
+    for &amt in inputs.transfer_amounts {
+        // Sender balances were checked at admission; settlement only routes the fee, so the
+        // balance check is vacuous here while fee and debit overflow still surface.
+        try_process_transfer(
+            slot,
+            u128::MAX,
+            amt,
+            fee_state,
+            |_| {},
+            |_| {},
+            |f| escrow += f,
+            |v| rel_v += v,
+            |t| rel_t += t,
+            |b| rel_b += b,
+        )?;
+    }
+    let mut emitted = 0u128;
+    on_slot_emission(emission_state, u128::from(slot), |e| emitted += e);
+
+    let pool = inputs.pool_balance_before + rel_v + emitted;
+    let mut payouts = Vec::new();
+    let (mut paid, mut residual) = (0u128, 0u128);
+    distribute_drp_for_slot(
+        slot,
+        y_edge_s,
+        inputs.part_set_sorted,
+        || pool,
+        |amt| paid += amt,
+        |pk, amt| payouts.push(sys(SysTxKind::RewardPayout, *pk, amt)),
+        |amt| residual += amt,
+    );
+    let mut out = Vec::new();
+    for (kind, amt) in [
+        (SysTxKind::EscrowCredit, escrow),
+        (SysTxKind::EmissionCredit, emitted),
+        (SysTxKind::VerifierCredit, rel_v),
+        (SysTxKind::TreasuryCredit, rel_t),
+        (SysTxKind::Burn, rel_b + residual),
+    ] {
+        if amt > 0 {
+            out.push(sys(kind, [0u8; 32], amt));
+        }
+    }
+    out.extend(payouts);
+    let flows = SlotFlows {
+        escrowed: escrow,
+        treasury: rel_t,
+        drp_burned: residual,
+        pool_after: pool - paid - residual,
+    };
+    Ok((canonical_sys_tx_order(out, y_edge_s), flows))
+}
+
+/// Tokenomics state carried from slot to slot: emission schedule, fee splits, the
+/// `SYS_VERIFIER_POOL` balance the DRP draws from, and the treasury balance.
+///
+/// Its methods wrap the free functions and keep the pieces in step: emission and verifier
+/// credits fund the pool, and every burn (fee share or DRP residue) lands in
+/// `fees.total_burned_u`, so the next NLB epoch snapshot sees the reduced effective supply.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct TokenomicsState {
+    pub emission: EmissionState,
+    pub fees: FeeSplitState,
+    pub pool_balance_u: u128,
+    pub treasury_balance_u: u128,
+    /// Cumulative fees escrowed by user transfers.
+    pub total_fees_u: u128,
+    /// Burns outside fee routing (DRP residue); also counted in `fees.total_burned_u`.
+    pub drp_burned_u: u128,
+}
+
+impl TokenomicsState {
+    /// `TOTAL_SUPPLY_UOBX` minus everything burned; the input to the NLB epoch split.
+    #[must_use]
+    pub const fn effective_supply_u(&self) -> u128 {
+        TOTAL_SUPPLY_UOBX.saturating_sub(self.fees.total_burned_u)
+    }
+
+    /// Burn `amt_u` outside fee routing.
+    pub const fn burn(&mut self, amt_u: u128) {
+        self.fees.total_burned_u = self.fees.total_burned_u.saturating_add(amt_u);
+        self.drp_burned_u = self.drp_burned_u.saturating_add(amt_u);
+    }
+
+    /// `nlb_roll_epoch_if_needed` on the carried fee state.
+    pub fn roll_epoch_if_needed(&mut self, slot: u64) {
+        nlb_roll_epoch_if_needed(slot, &mut self.fees);
+    }
+
+    /// `on_slot_emission`, crediting the pool; returns the amount emitted.
+    pub fn on_slot_emission(&mut self, slot_1based: u128) -> u128 {
+        let mut emitted = 0u128;
+        on_slot_emission(&mut self.emission, slot_1based, |e| emitted += e);
+        self.pool_balance_u += emitted;
+        emitted
+    }
+
+    /// `try_process_transfer` with the fee releases routed into the pool and treasury.
+    ///
+    /// Returns `(total_debit, fee)`; on error nothing changes and no callback fires.
+    pub fn process_transfer(
+        &mut self,
+        slot: u64,
+        sender_balance_u: u128,
+        amount_u: u128,
+        debit_sender: impl FnMut(u128),
+        credit_recipient: impl FnMut(u128),
+    ) -> Result<(u128, u128), TransferError> {
+        let (mut rel_v, mut rel_t) = (0u128, 0u128);
+        let (debit, fee) = try_process_transfer(
+            slot,
+            sender_balance_u,
+            amount_u,
+            &mut self.fees,
+            debit_sender,
+            credit_recipient,
+            |_| {},
+            |v| rel_v += v,
+            |t| rel_t += t,
+            |_| {},
+        )?;
+        self.total_fees_u += fee;
+        self.pool_balance_u += rel_v;
+        self.treasury_balance_u += rel_t;
+        Ok((debit, fee))
+    }
+
+    /// `distribute_drp_for_slot` out of the pool; rounding residue is burned.
+    pub fn distribute_drp(
+        &mut self,
+        s: u64,
+        y_edge_s: &Hash256,
+        part_set_sorted: &[Hash256],
+        credit_pk: impl FnMut(&Hash256, u128),
+    ) {
+        let pool = self.pool_balance_u;
+        let (mut paid, mut burned) = (0u128, 0u128);
+        distribute_drp_for_slot(
+            s,
+            y_edge_s,
+            part_set_sorted,
+            || pool,
+            |amt| paid += amt,
+            credit_pk,
+            |amt| burned += amt,
+        );
+        self.pool_balance_u -= paid + burned;
+        self.burn(burned);
+    }
+
+    /// Emission and fee conservation across the bundled state.
+    #[must_use]
+    pub fn invariant_ok(&self) -> bool {
+        self.emission.invariant_ok()
+            && self
+                .total_fees_u
+                .checked_add(self.drp_burned_u)
+                .is_some_and(|seen| self.fees.invariant_ok(seen))
+    }
+}
+
+/// Receiver of the system transactions produced by `settle_slot`, in canonical order.
+pub trait SettlementSink {
+    fn emit(&mut self, tx: SysTx);
+}
+
+impl SettlementSink for Vec<SysTx> {
+    fn emit(&mut self, tx: SysTx) {
+        self.push(tx);
+    }
+}
+
+/// Settle slot `slot` end to end: fee routing for `transfers`, emission, then the DRP over
+/// `part_set_sorted`.
+///
+/// With an empty `part_set_sorted` the pool rolls over (`drp_on_empty_participation`): no
+/// payouts and no DRP burn, and `pool_balance_u` keeps the slot's emission and verifier share.
+/// The resulting system transactions go to `sink` in canonical order, ready to be hashed into
+/// `txroot_s` after the slot's user txs. On error `state` is unchanged and nothing is emitted.
+pub fn settle_slot(
+    slot: u64,
+    y_edge_s: &Hash256,
+    transfers: &[u128],
+    part_set_sorted: &[Hash256],
+    state: &mut TokenomicsState,
+    sink: &mut impl SettlementSink,
+) -> Result<(), SysTxError> {
+    let mut next = state.clone();
+    let inputs = SlotSettlementInputs {
+        transfer_amounts: transfers,
+        part_set_sorted,
+        pool_balance_before: next.pool_balance_u,
+    };
+    let (txs, flows) =
+        settle_slot_flows(slot, y_edge_s, &mut next.emission, &mut next.fees, &inputs)?;
+    next.pool_balance_u = flows.pool_after;
+    next.treasury_balance_u += flows.treasury;
+    next.total_fees_u += flows.escrowed;
+    next.burn(flows.drp_burned);
+    *state = next;
+    for tx in txs {
+        sink.emit(tx);
+    }
+    Ok(())
+}
+
+/// Settlement-equality check for the system transactions of `slot`.
+///
+/// Recomputes them from the pre-slot states (left unmodified) and requires `sys_txs` to be the
+/// same multiset. Both sides are sorted by `sys_tx_order_key` with the encoding as tie-break,
+/// so entries whose order keys tie are accepted in any order, as `is_canonically_ordered` does.
+pub fn validate_sys_txs_for_slot(
+    slot: u64,
+    y_edge_s: &Hash256,
+    sys_txs: &[SysTx],
+    emission_state: &EmissionState,
+    fee_state: &FeeSplitState,
+    inputs: &SlotSettlementInputs<'_>,
+) -> Result<(), SysTxError> {
+    let expected = settle_slot_sys_txs(
+        slot,
+        y_edge_s,
+        &mut emission_state.clone(),
+        &mut fee_state.clone(),
+        inputs,
+    )?;
+    let by_key_then_bytes = |txs: &mut Vec<SysTx>| {
+        txs.sort_by_cached_key(|tx| (sys_tx_order_key(tx, y_edge_s), enc_sys_tx(tx)));
+    };
+    let mut expected = expected;
+    by_key_then_bytes(&mut expected);
+    let mut got = sys_txs.to_vec();
+    by_key_then_bytes(&mut got);
+    if got.len() != expected.len() {
+        return Err(SysTxError::CountMismatch {
+            expected: expected.len(),
+            got: got.len(),
+        });
+    }
+    got.iter()
+        .zip(&expected)
+        .position(|(a, b)| a != b)
+        .map_or(Ok(()), |i| Err(SysTxError::Mismatch(i)))
 }
 
 #[cfg(test)]
@@ -512,6 +1679,26 @@ mod tests {
         assert!(total > 0);
     }
 
+    #[test]
+    fn closed_form_emission_matches_replay_across_halving_and_terminal() {
+        // Resume replay from closed-form state just before a halving boundary and the terminal slot
+        for start in [SLOTS_PER_HALVING - 500, LAST_EMISSION_SLOT - 500] {
+            let mut st = emission_state_through(start);
+            for s in (start + 1)..=(start + 1_000).min(LAST_EMISSION_SLOT) {
+                let mut paid = 0u128;
+                on_slot_emission(&mut st, s, |a| paid += a);
+                assert_eq!(paid, emission_at_slot(s), "slot {s}");
+                assert_eq!(st.total_emitted_u, cumulative_emission_through(s));
+            }
+        }
+        assert_eq!(
+            cumulative_emission_through(LAST_EMISSION_SLOT),
+            TOTAL_SUPPLY_UOBX
+        );
+        assert_eq!(emission_at_slot(LAST_EMISSION_SLOT + 1), 0);
+        assert_eq!(emission_at_slot(0), 0);
+    }
+
     #[test]
     fn fee_rule_flat_and_percent() {
         assert_eq!(fee_int(10), FLAT_FEE_U);
@@ -530,6 +1717,31 @@ mod tests {
         assert!(fs.fee_escrow_u <= 5);
     }
 
+    #[test]
+    fn rank_ties_break_by_pk() {
+        // Force every rank to collide: order must still be total, by pk, for any input order.
+        let tie = |_: &Hash256| [0xAAu8; 32];
+        let pks = [[3u8; 32], [1u8; 32], [2u8; 32]];
+        let mut rev = pks;
+        rev.reverse();
+        let want = vec![[1u8; 32], [2u8; 32], [3u8; 32]];
+        assert_eq!(drp_winner_order_by(&pks, tie), want);
+        assert_eq!(drp_winner_order_by(&rev, tie), want);
+
+        let payout = |pk: Hash256| SysTx {
+            kind: SysTxKind::RewardPayout,
+            slot: 1,
+            pk,
+            amt: 5,
+        };
+        for input in [pks, rev] {
+            let mut txs: Vec<SysTx> = input.iter().map(|pk| payout(*pk)).collect();
+            txs.sort_by_cached_key(|tx| sys_tx_order_key_by(tx, tie));
+            let got: Vec<Hash256> = txs.iter().map(|tx| tx.pk).collect();
+            assert_eq!(got, want);
+        }
+    }
+
     #[test]
     fn canonical_sys_tx_ordering() {
         let pk1 = [1u8; 32];
@@ -0,0 +1,19 @@
+//! Fixtures shared by the integration tests; each test crate uses a subset.
+#![allow(dead_code)]
+
+use obex_alpha_t::SysTxKind;
+
+/// Every system-transaction kind, in wire-tag order.
+pub const ALL_KINDS: [SysTxKind; 6] = [
+    SysTxKind::EscrowCredit,
+    SysTxKind::VerifierCredit,
+    SysTxKind::TreasuryCredit,
+    SysTxKind::Burn,
+    SysTxKind::RewardPayout,
+    SysTxKind::EmissionCredit,
+];
+
+/// `m` distinct participant keys `[v; 32]`, already in canonical (sorted) order.
+pub fn parts(m: u8) -> Vec<[u8; 32]> {
+    (0..m).map(|v| [v; 32]).collect()
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use obex_alpha_t::*;
+use std::collections::BTreeMap;
+
+use common::parts;
+
+fn run(k: usize, parts: &[[u8; 32]], pool: u128) -> (u128, BTreeMap<[u8; 32], Vec<u128>>, u128) {
+    let mut debited = 0u128;
+    let mut credits: BTreeMap<[u8; 32], Vec<u128>> = BTreeMap::new();
+    let mut burned = 0u128;
+    distribute_drp_for_slot_k(
+        5,
+        &[7u8; 32],
+        parts,
+        k,
+        || pool,
+        |d| debited += d,
+        |pk, a| credits.entry(*pk).or_default().push(a),
+        |b| burned += b,
+    );
+    (debited, credits, burned)
+}
+
+#[test]
+fn k_zero_pays_only_baseline() {
+    let set = parts(8);
+    let pool = 1_000_003u128;
+    let (debited, credits, burned) = run(0, &set, pool);
+    let baseline = pool * u128::from(DRP_BASELINE_PCT) / 100;
+    let per_base = baseline / 8;
+    assert_eq!(debited, per_base * 8);
+    assert_eq!(burned, baseline % 8);
+    assert_eq!(credits.len(), 8);
+    assert!(credits.values().all(|v| v == &vec![per_base]));
+}
+
+#[test]
+fn k_equal_m_pays_every_participant_once_from_lottery() {
+    let set = parts(8);
+    let pool = 1_000_003u128;
+    let (debited, credits, burned) = run(8, &set, pool);
+    let baseline = pool * u128::from(DRP_BASELINE_PCT) / 100;
+    let lottery = pool - baseline;
+    let (per_base, per_win) = (baseline / 8, lottery / 8);
+    assert_eq!(debited, per_base * 8 + per_win * 8);
+    assert_eq!(burned, baseline % 8 + lottery % 8);
+    assert_eq!(credits.len(), 8);
+    assert!(credits.values().all(|v| v == &vec![per_base, per_win]));
+    // Oversized k clamps to m
+    assert_eq!(run(100, &set, pool), (debited, credits, burned));
+}
+
+#[test]
+fn default_width_matches_explicit_k() {
+    let set = parts(40);
+    let pool = 9_999_999u128;
+    let mut order = Vec::new();
+    distribute_drp_for_slot(
+        5,
+        &[7u8; 32],
+        &set,
+        || pool,
+        |_| {},
+        |pk, a| order.push((*pk, a)),
+        |_| {},
+    );
+    let mut order_k = Vec::new();
+    distribute_drp_for_slot_k(
+        5,
+        &[7u8; 32],
+        &set,
+        DRP_K_WINNERS,
+        || pool,
+        |_| {},
+        |pk, a| order_k.push((*pk, a)),
+        |_| {},
+    );
+    assert_eq!(order, order_k);
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use obex_alpha_t::*;
+
+use common::parts;
+
+/// Deterministic permutation without an RNG: stride through the set.
+fn permute(v: &[[u8; 32]], stride: usize) -> Vec<[u8; 32]> {
+    (0..v.len()).map(|i| v[(i * stride) % v.len()]).collect()
+}
+
+fn lottery_payouts(y: &[u8; 32], set: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    let mut credits = Vec::new();
+    distribute_drp_for_slot(
+        9,
+        y,
+        set,
+        || 50_000_000,
+        |_| {},
+        |pk, _| credits.push(*pk),
+        |_| {},
+    );
+    credits.split_off(set.len())
+}
+
+#[test]
+fn payout_order_is_rank_order_and_input_order_independent() {
+    let y = [0x5Au8; 32];
+    let mut sorted = parts(40);
+    sorted.sort_unstable();
+    let lottery = lottery_payouts(&y, &sorted);
+    assert_eq!(lottery.len(), DRP_K_WINNERS);
+    assert_eq!(drp_winner_order(&y, &lottery), lottery);
+    assert_eq!(lottery_payouts(&y, &sorted), lottery);
+
+    // Participant input in any order, canonicalised (sorted) as the API requires.
+    for stride in [3usize, 7, 11, 13] {
+        let mut set = permute(&sorted, stride);
+        assert_ne!(set, sorted);
+        set.sort_unstable();
+        assert_eq!(lottery_payouts(&y, &set), lottery);
+    }
+
+    // Winner list order never affects the ranked order.
+    let mut rev = lottery.clone();
+    rev.reverse();
+    assert_eq!(drp_winner_order(&y, &rev), lottery);
+}
+
+#[test]
+fn ranked_order_matches_canonical_sys_tx_order() {
+    let y = [0x5Au8; 32];
+    let winners = parts(16);
+    let txs: Vec<SysTx> = winners
+        .iter()
+        .map(|pk| SysTx {
+            kind: SysTxKind::RewardPayout,
+            slot: 9,
+            pk: *pk,
+            amt: 1,
+        })
+        .collect();
+    let ordered: Vec<[u8; 32]> = canonical_sys_tx_order(txs, &y)
+        .iter()
+        .map(|t| t.pk)
+        .collect();
+    assert_eq!(ordered, drp_winner_order(&y, &winners));
+}
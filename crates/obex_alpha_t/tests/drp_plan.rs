@@ -0,0 +1,61 @@
+mod common;
+
+use obex_alpha_t::*;
+use std::collections::BTreeSet;
+
+use common::parts;
+
+#[test]
+fn plan_totals_match_pay_and_winners_are_unique() {
+    let set = parts(40);
+    let pool = 9_999_999u128;
+    let plan = compute_drp_plan(5, &[7u8; 32], &set, pool);
+
+    let lottery_paid: u128 = plan.winners.iter().map(|w| w.amount).sum();
+    assert_eq!(plan.total_pay, plan.per_base * 40 + lottery_paid);
+    assert_eq!(plan.total_pay + plan.burned(), pool);
+    assert_eq!(plan.winners.len(), DRP_K_WINNERS);
+    let unique: BTreeSet<usize> = plan.winners.iter().map(|w| w.index).collect();
+    assert_eq!(unique.len(), plan.winners.len());
+    assert!(unique.iter().all(|&i| i < set.len()));
+}
+
+#[test]
+fn distribution_follows_plan() {
+    let set = parts(40);
+    let pool = 9_999_999u128;
+    let y = [7u8; 32];
+    let plan = compute_drp_plan(5, &y, &set, pool);
+
+    let (mut debited, mut burned) = (0u128, 0u128);
+    let mut credits = Vec::new();
+    distribute_drp_for_slot(
+        5,
+        &y,
+        &set,
+        || pool,
+        |d| debited += d,
+        |pk, a| credits.push((*pk, a)),
+        |b| burned += b,
+    );
+    assert_eq!(debited, plan.total_pay);
+    assert_eq!(burned, plan.burned());
+    let lottery: Vec<([u8; 32], u128)> = plan
+        .winners
+        .iter()
+        .map(|w| (set[w.index], w.amount))
+        .collect();
+    assert_eq!(credits[set.len()..], lottery[..]);
+}
+
+#[test]
+fn empty_inputs_give_empty_plan() {
+    assert_eq!(
+        compute_drp_plan(1, &[0u8; 32], &[], 1_000),
+        DrpPlan::default()
+    );
+    assert_eq!(
+        compute_drp_plan(1, &[0u8; 32], &parts(3), 0),
+        DrpPlan::default()
+    );
+}
@@ -51,3 +51,105 @@ fn explicit_residual_burns_in_splits_do_not_leak_fractional() {
     let delta = ver.saturating_add(tre).saturating_add(burned);
     assert!(escrow >= delta);
 }
+
+#[test]
+fn closed_form_emission_matches_iterated_first_100k_slots() {
+    let mut st = EmissionState::default();
+    for s in 1..=100_000u128 {
+        let mut paid = 0u128;
+        on_slot_emission(&mut st, s, |a| paid += a);
+        assert_eq!(emission_at_slot(s), paid, "slot {s}");
+        assert_eq!(
+            cumulative_emission_through(s),
+            st.total_emitted_u,
+            "slot {s}"
+        );
+    }
+}
+
+fn iterate_to(st: &mut EmissionState, target: u128) -> u128 {
+    let mut credited = 0u128;
+    for s in (st.last_slot + 1)..=target {
+        on_slot_emission(st, s, |a| credited += a);
+    }
+    credited
+}
+
+#[test]
+fn fast_forward_matches_iteration_from_genesis() {
+    let mut iter = EmissionState::default();
+    for target in [1u128, 2, 7, 1_000, 12_345, 60_000] {
+        let credited_iter = iterate_to(&mut iter, target);
+        let mut ff = EmissionState::default();
+        let mut credited_ff = 0u128;
+        advance_emission_to(&mut ff, target, |a| credited_ff += a);
+        assert_eq!(ff, iter, "target {target}");
+        assert_eq!(credited_ff, ff.total_emitted_u);
+        assert!(credited_iter <= credited_ff);
+    }
+}
+
+#[test]
+fn fast_forward_matches_iteration_across_halving_and_terminal() {
+    for anchor in [SLOTS_PER_HALVING, 2 * SLOTS_PER_HALVING, LAST_EMISSION_SLOT] {
+        let mut iter = EmissionState::default();
+        advance_emission_to(&mut iter, anchor - 1_000, |_| {});
+        let resume = iter.clone();
+        for target in [anchor - 1, anchor, anchor + 1, anchor + 1_000] {
+            iterate_to(&mut iter, target.min(LAST_EMISSION_SLOT));
+
+            let mut from_resume = resume.clone();
+            let mut credited_ff = 0u128;
+            let mut calls = 0u32;
+            advance_emission_to(&mut from_resume, target, |a| {
+                credited_ff += a;
+                calls += 1;
+            });
+            assert_eq!(from_resume, iter, "anchor {anchor} target {target}");
+            assert_eq!(resume.total_emitted_u + credited_ff, iter.total_emitted_u);
+            assert!(calls <= 3);
+
+            let mut from_genesis = EmissionState::default();
+            advance_emission_to(&mut from_genesis, target, |_| {});
+            assert_eq!(from_genesis, iter);
+        }
+    }
+    let mut st = EmissionState::default();
+    advance_emission_to(&mut st, u128::MAX, |_| {});
+    assert_eq!(st.total_emitted_u, TOTAL_SUPPLY_UOBX);
+    assert_eq!(st.last_slot, LAST_EMISSION_SLOT);
+}
+
+#[test]
+fn halving_schedule_exhausts_supply_exactly() {
+    // Per-slot rate in period p is R0_NUM / (R0_DEN · 2^p) with R0_NUM = TOTAL · 2^(N-1) and
+    // R0_DEN = SLOTS_PER_HALVING · (2^N - 1). Over the common denominator
+    // D = SLOTS_PER_HALVING · (2^N - 1) · 2^(N-1), period p pays
+    // SLOTS_PER_HALVING · R0_NUM · 2^(N-1-p) / D.
+    let n = HALVING_COUNT;
+    let r0_num = TOTAL_SUPPLY_UOBX << (n - 1);
+    let r0_den = SLOTS_PER_HALVING * ((1u128 << n) - 1);
+    let d = r0_den << (n - 1);
+
+    assert_eq!(LAST_EMISSION_SLOT % SLOTS_PER_HALVING, 0);
+    assert_eq!(LAST_EMISSION_SLOT / SLOTS_PER_HALVING, u128::from(n));
+
+    let mut exact_num = 0u128;
+    for p in 0..n {
+        exact_num += (SLOTS_PER_HALVING * r0_num) << (n - 1 - p);
+        // The accumulator never pays ahead of the exact schedule, and falls at most one base
+        // unit behind per period boundary crossed (carried remainders are re-scaled by 1/2).
+        let end = u128::from(p + 1) * SLOTS_PER_HALVING;
+        let paid = cumulative_emission_through(end);
+        let exact_floor = exact_num / d;
+        if end < LAST_EMISSION_SLOT {
+            assert!(paid <= exact_floor, "period {p} pays ahead");
+            assert!(exact_floor - paid <= u128::from(p) + 1, "period {p} lags");
+        }
+    }
+    assert_eq!(exact_num, TOTAL_SUPPLY_UOBX * d);
+    assert_eq!(
+        cumulative_emission_through(LAST_EMISSION_SLOT),
+        TOTAL_SUPPLY_UOBX
+    );
+}
@@ -0,0 +1,42 @@
+use obex_alpha_t::*;
+
+#[test]
+fn roll_from_epoch_0_directly_to_epoch_5() {
+    let mut fs = FeeSplitState {
+        total_burned_u: 650_000 * UOBX_PER_OBX,
+        ..FeeSplitState::default()
+    };
+    let slot = 5 * NLB_EPOCH_SLOTS + 123;
+    assert_eq!(nlb_roll_epochs_through(slot, &mut fs), 5);
+    assert_eq!(
+        fs.nlb,
+        NlbEpochState {
+            epoch_index: 5,
+            start_slot: 5 * NLB_EPOCH_SLOTS,
+            eff_supply_snapshot_u: 350_000 * UOBX_PER_OBX,
+            v_pct: 50,
+            t_pct: 40,
+            b_pct: 10,
+        }
+    );
+
+    // Idempotent within the epoch, and identical to the single-step roll.
+    let snap = fs.clone();
+    assert_eq!(nlb_roll_epochs_through(6 * NLB_EPOCH_SLOTS - 1, &mut fs), 0);
+    assert_eq!(fs, snap);
+    let mut single = FeeSplitState {
+        total_burned_u: 650_000 * UOBX_PER_OBX,
+        ..FeeSplitState::default()
+    };
+    nlb_roll_epoch_if_needed(slot, &mut single);
+    assert_eq!(single, fs);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "must not go backward")]
+fn rolling_backward_is_a_debug_assertion() {
+    let mut fs = FeeSplitState::default();
+    nlb_roll_epochs_through(3 * NLB_EPOCH_SLOTS, &mut fs);
+    nlb_roll_epochs_through(NLB_EPOCH_SLOTS, &mut fs);
+}
@@ -0,0 +1,88 @@
+use obex_alpha_t::*;
+
+fn state() -> FeeSplitState {
+    let mut fs = FeeSplitState::default();
+    // Force an epoch roll so split percentages are populated
+    nlb_roll_epoch_if_needed(NLB_EPOCH_SLOTS, &mut fs);
+    fs
+}
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Flows {
+    v: u128,
+    t: u128,
+    b: u128,
+}
+
+#[test]
+fn checked_matches_saturating_below_threshold() {
+    let mut fs_a = state();
+    let mut fs_b = state();
+    let (mut fa, mut fb) = (Flows::default(), Flows::default());
+    for (i, fee) in [10u128, 11, 123, 9_999, 1_000_001].into_iter().enumerate() {
+        let den = if i % 2 == 0 { 1 } else { 100 };
+        fs_a.fee_escrow_u += fee;
+        fs_b.fee_escrow_u += fee;
+        route_fee_with_nlb(
+            &mut fs_a,
+            fee,
+            den,
+            |v| fa.v += v,
+            |t| fa.t += t,
+            |b| fa.b += b,
+        );
+        route_fee_with_nlb_checked(
+            &mut fs_b,
+            fee,
+            den,
+            |v| fb.v += v,
+            |t| fb.t += t,
+            |b| fb.b += b,
+        )
+        .unwrap();
+        assert_eq!(fs_a, fs_b);
+    }
+    assert_eq!(fa, fb);
+}
+
+#[test]
+fn accumulator_near_max_errors_instead_of_saturating() {
+    let mut fs = state();
+    fs.acc_v_num = u128::MAX - 5;
+    let before = fs.clone();
+    let fired = std::cell::Cell::new(false);
+    let res = route_fee_with_nlb_checked(
+        &mut fs,
+        1,
+        1,
+        |_| fired.set(true),
+        |_| fired.set(true),
+        |_| fired.set(true),
+    );
+    assert_eq!(res, Err(FeeError::AccumulatorOverflow));
+    assert_eq!(fs, before, "state must be untouched on error");
+    assert!(!fired.get());
+
+    // The saturating path silently clamps the same input
+    let mut fs_sat = before;
+    route_fee_with_nlb(&mut fs_sat, 1, 1, |_| {}, |_| {}, |_| {});
+    assert_eq!(fs_sat.acc_v_num % 10_000, u128::MAX % 10_000);
+
+    let mut fs = state();
+    assert_eq!(
+        route_fee_with_nlb_checked(&mut fs, u128::MAX / 50, 1, |_| {}, |_| {}, |_| {}),
+        Err(FeeError::NumeratorOverflow)
+    );
+}
+
+#[test]
+fn try_fee_int_rejects_sub_minimum_and_matches_fee_int() {
+    assert_eq!(try_fee_int(0), Err(FeeError::BelowMinimum));
+    assert_eq!(try_fee_int(9), Err(FeeError::BelowMinimum));
+    assert_eq!(try_fee_int(10), Ok(FLAT_FEE_U));
+    assert_eq!(try_fee_int(1_000), Ok(FLAT_FEE_U));
+    assert_eq!(try_fee_int(1_001), Ok(11));
+    for a in [10u128, 999, 1_000, 1_001, 12_345, u128::MAX] {
+        assert_eq!(try_fee_int(a), Ok(fee_int(a)));
+    }
+}
@@ -0,0 +1,126 @@
+use obex_alpha_t::*;
+
+/// 40/20/40 split with `escrow_u` held against a 1_000 µOBX fee release of (400, 200, 400).
+fn short_escrow(escrow_u: u128) -> FeeSplitState {
+    let mut fs = FeeSplitState::default();
+    fs.nlb.v_pct = 40;
+    fs.nlb.t_pct = 20;
+    fs.nlb.b_pct = 40;
+    fs.fee_escrow_u = escrow_u;
+    fs
+}
+
+fn release(fs: &mut FeeSplitState, policy: DeficitPolicy) -> (u128, u128, u128) {
+    let (mut v, mut t, mut b) = (0, 0, 0);
+    route_fee_with_nlb_with_policy(fs, 1_000, 1, policy, |x| v += x, |x| t += x, |x| b += x);
+    (v, t, b)
+}
+
+#[test]
+fn policies_cut_the_same_deficit_differently() {
+    let mut fs_p = short_escrow(700);
+    let mut fs_q = short_escrow(700);
+    assert_eq!(
+        release(&mut fs_p, DeficitPolicy::PriorityBurnLast),
+        (400, 200, 100)
+    );
+    assert_eq!(
+        release(&mut fs_q, DeficitPolicy::Proportional),
+        (280, 140, 280)
+    );
+    assert_eq!((fs_p.fee_escrow_u, fs_q.fee_escrow_u), (0, 0));
+    assert_eq!(fs_p.total_burned_u, 100);
+    assert_eq!(fs_q.total_burned_u, 280);
+}
+
+#[test]
+fn proportional_rounding_leftover_is_cut_by_priority() {
+    let mut fs = short_escrow(999);
+    assert_eq!(
+        release(&mut fs, DeficitPolicy::Proportional),
+        (400, 200, 399)
+    );
+    assert_eq!(fs.fee_escrow_u, 0);
+}
+
+#[test]
+fn default_policy_matches_route_fee_with_nlb() {
+    assert_eq!(DeficitPolicy::default(), DeficitPolicy::PriorityBurnLast);
+    for escrow in [0u128, 1, 700, 999, 1_000, 5_000] {
+        let mut fs_a = short_escrow(escrow);
+        let mut fs_b = short_escrow(escrow);
+        let (mut v, mut t, mut b) = (0, 0, 0);
+        route_fee_with_nlb(&mut fs_a, 1_000, 1, |x| v += x, |x| t += x, |x| b += x);
+        assert_eq!(release(&mut fs_b, DeficitPolicy::default()), (v, t, b));
+        assert_eq!(fs_a, fs_b);
+    }
+}
+
+#[test]
+fn stored_policy_reaches_every_routing_path() {
+    let mut fs = short_escrow(700);
+    fs.deficit_policy = DeficitPolicy::Proportional;
+    let (mut v, mut t, mut b) = (0, 0, 0);
+    route_fee_with_nlb(
+        &mut fs.clone(),
+        1_000,
+        1,
+        |x| v += x,
+        |x| t += x,
+        |x| b += x,
+    );
+    assert_eq!((v, t, b), (280, 140, 280));
+
+    let (mut v, mut t, mut b) = (0, 0, 0);
+    route_fee_with_nlb_checked(
+        &mut fs.clone(),
+        1_000,
+        1,
+        |x| v += x,
+        |x| t += x,
+        |x| b += x,
+    )
+    .unwrap();
+    assert_eq!((v, t, b), (280, 140, 280));
+
+    // A transfer escrows its own fee first, so start from an escrow that its release outruns.
+    let mut short = short_escrow(0);
+    short.acc_b_num = 9_000_000;
+    let mut prio = short.clone();
+    short.deficit_policy = DeficitPolicy::Proportional;
+    let run = |fs: &mut FeeSplitState| {
+        let (mut v, mut t, mut b) = (0, 0, 0);
+        try_process_transfer(
+            1,
+            u128::MAX,
+            MIN_TRANSFER_U,
+            fs,
+            |_| {},
+            |_| {},
+            |_| {},
+            |x| v += x,
+            |x| t += x,
+            |x| b += x,
+        )
+        .unwrap();
+        (v, t, b)
+    };
+    assert_ne!(run(&mut short), run(&mut prio));
+}
+
+#[test]
+fn tokenomics_state_settles_with_its_fee_policy() {
+    let parts = [[1u8; 32]];
+    let mut prio = TokenomicsState::default();
+    // Pending releases in every bucket far beyond the slot's escrowed fee.
+    prio.fees.acc_v_num = 9_000_000;
+    prio.fees.acc_t_num = 9_000_000;
+    prio.fees.acc_b_num = 9_000_000;
+    let mut prop = prio.clone();
+    prop.fees.deficit_policy = DeficitPolicy::Proportional;
+    let (mut a, mut b): (Vec<SysTx>, Vec<SysTx>) = (Vec::new(), Vec::new());
+    settle_slot(1, &[0u8; 32], &[2_000], &parts, &mut prio, &mut a).unwrap();
+    settle_slot(1, &[0u8; 32], &[2_000], &parts, &mut prop, &mut b).unwrap();
+    assert_eq!(prop.fees.deficit_policy, DeficitPolicy::Proportional);
+    assert_ne!(prio.fees.total_burned_u, prop.fees.total_burned_u);
+}
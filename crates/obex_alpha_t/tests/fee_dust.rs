@@ -0,0 +1,52 @@
+use obex_alpha_t::*;
+
+#[test]
+fn released_plus_dust_matches_exact_fee_split() {
+    let mut fs = FeeSplitState::default();
+    let slot = NLB_EPOCH_SLOTS;
+    nlb_roll_epoch_if_needed(slot, &mut fs);
+    let (v_pct, t_pct, b_pct) = (fs.nlb.v_pct, fs.nlb.t_pct, fs.nlb.b_pct);
+    assert_eq!(u32::from(v_pct) + u32::from(t_pct) + u32::from(b_pct), 100);
+
+    // Exact per-bucket shares `sum(fee * pct / 100)`, scaled by 10_000.
+    let (mut exact_v, mut exact_t, mut exact_b) = (0u128, 0u128, 0u128);
+    let (mut ver, mut tre, mut burned) = (0u128, 0u128, 0u128);
+    let amounts = (0..500u128).map(|i| MIN_TRANSFER_U + (i * 7_919 + i * i * 13) % 250_000);
+    for amount in amounts {
+        let fee_over_100 = if amount <= FLAT_SWITCH_U {
+            FLAT_FEE_U * 100
+        } else {
+            amount
+        };
+        exact_v += fee_over_100 * u128::from(v_pct);
+        exact_t += fee_over_100 * u128::from(t_pct);
+        exact_b += fee_over_100 * u128::from(b_pct);
+        process_transfer(
+            slot,
+            u128::MAX,
+            amount,
+            &mut fs,
+            |_| {},
+            |_| {},
+            |_| {},
+            |v| ver += v,
+            |t| tre += t,
+            |b| burned += b,
+        );
+        let (dust_v, dust_t, dust_b) = fs.pending_dust();
+        assert!(dust_v < 10_000 && dust_t < 10_000 && dust_b < 10_000);
+        assert_eq!(ver * 10_000 + dust_v, exact_v);
+        assert_eq!(tre * 10_000 + dust_t, exact_t);
+        assert_eq!(burned * 10_000 + dust_b, exact_b);
+    }
+    assert_eq!(fs.nlb.epoch_index, 1);
+    assert_eq!(
+        (fs.total_verifier_u, fs.total_treasury_u, fs.total_burned_u),
+        (ver, tre, burned)
+    );
+}
+
+#[test]
+fn fresh_state_has_no_dust() {
+    assert_eq!(FeeSplitState::default().pending_dust(), (0, 0, 0));
+}
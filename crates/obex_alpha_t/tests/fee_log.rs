@@ -0,0 +1,81 @@
+use obex_alpha_t::*;
+
+#[test]
+fn logged_releases_sum_to_escrow_decrease() {
+    let mut fs = FeeSplitState::default();
+    let mut log = FeeSplitLog::default();
+    let mut escrowed = 0u128;
+    let (mut ver, mut tre, mut burned) = (0u128, 0u128, 0u128);
+    let transfers = [
+        (0u64, 10u128),
+        (1, 999),
+        (2, 1_001),
+        (3, 12_345),
+        (NLB_EPOCH_SLOTS, 77_777),
+        (NLB_EPOCH_SLOTS + 5, 500),
+        (NLB_EPOCH_SLOTS + 9, 3_141_592),
+    ];
+    for &(slot, amount) in &transfers {
+        let (_, fee) = process_transfer_logged(
+            slot,
+            u128::MAX,
+            amount,
+            &mut fs,
+            &mut log,
+            |_| {},
+            |_| {},
+            |e| escrowed += e,
+            |v| ver += v,
+            |t| tre += t,
+            |b| burned += b,
+        );
+        assert!(fee > 0);
+    }
+    assert_eq!(log.entries.len(), transfers.len());
+    for (entry, &(slot, _)) in log.entries.iter().zip(&transfers) {
+        assert_eq!(entry.slot, slot);
+    }
+    // Escrow decrease = everything credited in minus what is still held
+    assert_eq!(log.total_released(), escrowed - fs.fee_escrow_u);
+    assert_eq!(log.total_released(), ver + tre + burned);
+    assert_eq!(
+        log.entries.iter().map(|e| e.rel_b).sum::<u128>(),
+        fs.total_burned_u
+    );
+}
+
+#[test]
+fn logged_transfer_matches_unlogged() {
+    let mut plain = FeeSplitState::default();
+    let mut logged = FeeSplitState::default();
+    let mut log = FeeSplitLog::default();
+    for (slot, amount) in [(0u64, 2_345u128), (1, 10), (2, 1_000_000)] {
+        let a = process_transfer(
+            slot,
+            u128::MAX,
+            amount,
+            &mut plain,
+            |_| {},
+            |_| {},
+            |_| {},
+            |_| {},
+            |_| {},
+            |_| {},
+        );
+        let b = process_transfer_logged(
+            slot,
+            u128::MAX,
+            amount,
+            &mut logged,
+            &mut log,
+            |_| {},
+            |_| {},
+            |_| {},
+            |_| {},
+            |_| {},
+            |_| {},
+        );
+        assert_eq!(a, b);
+        assert_eq!(plain, logged);
+    }
+}
@@ -0,0 +1,54 @@
+use obex_alpha_t::*;
+
+#[test]
+fn emission_invariant_holds_along_schedule_and_detects_tampering() {
+    let mut st = EmissionState::default();
+    assert!(st.invariant_ok());
+    for s in 1u128..=500 {
+        on_slot_emission(&mut st, s, |_| {});
+        assert!(st.invariant_ok(), "slot {s}");
+    }
+    advance_emission_to(&mut st, SLOTS_PER_HALVING + 17, |_| {});
+    assert!(st.invariant_ok());
+
+    let mut bad = st.clone();
+    bad.total_emitted_u += 1;
+    assert!(!bad.invariant_ok());
+    let mut bad = st;
+    bad.last_slot += 1;
+    assert!(!bad.invariant_ok());
+
+    let mut done = EmissionState::default();
+    advance_emission_to(&mut done, LAST_EMISSION_SLOT, |_| {});
+    assert!(done.invariant_ok());
+    assert_eq!(done.total_emitted_u, TOTAL_SUPPLY_UOBX);
+}
+
+#[test]
+fn fee_invariant_holds_after_transfers() {
+    let mut fs = FeeSplitState::default();
+    let mut fees = 0u128;
+    assert!(fs.invariant_ok(fees));
+    for (i, amt) in [10u128, 999, 1_000, 1_001, 123_456, 7_777_777]
+        .into_iter()
+        .enumerate()
+    {
+        let slot = u64::try_from(i).unwrap() * (NLB_EPOCH_SLOTS / 2);
+        let (_debit, fee) = process_transfer(
+            slot,
+            u128::MAX,
+            amt,
+            &mut fs,
+            |_| {},
+            |_| {},
+            |_| {},
+            |_| {},
+            |_| {},
+            |_| {},
+        );
+        fees += fee;
+        assert!(fs.invariant_ok(fees));
+    }
+    assert!(fs.total_verifier_u > 0 && fs.total_treasury_u > 0);
+    assert!(!fs.invariant_ok(fees + 1));
+}
@@ -0,0 +1,120 @@
+use obex_alpha_t::*;
+
+const ALICE: [u8; 32] = [1; 32];
+const BOB: [u8; 32] = [2; 32];
+
+#[test]
+fn over_debit_errors_and_leaves_balances_unchanged() {
+    let mut ledger = Ledger::new();
+    ledger.credit(&ALICE, 500).unwrap();
+    let before = ledger.clone();
+    assert_eq!(
+        ledger.debit(&ALICE, 501),
+        Err(LedgerError::Underflow {
+            need: 501,
+            have: 500
+        })
+    );
+    assert_eq!(
+        ledger.debit(&BOB, 1),
+        Err(LedgerError::Underflow { need: 1, have: 0 })
+    );
+    assert_eq!(ledger, before);
+    ledger.debit(&ALICE, 500).unwrap();
+    assert_eq!(ledger.balance(&ALICE), 0);
+
+    ledger.credit(&BOB, u128::MAX).unwrap();
+    assert_eq!(ledger.credit(&BOB, 1), Err(LedgerError::Overflow));
+    assert_eq!(ledger.balance(&BOB), u128::MAX);
+}
+
+#[test]
+fn transfer_moves_balances_or_nothing() {
+    let mut ledger = Ledger::new();
+    ledger.credit(&ALICE, 10_000).unwrap();
+    let mut fs = FeeSplitState::default();
+    let (mut v, mut t, mut b) = (0u128, 0u128, 0u128);
+    let (debit, fee) = ledger
+        .transfer(
+            0,
+            &ALICE,
+            &BOB,
+            5_000,
+            &mut fs,
+            |x| v += x,
+            |x| t += x,
+            |x| b += x,
+        )
+        .unwrap();
+    assert_eq!((debit, fee), (5_050, 50));
+    assert_eq!(ledger.balance(&ALICE), 10_000 - 5_050);
+    assert_eq!(ledger.balance(&BOB), 5_000);
+    assert_eq!(v + t + b + fs.fee_escrow_u, fee);
+
+    let (before_ledger, before_fs) = (ledger.clone(), fs.clone());
+    let err = ledger
+        .transfer(0, &ALICE, &BOB, 4_950, &mut fs, |_| {}, |_| {}, |_| {})
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransferError::InsufficientBalance {
+            need: 4_950 + 50,
+            have: 4_950
+        }
+    );
+    assert_eq!((ledger, fs), (before_ledger, before_fs));
+}
+
+#[test]
+fn credit_all_collects_drp_payouts() {
+    let parts: Vec<[u8; 32]> = (1u8..=6).map(|i| [i; 32]).collect();
+    let mut ledger = Ledger::new();
+    let (mut paid, mut burned) = (0u128, 0u128);
+    let mut payouts = Vec::new();
+    distribute_drp_for_slot(
+        3,
+        &[7; 32],
+        &parts,
+        || 1_000_003,
+        |a| paid += a,
+        |pk, a| payouts.push((*pk, a)),
+        |a| burned += a,
+    );
+    ledger.credit_all(&payouts).unwrap();
+    let total: u128 = parts.iter().map(|pk| ledger.balance(pk)).sum();
+    assert!(paid > 0);
+    assert_eq!(total, paid);
+
+    // One overflowing payout rejects the whole batch.
+    let before = ledger.clone();
+    assert_eq!(
+        ledger.credit_all(&[(BOB, 1), (parts[0], u128::MAX)]),
+        Err(LedgerError::Overflow)
+    );
+    assert_eq!(ledger, before);
+}
+
+#[test]
+fn transfer_into_a_full_balance_errors_before_any_change() {
+    let mut ledger = Ledger::new();
+    ledger.credit(&ALICE, 10_000).unwrap();
+    ledger.credit(&BOB, u128::MAX - 4_999).unwrap();
+    let mut fs = FeeSplitState::default();
+    let (before_ledger, before_fs) = (ledger.clone(), fs.clone());
+    let fired = std::cell::Cell::new(false);
+    let err = ledger
+        .transfer(
+            0,
+            &ALICE,
+            &BOB,
+            5_000,
+            &mut fs,
+            |_| fired.set(true),
+            |_| fired.set(true),
+            |_| fired.set(true),
+        )
+        .unwrap_err();
+    assert_eq!(err, TransferError::Ledger(LedgerError::Overflow));
+    assert!(!fired.get());
+    assert_eq!((ledger, fs), (before_ledger, before_fs));
+}
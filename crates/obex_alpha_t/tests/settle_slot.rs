@@ -0,0 +1,153 @@
+use obex_alpha_t::*;
+
+fn sum(txs: &[SysTx], kind: SysTxKind) -> u128 {
+    txs.iter().filter(|t| t.kind == kind).map(|t| t.amt).sum()
+}
+
+#[test]
+fn credited_equals_emission_plus_fees_minus_burns() {
+    let parts: Vec<[u8; 32]> = (1u8..=20).map(|i| [i; 32]).collect();
+    let mut st = TokenomicsState::default();
+    let mut txs: Vec<SysTx> = Vec::new();
+    for slot in 1..=50u64 {
+        let y = [u8::try_from(slot).unwrap(); 32];
+        let transfers = [500, 2_000, 123_456_789 + u128::from(slot)];
+        let before = txs.len();
+        settle_slot(slot, &y, &transfers, &parts, &mut st, &mut txs).unwrap();
+        assert!(txs[before..].iter().all(|t| t.slot == slot));
+    }
+    let emitted = sum(&txs, SysTxKind::EmissionCredit);
+    let fees = sum(&txs, SysTxKind::EscrowCredit);
+    let burned = sum(&txs, SysTxKind::Burn);
+    assert_eq!(st.treasury_balance_u, sum(&txs, SysTxKind::TreasuryCredit));
+    let credited = st.treasury_balance_u
+        + sum(&txs, SysTxKind::RewardPayout)
+        + st.pool_balance_u
+        + st.fees.fee_escrow_u;
+    assert!(emitted > 0 && fees > 0 && burned > 0);
+    assert_eq!(credited, emitted + fees - burned);
+    assert_eq!(st.emission.total_emitted_u, emitted);
+    assert_eq!(st.total_fees_u, fees);
+    assert_eq!(st.fees.total_burned_u, burned);
+    assert!(st.invariant_ok());
+}
+
+#[test]
+fn settle_slot_matches_sys_tx_builder() {
+    let parts: Vec<[u8; 32]> = (1u8..=5).map(|i| [i; 32]).collect();
+    let y = [9u8; 32];
+    let mut st = TokenomicsState {
+        pool_balance_u: 77_777,
+        ..TokenomicsState::default()
+    };
+    let inputs = SlotSettlementInputs {
+        transfer_amounts: &[1_000, 50_000],
+        part_set_sorted: &parts,
+        pool_balance_before: st.pool_balance_u,
+    };
+    let expected = settle_slot_sys_txs(
+        3,
+        &y,
+        &mut st.emission.clone(),
+        &mut st.fees.clone(),
+        &inputs,
+    )
+    .unwrap();
+    let mut got: Vec<SysTx> = Vec::new();
+    settle_slot(3, &y, &[1_000, 50_000], &parts, &mut st, &mut got).unwrap();
+    assert_eq!(got, expected);
+    assert_eq!(st.emission.last_slot, 3);
+}
+
+#[test]
+fn failed_settlement_leaves_state_untouched() {
+    let mut st = TokenomicsState::default();
+    let mut sink: Vec<SysTx> = Vec::new();
+    assert_eq!(
+        settle_slot(1, &[0u8; 32], &[2_000, 9], &[[1u8; 32]], &mut st, &mut sink),
+        Err(SysTxError::Transfer(TransferError::Fee(
+            FeeError::BelowMinimum
+        )))
+    );
+    assert_eq!(st, TokenomicsState::default());
+    assert!(sink.is_empty());
+}
+
+#[test]
+fn empty_participation_rolls_the_pool_over() {
+    assert_eq!(
+        drp_on_empty_participation(123_456),
+        DrpEmptyAction::Rollover(123_456)
+    );
+
+    let y = [4u8; 32];
+    let mut st = TokenomicsState {
+        pool_balance_u: 500_000,
+        ..TokenomicsState::default()
+    };
+    let mut txs: Vec<SysTx> = Vec::new();
+    settle_slot(1, &y, &[2_000], &[], &mut st, &mut txs).unwrap();
+    assert!(txs.iter().all(|t| t.kind != SysTxKind::RewardPayout));
+    assert_eq!(st.drp_burned_u, 0);
+    let emitted = sum(&txs, SysTxKind::EmissionCredit);
+    let verifier = sum(&txs, SysTxKind::VerifierCredit);
+    assert!(emitted > 0);
+    assert_eq!(st.pool_balance_u, 500_000 + emitted + verifier);
+
+    // The carried pool is paid out by the next slot that has participants.
+    let parts: Vec<[u8; 32]> = (1u8..=3).map(|i| [i; 32]).collect();
+    let carried = st.pool_balance_u;
+    let before = txs.len();
+    settle_slot(2, &y, &[], &parts, &mut st, &mut txs).unwrap();
+    let emitted_2 = sum(&txs[before..], SysTxKind::EmissionCredit);
+    let paid = sum(&txs[before..], SysTxKind::RewardPayout);
+    let burned = sum(&txs[before..], SysTxKind::Burn);
+    assert_eq!(paid + burned + st.pool_balance_u, carried + emitted_2);
+    assert!(paid > carried / 2);
+    assert!(st.invariant_ok());
+}
+
+#[test]
+fn empty_participation_distribution_moves_nothing() {
+    let mut st = TokenomicsState {
+        pool_balance_u: 9_999,
+        ..TokenomicsState::default()
+    };
+    st.distribute_drp(5, &[1u8; 32], &[], |_, _| {
+        panic!("no participant to credit")
+    });
+    assert_eq!((st.pool_balance_u, st.drp_burned_u), (9_999, 0));
+}
+
+#[test]
+fn fee_and_drp_burns_share_one_sys_tx() {
+    let parts: Vec<[u8; 32]> = (1u8..=7).map(|i| [i; 32]).collect();
+    let mut st = TokenomicsState {
+        pool_balance_u: 1_000_003,
+        ..TokenomicsState::default()
+    };
+    let mut txs: Vec<SysTx> = Vec::new();
+    settle_slot(1, &[6u8; 32], &[2_000, 50_000], &parts, &mut st, &mut txs).unwrap();
+    assert!(st.drp_burned_u > 0);
+    assert_eq!(txs.iter().filter(|t| t.kind == SysTxKind::Burn).count(), 1);
+    assert_eq!(sum(&txs, SysTxKind::Burn), st.fees.total_burned_u);
+}
+
+#[test]
+fn overflowing_transfer_fails_settlement() {
+    let mut st = TokenomicsState::default();
+    let mut sink: Vec<SysTx> = Vec::new();
+    assert_eq!(
+        settle_slot(
+            1,
+            &[0u8; 32],
+            &[u128::MAX],
+            &[[1u8; 32]],
+            &mut st,
+            &mut sink
+        ),
+        Err(SysTxError::Transfer(TransferError::DebitOverflow))
+    );
+    assert_eq!(st, TokenomicsState::default());
+    assert!(sink.is_empty());
+}
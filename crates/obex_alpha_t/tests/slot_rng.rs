@@ -0,0 +1,67 @@
+use obex_alpha_t::*;
+
+/// `(y_edge_s, slot, set_len, winners_k, indices)`
+type PickCase = ([u8; 32], u64, usize, usize, &'static [usize]);
+
+#[test]
+fn pick_k_unique_indices_output_is_frozen() {
+    let cases: [PickCase; 4] = [
+        (
+            [9; 32],
+            7,
+            32,
+            16,
+            &[10, 9, 23, 18, 22, 17, 14, 26, 4, 5, 21, 11, 2, 3, 30, 16],
+        ),
+        (
+            [0x5a; 32],
+            9,
+            40,
+            16,
+            &[6, 11, 12, 9, 13, 25, 30, 21, 32, 24, 22, 31, 5, 14, 15, 20],
+        ),
+        ([1; 32], 1, 5, 5, &[2, 3, 1, 4, 0]),
+        (
+            [2; 32],
+            u64::MAX,
+            1_000_003,
+            8,
+            &[32941, 805886, 638920, 60869, 357004, 171333, 370209, 722817],
+        ),
+    ];
+    for (y, slot, n, k, expected) in cases {
+        assert_eq!(pick_k_unique_indices(&y, slot, n, k), expected);
+    }
+}
+
+#[test]
+fn slot_rng_streams_are_deterministic_and_counted() {
+    let y = [0x33; 32];
+    let mut a = SlotRng::new(&y, 12);
+    let mut b = a.clone();
+    let first = a.next_hash();
+    assert_eq!(a.counter(), 1);
+    assert_eq!(
+        b.next_u64(),
+        u64::from_le_bytes(first[..8].try_into().unwrap())
+    );
+    assert_eq!(a.next_hash(), b.next_hash());
+    assert_ne!(a.next_hash(), first);
+    // Slot and beacon both separate streams.
+    assert_ne!(SlotRng::new(&y, 13).next_hash(), first);
+    assert_ne!(SlotRng::new(&[0x34; 32], 12).next_hash(), first);
+}
+
+#[test]
+fn picks_follow_the_slot_rng_stream() {
+    let y = [7u8; 32];
+    let mut rng = SlotRng::new(&y, 3);
+    let mut expected = Vec::new();
+    while expected.len() < 10 {
+        let idx = usize::try_from(rng.next_u64() % 64).unwrap();
+        if !expected.contains(&idx) {
+            expected.push(idx);
+        }
+    }
+    assert_eq!(pick_k_unique_indices(&y, 3, 64, 10), expected);
+}
@@ -0,0 +1,97 @@
+use obex_alpha_t::*;
+
+fn golden_path() -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join("sys_tx_block_slot7.bin")
+}
+
+fn mixed_block() -> Vec<SysTx> {
+    let slot = 7;
+    let tx = |kind, pk: u8, amt| SysTx {
+        kind,
+        slot,
+        pk: [pk; 32],
+        amt,
+    };
+    let txs = vec![
+        tx(SysTxKind::RewardPayout, 3, 500),
+        tx(SysTxKind::Burn, 0, 40),
+        tx(SysTxKind::EscrowCredit, 0, 1_000),
+        tx(SysTxKind::RewardPayout, 4, 500),
+        tx(SysTxKind::VerifierCredit, 0, 400),
+        tx(SysTxKind::EmissionCredit, 0, 1_250),
+        tx(SysTxKind::TreasuryCredit, 0, 160),
+    ];
+    canonical_sys_tx_order(txs, &[0x5a; 32])
+}
+
+#[test]
+fn mixed_block_roundtrips_against_golden_bytes() {
+    let block = mixed_block();
+    let bytes = enc_sys_tx_block(&block);
+    assert_eq!(bytes[..4], 7u32.to_le_bytes());
+    for (i, tx) in block.iter().enumerate() {
+        let entry = &bytes[4 + i * 89..4 + (i + 1) * 89];
+        assert_eq!(entry, enc_sys_tx(tx).as_slice());
+    }
+    let golden = std::fs::read(golden_path()).expect("read golden block");
+    assert_eq!(bytes, golden, "sys tx block wire bytes must be stable");
+
+    let decoded = dec_sys_tx_block(&golden).expect("decode block");
+    assert_eq!(decoded, block);
+    assert!(is_canonically_ordered(&decoded, &[0x5a; 32]));
+    assert_eq!(enc_sys_tx_block(&decoded), golden);
+}
+
+#[test]
+fn empty_block_is_a_zero_count() {
+    let bytes = enc_sys_tx_block(&[]);
+    assert_eq!(bytes, vec![0u8; 4]);
+    assert!(dec_sys_tx_block(&bytes).unwrap().is_empty());
+}
+
+#[test]
+fn truncated_or_padded_block_errors() {
+    let bytes = enc_sys_tx_block(&mixed_block());
+    for cut in [0, 3, 4, 50, bytes.len() - 1] {
+        assert!(matches!(
+            dec_sys_tx_block(&bytes[..cut]),
+            Err(SysTxCodecError::Short)
+        ));
+    }
+    let mut padded = bytes.clone();
+    padded.push(0);
+    assert!(matches!(
+        dec_sys_tx_block(&padded),
+        Err(SysTxCodecError::Trailing)
+    ));
+    // A count larger than the entries present is short, not an oversized allocation.
+    let mut overclaimed = bytes;
+    overclaimed[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(matches!(
+        dec_sys_tx_block(&overclaimed),
+        Err(SysTxCodecError::Short)
+    ));
+}
+
+#[test]
+fn bad_kind_inside_block_errors() {
+    let mut bytes = enc_sys_tx_block(&mixed_block());
+    bytes[4 + 89 + 32] = 0xFF;
+    assert!(matches!(
+        dec_sys_tx_block(&bytes),
+        Err(SysTxCodecError::BadKind)
+    ));
+}
+
+#[test]
+fn bad_tag_inside_block_errors() {
+    let mut bytes = enc_sys_tx_block(&mixed_block());
+    bytes[4 + 89] ^= 1;
+    assert!(matches!(
+        dec_sys_tx_block(&bytes),
+        Err(SysTxCodecError::BadTag)
+    ));
+}
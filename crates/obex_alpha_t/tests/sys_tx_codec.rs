@@ -0,0 +1,57 @@
+mod common;
+
+use obex_alpha_t::*;
+
+use common::ALL_KINDS;
+
+#[test]
+fn all_valid_kinds_roundtrip_byte_for_byte() {
+    for (i, kind) in ALL_KINDS.into_iter().enumerate() {
+        let tx = SysTx {
+            kind,
+            slot: 7 + i as u64,
+            pk: [u8::try_from(i).unwrap(); 32],
+            amt: 1_000 * (i as u128 + 1),
+        };
+        let b = enc_sys_tx(&tx);
+        assert_eq!(b[32], kind as u8);
+        let tx2 = dec_sys_tx(&b).expect("decode");
+        assert_eq!(tx2, tx);
+        assert_eq!(enc_sys_tx(&tx2), b);
+    }
+}
+
+#[test]
+fn unknown_kind_bytes_rejected() {
+    let tx = SysTx {
+        kind: SysTxKind::Burn,
+        slot: 1,
+        pk: [1u8; 32],
+        amt: 5,
+    };
+    let b = enc_sys_tx(&tx);
+    for kind_byte in [6u8, 99, 0xFF] {
+        let mut bad = b.clone();
+        bad[32] = kind_byte;
+        assert!(matches!(dec_sys_tx(&bad), Err(SysTxCodecError::BadKind)));
+    }
+}
+
+#[test]
+fn foreign_domain_tag_rejected() {
+    let tx = SysTx {
+        kind: SysTxKind::Burn,
+        slot: 1,
+        pk: [1u8; 32],
+        amt: 5,
+    };
+    let b = enc_sys_tx(&tx);
+    for i in [0usize, 31] {
+        let mut bad = b.clone();
+        bad[i] ^= 0x80;
+        assert!(matches!(dec_sys_tx(&bad), Err(SysTxCodecError::BadTag)));
+    }
+    let mut zeroed = b;
+    zeroed[..32].fill(0);
+    assert!(matches!(dec_sys_tx(&zeroed), Err(SysTxCodecError::BadTag)));
+}
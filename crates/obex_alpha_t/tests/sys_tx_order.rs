@@ -0,0 +1,48 @@
+use obex_alpha_t::*;
+
+fn tx(kind: SysTxKind, pk: u8, amt: u128) -> SysTx {
+    SysTx {
+        kind,
+        slot: 7,
+        pk: [pk; 32],
+        amt,
+    }
+}
+
+#[test]
+fn canonical_list_passes_and_swapped_pair_fails() {
+    let y = [5u8; 32];
+    let txs = canonical_sys_tx_order(
+        vec![
+            tx(SysTxKind::RewardPayout, 1, 10),
+            tx(SysTxKind::Burn, 0, 3),
+            tx(SysTxKind::RewardPayout, 2, 10),
+            tx(SysTxKind::EscrowCredit, 0, 9),
+            tx(SysTxKind::RewardPayout, 3, 10),
+            tx(SysTxKind::EmissionCredit, 0, 100),
+        ],
+        &y,
+    );
+    assert!(is_canonically_ordered(&txs, &y));
+    assert!(is_canonically_ordered(&[], &y));
+
+    let keys: Vec<_> = txs.iter().map(|t| sys_tx_order_key(t, &y)).collect();
+    assert!(keys.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(keys[0], (0, ([0u8; 32], [0u8; 32])));
+
+    for i in 0..txs.len() - 1 {
+        let mut swapped = txs.clone();
+        swapped.swap(i, i + 1);
+        assert!(!is_canonically_ordered(&swapped, &y), "swap at {i}");
+    }
+}
+
+#[test]
+fn equal_keys_are_canonical_in_either_order() {
+    let y = [0u8; 32];
+    let a = [tx(SysTxKind::Burn, 0, 1), tx(SysTxKind::Burn, 0, 2)];
+    let b = [a[1], a[0]];
+    assert!(is_canonically_ordered(&a, &y));
+    assert!(is_canonically_ordered(&b, &y));
+    assert_eq!(canonical_sys_tx_order(b.to_vec(), &y), b.to_vec());
+}
@@ -0,0 +1,109 @@
+use obex_alpha_t::*;
+
+fn inputs(parts: &[[u8; 32]]) -> SlotSettlementInputs<'_> {
+    SlotSettlementInputs {
+        transfer_amounts: &[500, 2_000, 123_456_789],
+        part_set_sorted: parts,
+        pool_balance_before: 1_000_000,
+    }
+}
+
+#[test]
+fn settled_set_validates_across_kinds_and_tampering_fails() {
+    let slot = 42u64;
+    let y = [7u8; 32];
+    let parts: Vec<[u8; 32]> = (1u8..=20).map(|i| [i; 32]).collect();
+    let inp = inputs(&parts);
+    let em = EmissionState::default();
+    let fs = FeeSplitState::default();
+
+    let txs = settle_slot_sys_txs(slot, &y, &mut em.clone(), &mut fs.clone(), &inp).unwrap();
+    assert!(txs.iter().any(|t| t.kind == SysTxKind::EscrowCredit));
+    assert!(txs.iter().any(|t| t.kind == SysTxKind::EmissionCredit));
+    assert!(txs.iter().any(|t| t.kind == SysTxKind::RewardPayout));
+    assert!(txs.iter().all(|t| t.slot == slot));
+    assert_eq!(
+        validate_sys_txs_for_slot(slot, &y, &txs, &em, &fs, &inp),
+        Ok(())
+    );
+
+    let mut rotated = txs.clone();
+    rotated.rotate_left(1);
+    assert_eq!(
+        validate_sys_txs_for_slot(slot, &y, &rotated, &em, &fs, &inp),
+        Ok(())
+    );
+
+    let mut bad = txs.clone();
+    bad[0].amt += 1;
+    assert_eq!(
+        validate_sys_txs_for_slot(slot, &y, &bad, &em, &fs, &inp),
+        Err(SysTxError::Mismatch(0))
+    );
+
+    assert_eq!(
+        validate_sys_txs_for_slot(slot, &y, &txs[1..], &em, &fs, &inp),
+        Err(SysTxError::CountMismatch {
+            expected: txs.len(),
+            got: txs.len() - 1
+        })
+    );
+}
+
+#[test]
+fn sub_minimum_transfer_is_an_error() {
+    let inp = SlotSettlementInputs {
+        transfer_amounts: &[9],
+        ..SlotSettlementInputs::default()
+    };
+    assert_eq!(
+        validate_sys_txs_for_slot(
+            1,
+            &[0u8; 32],
+            &[],
+            &EmissionState::default(),
+            &FeeSplitState::default(),
+            &inp
+        ),
+        Err(SysTxError::Transfer(TransferError::Fee(
+            FeeError::BelowMinimum
+        )))
+    );
+}
+
+#[test]
+fn tied_keys_validate_in_either_order() {
+    let slot = 9u64;
+    let y = [3u8; 32];
+    let parts: Vec<[u8; 32]> = (1u8..=5).map(|i| [i; 32]).collect();
+    let inp = inputs(&parts);
+    let em = EmissionState::default();
+    let fs = FeeSplitState::default();
+    let txs = settle_slot_sys_txs(slot, &y, &mut em.clone(), &mut fs.clone(), &inp).unwrap();
+
+    // A stray BURN ties with the settled one on the order key; both orders are canonical and
+    // both must get the same verdict.
+    let burn_at = txs.iter().position(|t| t.kind == SysTxKind::Burn).unwrap();
+    let stray = SysTx {
+        pk: [0xAA; 32],
+        ..txs[burn_at]
+    };
+    let mut a = txs.clone();
+    a.insert(burn_at, stray);
+    let mut b = txs.clone();
+    b.insert(burn_at + 1, stray);
+    assert!(is_canonically_ordered(&a, &y) && is_canonically_ordered(&b, &y));
+    let verdict = validate_sys_txs_for_slot(slot, &y, &a, &em, &fs, &inp);
+    assert!(verdict.is_err());
+    assert_eq!(
+        validate_sys_txs_for_slot(slot, &y, &b, &em, &fs, &inp),
+        verdict
+    );
+
+    let mut swapped = txs.clone();
+    swapped.swap(0, txs.len() - 1);
+    assert_eq!(
+        validate_sys_txs_for_slot(slot, &y, &swapped, &em, &fs, &inp),
+        Ok(())
+    );
+}
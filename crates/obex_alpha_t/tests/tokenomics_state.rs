@@ -0,0 +1,61 @@
+use obex_alpha_t::*;
+
+#[test]
+fn epoch_roll_after_burns_uses_reduced_effective_supply() {
+    let mut st = TokenomicsState::default();
+    st.roll_epoch_if_needed(NLB_EPOCH_SLOTS);
+    assert_eq!(st.fees.nlb.b_pct, 20);
+
+    let burned = 600_000 * UOBX_PER_OBX;
+    st.burn(burned);
+    assert_eq!(st.fees.total_burned_u, burned);
+    assert_eq!(st.effective_supply_u(), TOTAL_SUPPLY_UOBX - burned);
+
+    // Splits are epoch-stable: the burn only shows at the next epoch boundary.
+    st.roll_epoch_if_needed(2 * NLB_EPOCH_SLOTS - 1);
+    assert_eq!(st.fees.nlb.b_pct, 20);
+    st.roll_epoch_if_needed(2 * NLB_EPOCH_SLOTS);
+    assert_eq!(st.fees.nlb.eff_supply_snapshot_u, st.effective_supply_u());
+    assert_eq!(
+        (st.fees.nlb.v_pct, st.fees.nlb.t_pct, st.fees.nlb.b_pct),
+        (45, 40, 15)
+    );
+}
+
+#[test]
+fn methods_keep_pool_treasury_and_burns_consistent() {
+    let parts: Vec<[u8; 32]> = (1u8..=10).map(|i| [i; 32]).collect();
+    let mut st = TokenomicsState::default();
+    let mut paid_out = 0u128;
+    for slot in 1..=20u64 {
+        let emitted = st.on_slot_emission(u128::from(slot));
+        assert!(emitted > 0);
+        for amt in [500u128, 250_000, 9_999_999] {
+            st.process_transfer(slot, u128::MAX, amt, |_| {}, |_| {})
+                .unwrap();
+        }
+        st.distribute_drp(slot, &[u8::try_from(slot).unwrap(); 32], &parts, |_, a| {
+            paid_out += a;
+        });
+        assert!(st.invariant_ok(), "slot {slot}");
+    }
+    assert!(st.drp_burned_u > 0);
+    assert_eq!(
+        st.emission.total_emitted_u + st.total_fees_u,
+        paid_out
+            + st.pool_balance_u
+            + st.treasury_balance_u
+            + st.fees.fee_escrow_u
+            + st.fees.total_burned_u
+    );
+
+    let before = st.clone();
+    assert_eq!(
+        st.process_transfer(21, 5, 1_000, |_| {}, |_| {}),
+        Err(TransferError::InsufficientBalance {
+            need: 1_010,
+            have: 5
+        })
+    );
+    assert_eq!(st, before);
+}
@@ -0,0 +1,91 @@
+use obex_alpha_t::*;
+
+/// Callback trace: (hook, amount) with d/r/e/v/t/b = debit/recipient/escrow/verifier/treasury/burn.
+type Calls = Vec<(char, u128)>;
+
+fn run_checked(
+    fs: &mut FeeSplitState,
+    balance: u128,
+    amount: u128,
+) -> (Result<(u128, u128), TransferError>, Calls) {
+    let mut calls = Vec::new();
+    let calls_ref = std::cell::RefCell::new(&mut calls);
+    let rec = |c: char| {
+        let calls_ref = &calls_ref;
+        move |a: u128| calls_ref.borrow_mut().push((c, a))
+    };
+    let res = try_process_transfer(
+        3,
+        balance,
+        amount,
+        fs,
+        rec('d'),
+        rec('r'),
+        rec('e'),
+        rec('v'),
+        rec('t'),
+        rec('b'),
+    );
+    (res, calls)
+}
+
+#[test]
+fn matches_process_transfer_on_valid_input() {
+    let mut fs_a = FeeSplitState::default();
+    let mut fs_b = FeeSplitState::default();
+    for amount in [10u128, 1_000, 1_001, 250_000, 9_999_999] {
+        let mut calls = Vec::new();
+        let calls_ref = std::cell::RefCell::new(&mut calls);
+        let rec = |c: char| {
+            let calls_ref = &calls_ref;
+            move |a: u128| calls_ref.borrow_mut().push((c, a))
+        };
+        let out = process_transfer(
+            3,
+            u128::MAX,
+            amount,
+            &mut fs_a,
+            rec('d'),
+            rec('r'),
+            rec('e'),
+            rec('v'),
+            rec('t'),
+            rec('b'),
+        );
+        let (res, calls_b) = run_checked(&mut fs_b, u128::MAX, amount);
+        assert_eq!(res, Ok(out));
+        assert_eq!(calls_b, calls);
+        assert_eq!(fs_a, fs_b);
+    }
+}
+
+#[test]
+fn overflow_is_reported_not_saturated() {
+    let mut fs = FeeSplitState::default();
+    let before = fs.clone();
+    // amount + ceil(amount / 100) exceeds u128::MAX; saturation would hide it.
+    let (res, calls) = run_checked(&mut fs, u128::MAX, u128::MAX - 5);
+    assert_eq!(res, Err(TransferError::DebitOverflow));
+    assert!(calls.is_empty());
+    assert_eq!(fs, before);
+}
+
+#[test]
+fn insufficient_balance_and_sub_minimum_are_distinct_errors() {
+    let mut fs = FeeSplitState::default();
+    let before = fs.clone();
+    let (res, calls) = run_checked(&mut fs, 1_009, 1_000);
+    assert_eq!(
+        res,
+        Err(TransferError::InsufficientBalance {
+            need: 1_010,
+            have: 1_009
+        })
+    );
+    assert!(calls.is_empty());
+    let (res, _) = run_checked(&mut fs, 1_000, 9);
+    assert_eq!(res, Err(TransferError::Fee(FeeError::BelowMinimum)));
+    assert_eq!(fs, before);
+    let (res, _) = run_checked(&mut fs, 1_010, 1_000);
+    assert_eq!(res, Ok((1_010, 10)));
+}
@@ -0,0 +1,58 @@
+use obex_alpha_t::*;
+
+#[test]
+fn preview_matches_process_transfer_without_mutating() {
+    let mut fs = FeeSplitState::default();
+    let slots = [
+        0u64,
+        1,
+        NLB_EPOCH_SLOTS,
+        NLB_EPOCH_SLOTS + 5,
+        3 * NLB_EPOCH_SLOTS,
+    ];
+    let amounts = [10u128, 999, 1_000, 1_001, 12_345, 250_000, 9_999_999];
+    for (i, &amount) in amounts.iter().cycle().take(28).enumerate() {
+        let slot = slots[i * slots.len() / 28];
+        let before = fs.clone();
+        let preview = preview_transfer(slot, amount, &fs);
+        assert_eq!(fs, before, "preview must not mutate state");
+
+        let (mut v, mut t, mut b) = (0u128, 0u128, 0u128);
+        let (total_debit, fee) = process_transfer(
+            slot,
+            u128::MAX,
+            amount,
+            &mut fs,
+            |_| {},
+            |_| {},
+            |_| {},
+            |x| v += x,
+            |x| t += x,
+            |x| b += x,
+        );
+        assert_eq!(
+            preview,
+            TransferPreview {
+                total_debit_u: total_debit,
+                fee_u: fee,
+                rel_v: v,
+                rel_t: t,
+                rel_b: b,
+            },
+            "slot {slot} amount {amount}"
+        );
+    }
+}
+
+#[test]
+fn preview_reports_fee_schedule() {
+    let fs = FeeSplitState::default();
+    let flat = preview_transfer(0, 500, &fs);
+    assert_eq!(
+        (flat.fee_u, flat.total_debit_u),
+        (FLAT_FEE_U, 500 + FLAT_FEE_U)
+    );
+    let pct = preview_transfer(0, 250_000, &fs);
+    assert_eq!((pct.fee_u, pct.total_debit_u), (2_500, 252_500));
+    assert!(pct.rel_v + pct.rel_t + pct.rel_b <= pct.fee_u);
+}
@@ -1,23 +1,27 @@
 //! Basic usage for the Obex Engine I (OE1) implementation.
-//! 
+//!
 //! This implementation shows how to:
 //! 1. Implement a VRF (Verifiable Random Function)
 //! 2. Use the Obex Engine I for epoch hash computation
 //! 3. Generate identity signatures and derive seeds
 //! 4. Create and verify tickets
 
-use obex_engine_i::{
-    compute_epoch_hash, verify_registration, mk_chain_vrf,
-    derive_seed_and_key, build_m,
-    types::{ChainId, EpochNonce, VrfProof, VrfOutput, EpochHash, N_LEAVES, MerkleRoot, Registration}, Vrf,
-};
-use obex_engine_i::ser::build_alpha;
+use ed25519_dalek::{Signer, SigningKey};
+use obex_engine_i::challenge::derive_challenge_indices;
 use obex_engine_i::dataset::compute_leaf;
 use obex_engine_i::ecvrf_ristretto255::EcVrfRistretto255;
-use obex_engine_i::ecvrf_traits::{Vrf as NewVrf, VrfError, VrfOutput as EcVrfOutput, VrfProof as EcVrfProof};
-use obex_engine_i::challenge::derive_challenge_indices;
+use obex_engine_i::ecvrf_traits::{
+    Vrf as NewVrf, VrfError, VrfOutput as EcVrfOutput, VrfProof as EcVrfProof,
+};
+use obex_engine_i::ser::build_alpha;
 use obex_engine_i::ticket::{create_ticket, is_ticket_valid_time, TicketParams};
-use ed25519_dalek::{SigningKey, Signer};
+use obex_engine_i::{
+    build_m, compute_epoch_hash, derive_seed_and_key, mk_chain_vrf,
+    types::{
+        ChainId, EpochHash, EpochNonce, MerkleRoot, Registration, VrfOutput, VrfProof, N_LEAVES,
+    },
+    verify_registration, Vrf,
+};
 use rand_core::OsRng;
 
 /// RFC 9381 ECVRF-RISTRETTO255-SHA512 implementation.
@@ -32,10 +36,10 @@ impl ProductionVrf {
         // Create a new VRF instance with real cryptographic capabilities
         // This enables both proving and verification with actual ECVRF operations
         let vrf_impl = EcVrfRistretto255::new();
-        
+
         Self { vrf_impl }
     }
-    
+
     fn new_with_real_crypto() -> Self {
         // Create a VRF instance with real cryptographic security
         // Using cryptographically secure random number generation
@@ -43,11 +47,12 @@ impl ProductionVrf {
             vrf_impl: EcVrfRistretto255::new(), // Uses OsRng for secure key generation
         }
     }
-    
 
-    
     fn secret_key_bytes(&self) -> [u8; 32] {
-        self.vrf_impl.secret_key_bytes()
+        // `Zeroizing<[u8; 32]>` with the `zeroize` feature; copy out either way.
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&self.vrf_impl.secret_key_bytes()[..]);
+        out
     }
 }
 
@@ -55,12 +60,12 @@ impl NewVrf for ProductionVrf {
     fn prove(&self, alpha: &[u8]) -> Result<([u8; 80], EcVrfOutput), VrfError> {
         self.vrf_impl.prove(alpha)
     }
-    
+
     fn verify(&self, alpha: &[u8], proof: &EcVrfProof) -> Result<EcVrfOutput, VrfError> {
         // Use the actual RFC 9381 ECVRF implementation
         self.vrf_impl.verify(alpha, proof)
     }
-    
+
     fn public_key(&self) -> [u8; 32] {
         self.vrf_impl.public_key()
     }
@@ -70,38 +75,38 @@ impl NewVrf for ProductionVrf {
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Obex Engine I (OE1) - Basic Usage Example");
     println!("=======================================");
-    
+
     // Step 1: Setup chain parameters
     let chain_id = ChainId([1u8; 32]);
     let epoch_number = 42u64;
     let epoch_nonce = EpochNonce([2u8; 32]);
-    
+
     println!("\n1. Chain Parameters:");
     println!("   Chain ID: {:?}", &chain_id.0[0..8]);
     println!("   Epoch Number: {epoch_number}");
     println!("   Epoch Nonce: {:?}", &epoch_nonce.0[0..8]);
-    
+
     // Step 2: Create VRF with real cryptographic capabilities
     let production_vrf = ProductionVrf::new_with_real_crypto();
     let vrf_public_key = production_vrf.public_key();
     let _secret_key_bytes = production_vrf.secret_key_bytes();
     let alpha = build_alpha(&chain_id, epoch_number, &epoch_nonce);
-    
+
     // Generate a REAL VRF proof using actual cryptographic operations
     let (pi, y) = match production_vrf.prove(&alpha) {
         Ok((proof, output)) => (proof, output.0),
         Err(e) => return Err(format!("VRF proving failed: {e:?}").into()),
     };
-    
+
     let vrf = mk_chain_vrf(vrf_public_key);
     println!("   Using ECVRF-RISTRETTO255-SHA512 implementation with real cryptography");
-    
+
     println!("\n2. VRF Computation (Real Cryptography):");
     println!("   Alpha length: {} bytes", alpha.len());
     println!("   VRF Public Key: {:02x?}", &vrf_public_key[0..8]);
     println!("   VRF Output (y): {:?}", &y[0..8]);
     println!("   VRF Proof (π): {:?}", &pi[0..8]);
-    
+
     // Verify VRF using ChainVrf (note: this is a stub implementation)
     let vrf_proof_wrapped = VrfProof(pi);
     match vrf.verify(&alpha, &vrf_proof_wrapped) {
@@ -113,7 +118,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("   ! VRF verification failed (expected with stub): {err:?}");
         }
     }
-    
+
     // Also test that zero proofs are properly rejected
     let zero_proof = VrfProof([0u8; 80]);
     match vrf.verify(&alpha, &zero_proof) {
@@ -124,55 +129,68 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("   ✓ Zero proof correctly rejected (security check passed)");
         }
     }
-    
+
     // Step 3: Compute epoch hash
     let vrf_output_wrapped = VrfOutput(y);
     let vrf_proof_wrapped = VrfProof(pi);
-    let epoch_hash = compute_epoch_hash(&chain_id, epoch_number, &epoch_nonce, &vrf_output_wrapped, &vrf_proof_wrapped);
+    let epoch_hash = compute_epoch_hash(
+        &chain_id,
+        epoch_number,
+        &epoch_nonce,
+        &vrf_output_wrapped,
+        &vrf_proof_wrapped,
+    );
     println!("\n3. Epoch Hash: {:?}", &epoch_hash.0[0..8]);
-    
+
     // Step 4: Identity binding and signature
     let signing_key = SigningKey::generate(&mut OsRng);
     let verifying_key = signing_key.verifying_key();
-    
+
     let epoch_hash_wrapped = EpochHash(epoch_hash.0);
     let m = build_m(&epoch_hash_wrapped, &epoch_nonce, &verifying_key);
     let identity_sig = signing_key.sign(&m);
-    
+
     println!("\n4. Identity Binding:");
     println!("   Public Key: {:?}", &verifying_key.as_bytes()[0..8]);
     println!("   Message (M) length: {} bytes", m.len());
-    println!("   Identity Signature: {:?}", &identity_sig.to_bytes()[0..8]);
-    
+    println!(
+        "   Identity Signature: {:?}",
+        &identity_sig.to_bytes()[0..8]
+    );
+
     // Verify identity signature
     if verifying_key.verify_strict(&m, &identity_sig).is_err() {
         return Err("Identity signature verification failed".into());
     }
     println!("   Identity signature verification successful");
-    
+
     // Step 5: Derive seed and key
     let (seed, k) = derive_seed_and_key(&m, &identity_sig);
     println!("\n5. Seed and Key Derivation:");
     println!("   Seed: {:?}", &seed[0..8]);
     println!("   Key (k): {:?}", &k[0..8]);
-    
+
     // Step 6: Dataset generation (simplified for demonstration)
     // Note: In practice, this would generate the full 2^26-leaf dataset
     println!("\n6. Dataset Generation:");
-    println!("   Dataset size: {} bytes ({} leaves)", N_LEAVES * 32, N_LEAVES);
-    
+    println!(
+        "   Dataset size: {} bytes ({} leaves)",
+        N_LEAVES * 32,
+        N_LEAVES
+    );
+
     // Generate a small sample of leaves for demonstration
     let dataset_key = [42u8; 32]; // Example key
     let sample_leaf = compute_leaf(&dataset_key, 0);
     println!("   Sample leaf at index 0: {:?}", &sample_leaf[0..8]);
-    
+
     // Create a dummy root for demonstration
     let root = [0u8; 32]; // Simplified root
     println!("   Sample Merkle Root: {:?}", &root[0..8]);
-    
+
     // Step 7: Challenge derivation
     println!("\n7. Challenge Derivation:");
-    
+
     // Create registration for challenge derivation
     let root_wrapped = MerkleRoot(root);
     let registration = Registration {
@@ -186,34 +204,39 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         sig: &identity_sig,
         root: &root_wrapped,
     };
-    
+
     // Derive challenge indices from registration
     match derive_challenge_indices(&registration, 1u32) {
         Ok(indices) => {
-            println!("   Challenge indices: {:?}", &indices[0..5.min(indices.len())]);
+            println!(
+                "   Challenge indices: {:?}",
+                &indices[0..5.min(indices.len())]
+            );
             println!("   Total challenges: {}", indices.len());
-        },
+        }
         Err(e) => println!("   ! Challenge derivation failed: {e:?}"),
     }
-    
+
     // Note: Merkle path generation would be needed for full verification
     println!("   Challenge indices generated for verification");
-    
+
     // Step 8: Registration verification with succinct proofs
     println!("\n8. Registration Verification:");
-    
+
     // Use the registration already created above for verification
-    
+
     // Perform basic registration verification (will fail with dummy VRF proof)
     let empty_openings = Vec::new();
     match verify_registration(&registration, 1u32, &vrf, &root_wrapped, &empty_openings) {
         Ok(()) => println!("   Registration verification successful"),
-        Err(e) => println!("   ! Registration verification failed (expected with zero proof): {e:?}"),
+        Err(e) => {
+            println!("   ! Registration verification failed (expected with zero proof): {e:?}")
+        }
     }
-    
+
     // Step 9: Create and verify a ticket
     println!("\n9. Ticket Creation and Verification:");
-    
+
     // Create ticket using the create_ticket function
     let ticket = create_ticket(TicketParams {
         chain_id: chain_id.0,
@@ -222,20 +245,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         epoch_nonce: epoch_nonce.0,
         pk: *verifying_key.as_bytes(),
         root,
-        valid_from: Some(100), // valid_from
+        valid_from: Some(100),    // valid_from
         valid_duration_secs: 100, // valid_duration_secs (100 seconds)
     });
-    
+
     println!("   Ticket created successfully");
-    println!("   Ticket valid from slot {} to {}", ticket.valid_from, ticket.valid_to);
-    
+    println!(
+        "   Ticket valid from slot {} to {}",
+        ticket.valid_from, ticket.valid_to
+    );
+
     // Verify ticket time validity
     let is_valid_150 = is_ticket_valid_time(&ticket, Some(150));
     let is_valid_300 = is_ticket_valid_time(&ticket, Some(300));
-    
+
     println!("   ✓ Ticket valid at slot 150: {is_valid_150}");
     println!("   ✓ Ticket valid at slot 300: {is_valid_300}");
-    
+
     println!("\nAll operations completed successfully!");
     println!("\nNote: This implementation shows the Obex Engine I interface.");
     println!("The VRF now uses GENUINE cryptographic operations with random secret keys.");
@@ -244,7 +270,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("where only challenged leaves and their Merkle paths are verified.");
     println!("\n✓ All cryptographic operations use real RFC 9381 ECVRF with secure randomness!");
     println!("✓ Secret keys generated using cryptographically secure OsRng!");
-    
+
     Ok(())
 }
-
@@ -0,0 +1,155 @@
+use crate::{
+    errors::Step1Error,
+    hashers::build_challenge_seed,
+    types::{Registration, CHALLENGE_COUNT, N_LEAVES},
+};
+use sha3::{Digest, Sha3_256};
+
+/// Derive challenge indices using uniform rejection sampling.
+/// Derive challenge indices from registration data.
+///
+/// Consensus: candidates below the largest multiple of `N_LEAVES` not exceeding 2^32 are
+/// accepted and reduced mod `N_LEAVES` (see `sample_indices`). Earlier releases accepted only
+/// `candidate < N_LEAVES` without reduction, so for the same seed they derive different
+/// indices; registrations from either rule do not verify under the other.
+///
+/// # Errors
+///
+/// Returns `Step1Error` if the challenge seed generation fails or insufficient valid indices are found.
+pub fn derive_challenge_indices(reg: &Registration, _epoch: u32) -> Result<Vec<u32>, Step1Error> {
+    let seed = build_challenge_seed(reg.epoch_hash, reg.epoch_nonce, reg.pk, reg.root);
+    sample_indices(&seed, N_LEAVES, CHALLENGE_COUNT)
+}
+
+/// Draw `count` indices in `[0, n)` from `SHA3_256(seed || LE64(counter))`.
+///
+/// Each candidate is the first 4 digest bytes as LE `u32`; candidates at or above the largest
+/// multiple of `n` not exceeding 2^32 are rejected and the rest reduced mod `n`, so every
+/// index is equally likely whether or not `n` divides 2^32.
+fn sample_indices(seed: &[u8; 32], n: u32, count: usize) -> Result<Vec<u32>, Step1Error> {
+    if n == 0 {
+        return Err(Step1Error::ChallengeDerivationError);
+    }
+    let span = 1u64 << 32;
+    let limit = span - span % u64::from(n);
+    let mut indices = Vec::with_capacity(count);
+    let mut counter = 0u64;
+
+    while indices.len() < count {
+        let mut hasher = Sha3_256::new();
+        hasher.update(seed);
+        hasher.update(counter.to_le_bytes());
+        let digest = hasher.finalize();
+        let bytes: [u8; 32] = digest.into();
+        // Extract 4 bytes and interpret as u32
+        let candidate = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+        // Uniform rejection sampling: accept only below the largest multiple of n
+        if u64::from(candidate) < limit {
+            indices.push(candidate % n);
+        }
+
+        counter += 1;
+
+        // Safety check to prevent infinite loops
+        if counter > 1_000_000 {
+            return Err(Step1Error::ChallengeDerivationFailed);
+        }
+    }
+
+    Ok(indices)
+}
+
+/// Verify that challenge indices are properly derived.
+/// Verify that challenge indices match the expected derivation.
+///
+/// # Errors
+///
+/// Returns `Step1Error` if the derived indices don't match the provided indices.
+pub fn verify_challenge_indices(
+    reg: &Registration,
+    epoch: u32,
+    indices: &[u32],
+) -> Result<(), Step1Error> {
+    if indices.len() != CHALLENGE_COUNT {
+        return Err(Step1Error::InvalidLength {
+            expected: CHALLENGE_COUNT,
+            got: indices.len(),
+        });
+    }
+
+    let expected = derive_challenge_indices(reg, epoch)?;
+    if indices != expected {
+        return Err(Step1Error::ChallengeIndicesMismatch);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::cast_precision_loss)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sampling_is_uniform_for_non_power_of_two_n() {
+        // 3 * 2^30 leaves the top quarter of u32 space above the threshold
+        const N: u32 = 3 << 30;
+        const DRAWS: usize = 120_000;
+        const BUCKETS: u32 = 3;
+        let indices = sample_indices(&[0x5a; 32], N, DRAWS).unwrap();
+        assert_eq!(indices.len(), DRAWS);
+        let mut counts = [0u64; BUCKETS as usize];
+        for &i in &indices {
+            assert!(i < N);
+            counts[(i / (N / BUCKETS)) as usize] += 1;
+        }
+        // Chi-square with 2 degrees of freedom; 20 is far beyond the 0.9999 quantile (~18.4).
+        let expected = DRAWS as f64 / f64::from(BUCKETS);
+        let chi2: f64 = counts
+            .iter()
+            .map(|&c| (c as f64 - expected).powi(2) / expected)
+            .sum();
+        assert!(chi2 < 20.0, "chi2 = {chi2}, counts = {counts:?}");
+    }
+
+    #[test]
+    fn sampling_is_uniform_for_small_n() {
+        const N: u32 = 1_000;
+        const DRAWS: usize = 200_000;
+        let indices = sample_indices(&[0xa5; 32], N, DRAWS).unwrap();
+        let mut counts = vec![0u64; N as usize];
+        for &i in &indices {
+            counts[i as usize] += 1;
+        }
+        // df = 999: mean 999, sd ~44.7; allow 6 sd.
+        let expected = DRAWS as f64 / f64::from(N);
+        let chi2: f64 = counts
+            .iter()
+            .map(|&c| (c as f64 - expected).powi(2) / expected)
+            .sum();
+        assert!(chi2 < 1_270.0, "chi2 = {chi2}");
+    }
+
+    #[test]
+    fn production_indices_are_pinned() {
+        // N_LEAVES divides 2^32, so every candidate is accepted: index t is
+        // LE32(SHA3_256(seed || LE64(t))[..4]) mod 2^26. Changing the sampler changes these.
+        let expected: [u32; CHALLENGE_COUNT] = [
+            27_685_397, 14_037_593, 5_537_386, 55_892_007, 59_944_052, 9_708_614, 29_900_148,
+            2_131_620, 61_561_671, 26_224_346, 43_468_853, 4_829_197, 43_584_734, 20_189_004,
+            43_914_052, 56_369_059, 30_306_807, 26_402_457, 54_923_440, 41_153_180, 48_025_875,
+            14_363_786, 45_228_614, 64_044_151, 58_120_551, 34_025_727, 37_460_151, 60_177_889,
+            16_658_517, 37_458_275, 44_495_467, 48_919_508,
+        ];
+        assert_eq!(
+            sample_indices(&[0x11; 32], N_LEAVES, CHALLENGE_COUNT).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn zero_leaf_count_is_rejected() {
+        assert!(sample_indices(&[0; 32], 0, 1).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    errors::Step1Error,
+    merkle::parent_hash,
+    types::{MerklePath, MerkleRoot, N_LEAVES, N_LOG2},
+};
+use core::ops::Range;
+use sha3::{Digest, Sha3_256};
+
+/// Streaming builder yields leaves without holding 2 GB in RAM.
+pub struct DatasetBuilder<'a> {
+    k: &'a [u8; 32],
+    i: u32,
+    end: u32,
+}
+
+impl<'a> DatasetBuilder<'a> {
+    #[must_use]
+    pub const fn new(k: &'a [u8; 32]) -> Self {
+        Self {
+            k,
+            i: 0,
+            end: N_LEAVES,
+        }
+    }
+}
+
+impl Iterator for DatasetBuilder<'_> {
+    type Item = [u8; 32];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.end {
+            return None;
+        }
+        let leaf = compute_leaf(self.k, self.i);
+        self.i += 1;
+        Some(leaf)
+    }
+}
+
+/// `Leaf[i] = SHA3_256( K || LE64(i) )`
+#[must_use]
+pub fn compute_leaf(k: &[u8; 32], index: u32) -> [u8; 32] {
+    let msg = index.to_le_bytes();
+    let mut hasher = Sha3_256::new();
+    hasher.update(k);
+    hasher.update(msg);
+    let digest = hasher.finalize();
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&digest);
+    out
+}
+
+/// Leaves `range` in order, equal to `range.map(|i| compute_leaf(k, i))`.
+///
+/// With the `rayon` feature the buffer is split into at most `workers` contiguous chunks filled
+/// in parallel; without it (or with `workers <= 1`) the leaves are computed sequentially.
+#[must_use]
+pub fn generate_parallel(k: &[u8; 32], range: Range<u32>, workers: usize) -> Vec<[u8; 32]> {
+    let mut out = vec![[0u8; 32]; range.len()];
+    #[cfg(feature = "rayon")]
+    if workers > 1 && out.len() > 1 {
+        use rayon::prelude::*;
+        let chunk = out.len().div_ceil(workers);
+        let starts: Vec<u32> = range.step_by(chunk).collect();
+        out.par_chunks_mut(chunk)
+            .zip(starts)
+            .for_each(|(leaves, first)| {
+                for (i, leaf) in (first..).zip(leaves.iter_mut()) {
+                    *leaf = compute_leaf(k, i);
+                }
+            });
+        return out;
+    }
+    #[cfg(not(feature = "rayon"))]
+    let _ = workers;
+    for (i, leaf) in range.zip(out.iter_mut()) {
+        *leaf = compute_leaf(k, i);
+    }
+    out
+}
+
+/// Root of the perfect subtree of height `level` whose leftmost leaf is `start`.
+/// Streams leaves through a stack of at most `level + 1` pending nodes.
+fn subtree_root(k: &[u8; 32], start: u32, level: u8) -> [u8; 32] {
+    let mut stack: Vec<(u8, [u8; 32])> = Vec::with_capacity(usize::from(level) + 1);
+    for i in start..start + (1u32 << level) {
+        let mut node = (0u8, compute_leaf(k, i));
+        while let Some(&(h, left)) = stack.last() {
+            if h != node.0 {
+                break;
+            }
+            stack.pop();
+            node = (h + 1, parent_hash(&left, &node.1));
+        }
+        stack.push(node);
+    }
+    debug_assert_eq!(stack.len(), 1);
+    stack[0].1
+}
+
+/// Dataset Merkle root over all `N_LEAVES` leaves (depth `N_LOG2`), built streaming.
+#[must_use]
+pub fn compute_root(k: &[u8; 32]) -> MerkleRoot {
+    compute_root_with_depth(k, N_LOG2)
+}
+
+/// As `compute_root` for a tree of `depth` levels (2^depth leaves).
+///
+/// # Panics
+///
+/// Panics if `depth > N_LOG2`.
+#[must_use]
+pub fn compute_root_with_depth(k: &[u8; 32], depth: u8) -> MerkleRoot {
+    assert!(depth <= N_LOG2, "depth exceeds N_LOG2");
+    MerkleRoot(subtree_root(k, 0, depth))
+}
+
+/// Authentication path (leaf to root, `N_LOG2` siblings) for leaf `index`.
+///
+/// # Errors
+///
+/// Returns `Step1Error::OutOfRangeIndex` if `index >= N_LEAVES`.
+pub fn prove(k: &[u8; 32], index: u32) -> Result<MerklePath, Step1Error> {
+    prove_with_depth(k, index, N_LOG2)
+}
+
+/// As `prove` for a tree of `depth` levels. Each sibling is the root of a neighbouring
+/// subtree, so one path costs 2^depth leaf hashes and O(depth) memory.
+///
+/// # Errors
+///
+/// Returns `Step1Error::OutOfRangeIndex` if `index >= 2^depth`.
+///
+/// # Panics
+///
+/// Panics if `depth > N_LOG2`.
+pub fn prove_with_depth(k: &[u8; 32], index: u32, depth: u8) -> Result<MerklePath, Step1Error> {
+    assert!(depth <= N_LOG2, "depth exceeds N_LOG2");
+    let max = 1u32 << depth;
+    if index >= max {
+        return Err(Step1Error::OutOfRangeIndex { index, max });
+    }
+    let path = (0..depth)
+        .map(|level| {
+            let sibling = (index >> level) ^ 1;
+            subtree_root(k, sibling << level, level)
+        })
+        .collect();
+    Ok(MerklePath { path })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle::verify_merkle_path_with_depth;
+
+    const DEPTH: u8 = 5;
+
+    #[test]
+    fn streaming_root_matches_naive_levels() {
+        let k = [3u8; 32];
+        let mut level: Vec<[u8; 32]> = (0..1u32 << DEPTH).map(|i| compute_leaf(&k, i)).collect();
+        while level.len() > 1 {
+            level = level.chunks(2).map(|p| parent_hash(&p[0], &p[1])).collect();
+        }
+        assert_eq!(compute_root_with_depth(&k, DEPTH).0, level[0]);
+    }
+
+    #[test]
+    fn generated_paths_verify() {
+        let k = [11u8; 32];
+        let root = compute_root_with_depth(&k, DEPTH);
+        for index in 0..1u32 << DEPTH {
+            let path = prove_with_depth(&k, index, DEPTH).expect("in range");
+            assert_eq!(path.path.len(), usize::from(DEPTH));
+            let leaf = compute_leaf(&k, index);
+            assert!(verify_merkle_path_with_depth(index, &leaf, &path, &root, DEPTH).is_ok());
+            let wrong = compute_leaf(&k, index ^ 1);
+            assert!(verify_merkle_path_with_depth(index, &wrong, &path, &root, DEPTH).is_err());
+        }
+        assert!(prove_with_depth(&k, 1 << DEPTH, DEPTH).is_err());
+    }
+
+    #[test]
+    fn parallel_leaves_match_serial() {
+        let k = [7u8; 32];
+        let range = 1_000u32..1_037;
+        let serial: Vec<[u8; 32]> = range.clone().map(|i| compute_leaf(&k, i)).collect();
+        for workers in [0, 1, 2, 3, 8, 64] {
+            assert_eq!(
+                generate_parallel(&k, range.clone(), workers),
+                serial,
+                "workers = {workers}"
+            );
+        }
+        assert!(generate_parallel(&k, 5..5, 4).is_empty());
+    }
+}
@@ -0,0 +1,6 @@
+pub const TAG_VRFOUT: &[u8] = b"VRFOUT";
+pub const TAG_EPOCH: &[u8] = b"EPOCH";
+pub const TAG_SEED: &[u8] = b"SEED";
+pub const TAG_KDF: &[u8] = b"KDF";
+pub const TAG_CHAL: &[u8] = b"CHAL";
+pub const TAG_TICKET: &[u8] = b"TICKET";
@@ -11,6 +11,9 @@ pub enum Step1Error {
     #[error("invalid VRF proof")]
     InvalidProof,
 
+    #[error("VRF secret key not available")]
+    MissingSecretKey,
+
     #[error("invalid signature")]
     InvalidSignature,
 
@@ -33,5 +36,9 @@ pub enum Step1Error {
     ChallengeIndicesMismatch,
 
     #[error("ticket expired: timestamp {timestamp}, current {current_time}, window {window}s")]
-    TicketExpired { timestamp: u64, current_time: u64, window: u64 },
-}
\ No newline at end of file
+    TicketExpired {
+        timestamp: u64,
+        current_time: u64,
+        window: u64,
+    },
+}
@@ -1,8 +1,14 @@
-use sha3::{Digest, Sha3_256};
+use crate::{
+    domain::{TAG_CHAL, TAG_EPOCH, TAG_KDF, TAG_SEED, TAG_VRFOUT},
+    ser::le64,
+    types::{
+        ChainId, EpochHash, EpochNonce, MerkleRoot, Registration, VrfOutput, VrfProof, DOMAIN_TAG,
+    },
+};
 use ed25519_dalek as ed25519;
-use crate::{types::{ChainId, DOMAIN_TAG, EpochHash, EpochNonce, MerkleRoot, VrfOutput, VrfProof}, ser::le64, domain::{TAG_CHAL, TAG_EPOCH, TAG_KDF, TAG_SEED, TAG_VRFOUT}};
+use sha3::{Digest, Sha3_256};
 
-/// E = SHA3_256( DOMAIN_TAG || "VRFOUT" || CHAIN_ID || LE64(epoch_number) || epoch_nonce || y || π )
+/// E = `SHA3_256( DOMAIN_TAG || "VRFOUT" || CHAIN_ID || LE64(epoch_number) || epoch_nonce || y || π )`
 #[must_use]
 pub fn compute_epoch_hash(
     chain_id: &ChainId,
@@ -14,11 +20,11 @@ pub fn compute_epoch_hash(
     let mut h = Sha3_256::new();
     h.update(DOMAIN_TAG);
     h.update(TAG_VRFOUT);
-    h.update(&chain_id.0);
-    h.update(&le64(epoch_number));
-    h.update(&epoch_nonce.0);
-    h.update(&y.0);
-    h.update(&pi.0);
+    h.update(chain_id.0);
+    h.update(le64(epoch_number));
+    h.update(epoch_nonce.0);
+    h.update(y.0);
+    h.update(pi.0);
     let digest = h.finalize();
     let mut out = [0u8; 32];
     out.copy_from_slice(&digest);
@@ -27,8 +33,12 @@ pub fn compute_epoch_hash(
 
 /// M = `DOMAIN_TAG` || "EPOCH" || E || `epoch_nonce` || pk
 #[must_use]
-pub fn build_m(epoch_hash: &EpochHash, epoch_nonce: &EpochNonce, pk: &ed25519::VerifyingKey) -> Vec<u8> {
-    let mut v = Vec::with_capacity(14+5 + 32 + 32 + 32);
+pub fn build_m(
+    epoch_hash: &EpochHash,
+    epoch_nonce: &EpochNonce,
+    pk: &ed25519::VerifyingKey,
+) -> Vec<u8> {
+    let mut v = Vec::with_capacity(14 + 5 + 32 + 32 + 32);
     v.extend_from_slice(DOMAIN_TAG);
     v.extend_from_slice(TAG_EPOCH);
     v.extend_from_slice(&epoch_hash.0);
@@ -39,21 +49,21 @@ pub fn build_m(epoch_hash: &EpochHash, epoch_nonce: &EpochNonce, pk: &ed25519::V
 
 // Note: A `build_M` alias is intentionally omitted to satisfy pedantic naming lints.
 
-/// SEED = SHA3_256( DOMAIN_TAG || "SEED" || M || σ )
-/// K    = SHA3_256( DOMAIN_TAG || "KDF"  || SEED )
+/// SEED = `SHA3_256( DOMAIN_TAG || "SEED" || M || σ )`
+/// K    = `SHA3_256( DOMAIN_TAG || "KDF"  || SEED )`
 #[must_use]
 pub fn derive_seed_and_key(m: &[u8], sigma: &ed25519::Signature) -> ([u8; 32], [u8; 32]) {
     let mut h = Sha3_256::new();
     h.update(DOMAIN_TAG);
     h.update(TAG_SEED);
     h.update(m);
-    h.update(&sigma.to_bytes());
+    h.update(sigma.to_bytes());
     let seed_digest = h.finalize();
 
     let mut h2 = Sha3_256::new();
     h2.update(DOMAIN_TAG);
     h2.update(TAG_KDF);
-    h2.update(&seed_digest);
+    h2.update(seed_digest);
     let k_digest = h2.finalize();
 
     let mut seed_out = [0u8; 32];
@@ -63,7 +73,24 @@ pub fn derive_seed_and_key(m: &[u8], sigma: &ed25519::Signature) -> ([u8; 32], [
     (seed_out, k_out)
 }
 
-/// C = SHA3_256( DOMAIN_TAG || "CHAL" || E || epoch_nonce || pk || root )
+/// Dataset key K for a registration: E → M → (SEED, K), exactly as the verifier derives it.
+///
+/// `vrf_output` is the VRF output y the verifier obtains from `reg.vrf_proof`; a prover uses the
+/// returned key with `compute_leaf` to build the leaves the challenge openings must match.
+#[must_use]
+pub fn dataset_key_for(reg: &Registration, vrf_output: &VrfOutput) -> [u8; 32] {
+    let e = compute_epoch_hash(
+        reg.chain_id,
+        reg.epoch_number,
+        reg.epoch_nonce,
+        vrf_output,
+        reg.vrf_proof,
+    );
+    let m = build_m(&e, reg.epoch_nonce, reg.pk);
+    derive_seed_and_key(&m, reg.sig).1
+}
+
+/// C = `SHA3_256( DOMAIN_TAG || "CHAL" || E || epoch_nonce || pk || root )`
 #[must_use]
 pub fn build_challenge_seed(
     epoch_hash: &EpochHash,
@@ -74,12 +101,12 @@ pub fn build_challenge_seed(
     let mut h = Sha3_256::new();
     h.update(DOMAIN_TAG);
     h.update(TAG_CHAL);
-    h.update(&epoch_hash.0);
-    h.update(&epoch_nonce.0);
+    h.update(epoch_hash.0);
+    h.update(epoch_nonce.0);
     h.update(pk.as_bytes());
-    h.update(&root.0);
+    h.update(root.0);
     let digest = h.finalize();
     let mut out = [0u8; 32];
     out.copy_from_slice(&digest);
     out
-}
\ No newline at end of file
+}
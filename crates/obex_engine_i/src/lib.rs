@@ -24,30 +24,38 @@
 // 4. Maintainability: Clear, well-documented code structure
 
 // Core modules
-pub mod types;
-pub mod errors;
-pub mod ser;
-pub mod domain;
-pub mod vrf;
-pub mod merkle;
 pub mod challenge;
 pub mod dataset;
-pub mod registration;
+pub mod domain;
+pub mod ecvrf_ristretto255;
+pub mod ecvrf_traits;
+pub mod errors;
 pub mod hashers;
+pub mod merkle;
+pub mod registration;
+pub mod ser;
 pub mod ticket;
-pub mod ecvrf_traits;
-pub mod ecvrf_ristretto255;
+pub mod types;
+pub mod vrf;
 
 // Re-export commonly used types and functions
-pub use types::*;
-pub use errors::Step1Error;
-pub use vrf::{Vrf, ChainVrf, mk_chain_vrf};
-pub use merkle::verify_merkle_path;
 pub use challenge::{derive_challenge_indices, verify_challenge_indices};
-pub use dataset::compute_leaf;
-pub use registration::{verify_registration_succinct, verify_registration, verify_challenge_open, verify_registrations_batch};
-pub use hashers::{compute_epoch_hash, build_m, derive_seed_and_key, build_challenge_seed};
-pub use ticket::{verify_ticket_time, create_ticket, verify_tickets_batch, is_ticket_valid_time};
+pub use dataset::{compute_leaf, compute_root};
+pub use errors::Step1Error;
+pub use hashers::{
+    build_challenge_seed, build_m, compute_epoch_hash, dataset_key_for, derive_seed_and_key,
+};
+pub use merkle::{verify_merkle_path, verify_merkle_path_with_depth};
+pub use registration::{
+    verify_challenge_open, verify_registration, verify_registration_succinct,
+    verify_registrations_batch, verify_registrations_batch_serial,
+};
+pub use ticket::{
+    create_ticket, create_ticket_signed, is_ticket_valid_time, verify_ticket_sig,
+    verify_ticket_time, verify_tickets_batch,
+};
+pub use types::*;
+pub use vrf::{mk_chain_vrf, ChainVrf, Vrf};
 
 // Version and protocol constants
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,140 @@
+use crate::{
+    errors::Step1Error,
+    types::{MerklePath, MerkleRoot, N_LOG2},
+};
+use sha3::{Digest, Sha3_256};
+
+#[inline]
+pub(crate) fn parent_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut h = Sha3_256::new();
+    h.update(left);
+    h.update(right);
+    let digest = h.finalize();
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&digest);
+    out
+}
+
+/// Verify a Merkle authentication path for (index, leaf) up to root.
+///
+/// The path must have exactly `N_LOG2` siblings (leaf to root) and `index < N_LEAVES`.
+///
+/// # Errors
+///
+/// Returns `Step1Error::OutOfRangeIndex` for an index outside the tree,
+/// `Step1Error::InvalidLength` for a path of the wrong depth, and
+/// `Step1Error::MerklePathMismatch` if the computed root doesn't match the expected root.
+pub fn verify_merkle_path(
+    index: u32,
+    leaf: &[u8; 32],
+    path: &MerklePath,
+    root: &MerkleRoot,
+) -> Result<(), Step1Error> {
+    verify_merkle_path_with_depth(index, leaf, path, root, N_LOG2)
+}
+
+/// As `verify_merkle_path` for a tree of `depth` levels (2^depth leaves).
+///
+/// # Errors
+///
+/// As `verify_merkle_path`, with bounds `index < 2^depth` and `path.path.len() == depth`.
+///
+/// # Panics
+///
+/// Panics if `depth > N_LOG2`.
+pub fn verify_merkle_path_with_depth(
+    index: u32,
+    leaf: &[u8; 32],
+    path: &MerklePath,
+    root: &MerkleRoot,
+    depth: u8,
+) -> Result<(), Step1Error> {
+    assert!(depth <= N_LOG2, "depth exceeds N_LOG2");
+    let max = 1u32 << depth;
+    if index >= max {
+        return Err(Step1Error::OutOfRangeIndex { index, max });
+    }
+    // A short path would stop below the root, a long one hash past it: neither is a proof.
+    if path.path.len() != usize::from(depth) {
+        return Err(Step1Error::InvalidLength {
+            expected: usize::from(depth),
+            got: path.path.len(),
+        });
+    }
+    let mut acc = *leaf;
+    let mut idx = index;
+    for sib in &path.path {
+        if (idx & 1) == 0 {
+            acc = parent_hash(&acc, sib);
+        } else {
+            acc = parent_hash(sib, &acc);
+        }
+        idx >>= 1;
+    }
+    if acc != root.0 {
+        return Err(Step1Error::MerklePathMismatch);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::N_LEAVES;
+
+    /// Root of the tree in which every sibling on `leaf`'s path is `sib`.
+    fn root_for(index: u32, leaf: &[u8; 32], sib: &[u8; 32]) -> MerkleRoot {
+        let mut acc = *leaf;
+        for level in 0..N_LOG2 {
+            acc = if (index >> level) & 1 == 0 {
+                parent_hash(&acc, sib)
+            } else {
+                parent_hash(sib, &acc)
+            };
+        }
+        MerkleRoot(acc)
+    }
+
+    #[test]
+    fn full_depth_path_verifies() {
+        let (index, leaf, sib) = (N_LEAVES - 3, [7u8; 32], [9u8; 32]);
+        let root = root_for(index, &leaf, &sib);
+        let path = MerklePath {
+            path: vec![sib; usize::from(N_LOG2)],
+        };
+        assert!(verify_merkle_path(index, &leaf, &path, &root).is_ok());
+        assert!(matches!(
+            verify_merkle_path(index ^ 1, &leaf, &path, &root),
+            Err(Step1Error::MerklePathMismatch)
+        ));
+    }
+
+    #[test]
+    fn short_or_long_path_rejected() {
+        let (index, leaf, sib) = (5, [1u8; 32], [2u8; 32]);
+        let root = root_for(index, &leaf, &sib);
+        for len in [usize::from(N_LOG2) - 1, usize::from(N_LOG2) + 1, 0] {
+            let path = MerklePath {
+                path: vec![sib; len],
+            };
+            assert!(matches!(
+                verify_merkle_path(index, &leaf, &path, &root),
+                Err(Step1Error::InvalidLength { expected: 26, got }) if got == len
+            ));
+        }
+    }
+
+    #[test]
+    fn out_of_range_index_rejected() {
+        let path = MerklePath {
+            path: vec![[0u8; 32]; usize::from(N_LOG2)],
+        };
+        let root = MerkleRoot([0u8; 32]);
+        for index in [N_LEAVES, u32::MAX] {
+            assert!(matches!(
+                verify_merkle_path(index, &[0u8; 32], &path, &root),
+                Err(Step1Error::OutOfRangeIndex { max: N_LEAVES, .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use crate::{
+    challenge::derive_challenge_indices,
+    dataset::compute_leaf,
+    errors::Step1Error,
+    hashers::{build_m, compute_epoch_hash, derive_seed_and_key},
+    merkle::verify_merkle_path,
+    ser::build_alpha,
+    types::{ChallengeOpen, EpochHash, MerkleRoot, Registration, CHALLENGE_COUNT},
+    vrf::Vrf,
+};
+
+/// Complete Step-1 registration verification pipeline.
+/// Verify a registration with VRF proof and challenge openings.
+///
+/// # Errors
+///
+/// Returns `Step1Error` if VRF verification fails, challenge indices are invalid, or challenge openings are incorrect.
+pub fn verify_registration<V: Vrf>(
+    reg: &Registration,
+    epoch: u32,
+    vrf: &V,
+    merkle_root: &MerkleRoot,
+    challenge_opens: &[ChallengeOpen],
+) -> Result<(), Step1Error> {
+    verify_registration_succinct(vrf, reg, challenge_opens, epoch, merkle_root)
+}
+
+/// Verify a single challenge opening.
+///
+/// # Errors
+///
+/// Returns `Step1Error` if the Merkle path verification fails or the computed leaf doesn't match.
+pub fn verify_challenge_open(
+    dataset_key: &[u8; 32],
+    index: u32,
+    open: &ChallengeOpen,
+    merkle_root: &MerkleRoot,
+) -> Result<(), Step1Error> {
+    let expected_leaf = compute_leaf(dataset_key, index);
+    verify_merkle_path(index, &expected_leaf, open.path, merkle_root)
+}
+
+/// Batch verification for multiple registrations, in parallel (`rayon` feature).
+///
+/// The result order always matches `registrations`. Only this parallel build requires
+/// `V: Sync`; without the feature the same call runs `verify_registrations_batch_serial`.
+///
+/// # Errors
+///
+/// Returns `Step1Error` if any individual registration verification fails during the batch process.
+#[cfg(feature = "rayon")]
+pub fn verify_registrations_batch<V: Vrf + Sync>(
+    registrations: &[(Registration, Vec<ChallengeOpen>)],
+    epoch: u32,
+    vrf: &V,
+    merkle_root: &MerkleRoot,
+) -> Result<Vec<bool>, Step1Error> {
+    use rayon::prelude::*;
+    Ok(registrations
+        .par_iter()
+        .map(|(reg, opens)| verify_registration(reg, epoch, vrf, merkle_root, opens).is_ok())
+        .collect())
+}
+
+/// Batch verification for multiple registrations.
+///
+/// With the `rayon` feature enabled entries are verified in parallel, which then also requires
+/// `V: Sync`; the result order always matches `registrations`.
+///
+/// # Errors
+///
+/// Returns `Step1Error` if any individual registration verification fails during the batch process.
+#[cfg(not(feature = "rayon"))]
+pub fn verify_registrations_batch<V: Vrf>(
+    registrations: &[(Registration, Vec<ChallengeOpen>)],
+    epoch: u32,
+    vrf: &V,
+    merkle_root: &MerkleRoot,
+) -> Result<Vec<bool>, Step1Error> {
+    verify_registrations_batch_serial(registrations, epoch, vrf, merkle_root)
+}
+
+/// Sequential batch verification (the default path of `verify_registrations_batch`).
+///
+/// # Errors
+///
+/// Returns `Step1Error` if any individual registration verification fails during the batch process.
+pub fn verify_registrations_batch_serial<V: Vrf>(
+    registrations: &[(Registration, Vec<ChallengeOpen>)],
+    epoch: u32,
+    vrf: &V,
+    merkle_root: &MerkleRoot,
+) -> Result<Vec<bool>, Step1Error> {
+    let mut results = Vec::with_capacity(registrations.len());
+
+    for (reg, opens) in registrations {
+        let is_valid = verify_registration(reg, epoch, vrf, merkle_root, opens).is_ok();
+        results.push(is_valid);
+    }
+
+    Ok(results)
+}
+
+/// Verify a succinct registration per the Step-1 spec.
+/// Steps: α build → VRF verify → E → M → signature check → (seed,K) → challenge C → indices → verify openings.
+///
+/// # Errors
+/// Returns `Step1Error` when input sizes are invalid, cryptographic checks fail,
+/// challenge indices mismatch, Merkle paths don't authenticate to the declared root,
+/// or the signature/VRF verification fails.
+pub fn verify_registration_succinct<V: Vrf>(
+    vrf: &V,
+    reg: &Registration,
+    openings: &[ChallengeOpen],
+    epoch: u32,
+    declared_root: &MerkleRoot,
+) -> Result<(), Step1Error> {
+    if openings.len() != CHALLENGE_COUNT {
+        return Err(Step1Error::InvalidLength {
+            expected: CHALLENGE_COUNT,
+            got: openings.len(),
+        });
+    }
+
+    // α
+    let alpha = build_alpha(reg.chain_id, reg.epoch_number, reg.epoch_nonce);
+    // VRF verify
+    let y = vrf.verify(&alpha, reg.vrf_proof)?;
+    // E
+    let e: EpochHash = compute_epoch_hash(
+        reg.chain_id,
+        reg.epoch_number,
+        reg.epoch_nonce,
+        &y,
+        reg.vrf_proof,
+    );
+    // M
+    let m = build_m(&e, reg.epoch_nonce, reg.pk);
+    // Signature
+    reg.pk
+        .verify_strict(&m, reg.sig)
+        .map_err(|_| Step1Error::InvalidSignature)?;
+    // (seed, K)
+    let (_seed, k) = derive_seed_and_key(&m, reg.sig);
+    // Derive challenge indices
+    let indices = derive_challenge_indices(reg, epoch)?;
+    if indices.len() != CHALLENGE_COUNT {
+        return Err(Step1Error::InvalidLength {
+            expected: CHALLENGE_COUNT,
+            got: indices.len(),
+        });
+    }
+
+    // Verify each opening
+    for (open, idx) in openings.iter().zip(indices.iter()) {
+        if open.index != *idx {
+            return Err(Step1Error::ChallengeIndicesMismatch);
+        }
+        // Recompute leaf from K
+        let expected_leaf = compute_leaf(&k, open.index);
+        if &expected_leaf != open.leaf {
+            return Err(Step1Error::MerklePathMismatch);
+        }
+        verify_merkle_path(open.index, open.leaf, open.path, declared_root)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        merkle::parent_hash,
+        types::{ChainId, EpochNonce, MerklePath, VrfOutput, VrfProof, N_LOG2},
+    };
+    use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+    use std::collections::BTreeMap;
+
+    /// Accepts proofs whose first byte is 1 and returns a fixed y.
+    struct MockVrf;
+
+    impl Vrf for MockVrf {
+        fn verify(&self, _alpha: &[u8], proof: &VrfProof) -> Result<VrfOutput, Step1Error> {
+            if proof.0[0] == 1 {
+                Ok(VrfOutput([7u8; 64]))
+            } else {
+                Err(Step1Error::InvalidProof)
+            }
+        }
+    }
+
+    struct Owned {
+        chain_id: ChainId,
+        epoch_nonce: EpochNonce,
+        vrf_proof: VrfProof,
+        vrf_output: VrfOutput,
+        epoch_hash: EpochHash,
+        pk: VerifyingKey,
+        sig: Signature,
+        root: MerkleRoot,
+        k: [u8; 32],
+    }
+
+    impl Owned {
+        fn new(seed: u8, proof_ok: bool, sign_ok: bool) -> Self {
+            let sk = SigningKey::from_bytes(&[seed; 32]);
+            let pk = sk.verifying_key();
+            let chain_id = ChainId([1u8; 32]);
+            let epoch_nonce = EpochNonce([seed; 32]);
+            let mut proof = [seed; 80];
+            proof[0] = u8::from(proof_ok);
+            let vrf_proof = VrfProof(proof);
+            let vrf_output = VrfOutput([7u8; 64]);
+            let epoch_hash =
+                compute_epoch_hash(&chain_id, 9, &epoch_nonce, &vrf_output, &vrf_proof);
+            let m = build_m(&epoch_hash, &epoch_nonce, &pk);
+            let sig = if sign_ok {
+                sk.sign(&m)
+            } else {
+                sk.sign(b"other")
+            };
+            let (_seed, k) = derive_seed_and_key(&m, &sig);
+            Self {
+                chain_id,
+                epoch_nonce,
+                vrf_proof,
+                vrf_output,
+                epoch_hash,
+                pk,
+                sig,
+                root: MerkleRoot([seed; 32]),
+                k,
+            }
+        }
+
+        fn reg(&self) -> Registration<'_> {
+            Registration {
+                chain_id: &self.chain_id,
+                epoch_number: 9,
+                epoch_nonce: &self.epoch_nonce,
+                vrf_proof: &self.vrf_proof,
+                vrf_output: &self.vrf_output,
+                epoch_hash: &self.epoch_hash,
+                pk: &self.pk,
+                sig: &self.sig,
+                root: &self.root,
+            }
+        }
+    }
+
+    /// Sparse tree over the challenged leaves only (absent subtrees hash to zero), which is
+    /// all `verify_merkle_path` can observe.
+    fn sparse_tree(leaves: &BTreeMap<u32, [u8; 32]>) -> (MerkleRoot, BTreeMap<u32, MerklePath>) {
+        let mut paths: BTreeMap<u32, MerklePath> = leaves
+            .keys()
+            .map(|&i| (i, MerklePath { path: Vec::new() }))
+            .collect();
+        let mut level = leaves.clone();
+        for l in 0..N_LOG2 {
+            for (&i, path) in &mut paths {
+                let sib = level.get(&((i >> l) ^ 1)).copied().unwrap_or([0u8; 32]);
+                path.path.push(sib);
+            }
+            let mut next = BTreeMap::new();
+            for &j in level.keys() {
+                let p = j >> 1;
+                let left = level.get(&(p << 1)).copied().unwrap_or([0u8; 32]);
+                let right = level.get(&((p << 1) | 1)).copied().unwrap_or([0u8; 32]);
+                next.insert(p, parent_hash(&left, &right));
+            }
+            level = next;
+        }
+        (MerkleRoot(level[&0]), paths)
+    }
+
+    #[test]
+    fn batch_matches_serial_on_mixed_registrations() {
+        // (proof ok, signature ok, all openings present)
+        let cases = [
+            (true, true, true),
+            (false, true, true),
+            (true, true, true),
+            (true, false, true),
+            (true, true, false),
+            (true, true, true),
+        ];
+        let owned: Vec<Owned> = cases
+            .iter()
+            .zip(1u8..)
+            .map(|(&(proof_ok, sign_ok, _), seed)| Owned::new(seed, proof_ok, sign_ok))
+            .collect();
+        let indices: Vec<Vec<u32>> = owned
+            .iter()
+            .map(|o| derive_challenge_indices(&o.reg(), 0).unwrap())
+            .collect();
+        let mut leaves = BTreeMap::new();
+        for (o, idx) in owned.iter().zip(&indices) {
+            for &i in idx {
+                leaves.insert(i, compute_leaf(&o.k, i));
+            }
+        }
+        let (root, paths) = sparse_tree(&leaves);
+
+        let batch: Vec<(Registration, Vec<ChallengeOpen>)> = owned
+            .iter()
+            .zip(&indices)
+            .zip(&cases)
+            .map(|((o, idx), &(_, _, complete))| {
+                let take = if complete {
+                    CHALLENGE_COUNT
+                } else {
+                    CHALLENGE_COUNT - 1
+                };
+                let opens = idx[..take]
+                    .iter()
+                    .map(|&i| ChallengeOpen {
+                        index: i,
+                        leaf: &leaves[&i],
+                        path: &paths[&i],
+                    })
+                    .collect();
+                (o.reg(), opens)
+            })
+            .collect();
+
+        let expected = vec![true, false, true, false, false, true];
+        let serial = verify_registrations_batch_serial(&batch, 0, &MockVrf, &root).unwrap();
+        let batched = verify_registrations_batch(&batch, 0, &MockVrf, &root).unwrap();
+        assert_eq!(serial, expected);
+        assert_eq!(batched, serial);
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    #[test]
+    fn default_batch_accepts_a_non_sync_vrf() {
+        /// Counts calls through a `Cell`, so it is not `Sync`.
+        struct CountingVrf(std::cell::Cell<u32>);
+
+        impl Vrf for CountingVrf {
+            fn verify(&self, alpha: &[u8], proof: &VrfProof) -> Result<VrfOutput, Step1Error> {
+                self.0.set(self.0.get() + 1);
+                MockVrf.verify(alpha, proof)
+            }
+        }
+
+        let o = Owned::new(1, true, true);
+        let vrf = CountingVrf(std::cell::Cell::new(0));
+        let batch = vec![(o.reg(), Vec::new())];
+        let root = MerkleRoot([0u8; 32]);
+        assert_eq!(
+            verify_registrations_batch(&batch, 0, &vrf, &root).unwrap(),
+            vec![false]
+        );
+    }
+
+    #[test]
+    fn dataset_key_for_matches_the_verifier_key() {
+        let o = Owned::new(3, true, true);
+        let reg = o.reg();
+        let y = MockVrf
+            .verify(
+                &build_alpha(reg.chain_id, reg.epoch_number, reg.epoch_nonce),
+                reg.vrf_proof,
+            )
+            .unwrap();
+        let k = crate::hashers::dataset_key_for(&reg, &y);
+        assert_eq!(k, o.k);
+
+        // Leaves built from the helper's key satisfy the verifier.
+        let indices = derive_challenge_indices(&reg, 0).unwrap();
+        let leaves: BTreeMap<u32, [u8; 32]> =
+            indices.iter().map(|&i| (i, compute_leaf(&k, i))).collect();
+        let (root, paths) = sparse_tree(&leaves);
+        let opens: Vec<ChallengeOpen> = indices
+            .iter()
+            .map(|&i| ChallengeOpen {
+                index: i,
+                leaf: &leaves[&i],
+                path: &paths[&i],
+            })
+            .collect();
+        assert!(verify_registration_succinct(&MockVrf, &reg, &opens, 0, &root).is_ok());
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    errors::Step1Error,
+    ser::encode_ticket,
+    types::{SignedTicket, Ticket},
+};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Parameters for creating a ticket
+#[derive(Debug, Clone, Copy)]
+pub struct TicketParams {
+    pub chain_id: [u8; 32],
+    pub epoch_number: u64,
+    pub epoch_hash: [u8; 32],
+    pub epoch_nonce: [u8; 32],
+    pub pk: [u8; 32],
+    pub root: [u8; 32],
+    pub valid_from: Option<u64>,
+    pub valid_duration_secs: u64,
+}
+
+/// Wall-clock seconds since the Unix epoch (0 if the clock is before it).
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Verify a ticket's time validity.
+///
+/// # Errors
+///
+/// Returns `Step1Error::InvalidTicketWindow` if the ticket is outside its valid time window.
+pub fn verify_ticket_time(ticket: &Ticket, current_time: Option<u64>) -> Result<(), Step1Error> {
+    let now = current_time.unwrap_or_else(unix_now);
+
+    if now < ticket.valid_from {
+        return Err(Step1Error::TicketExpired {
+            timestamp: ticket.valid_from,
+            current_time: now,
+            window: 0,
+        });
+    }
+
+    if now > ticket.valid_to {
+        return Err(Step1Error::TicketExpired {
+            timestamp: ticket.valid_to,
+            current_time: now,
+            window: 0,
+        });
+    }
+
+    Ok(())
+}
+
+/// Create a ticket with specified validity period.
+#[must_use]
+pub fn create_ticket(params: TicketParams) -> Ticket {
+    let valid_from = params.valid_from.unwrap_or_else(unix_now);
+
+    Ticket {
+        chain_id: params.chain_id,
+        epoch_number: params.epoch_number,
+        epoch_hash: params.epoch_hash,
+        epoch_nonce: params.epoch_nonce,
+        pk: params.pk,
+        root: params.root,
+        valid_from,
+        valid_to: valid_from + params.valid_duration_secs,
+    }
+}
+
+/// Create a ticket signed by `signing_key` over its canonical encoding.
+/// The ticket `pk` is taken from `signing_key`; `params.pk` is ignored.
+#[must_use]
+pub fn create_ticket_signed(params: TicketParams, signing_key: &SigningKey) -> SignedTicket {
+    let ticket = create_ticket(TicketParams {
+        pk: signing_key.verifying_key().to_bytes(),
+        ..params
+    });
+    let sig = signing_key.sign(&encode_ticket(&ticket)).to_bytes();
+    SignedTicket { ticket, sig }
+}
+
+/// Verify a signed ticket against its embedded `pk`. Time windows are checked separately via
+/// `verify_ticket_time(&signed.ticket, ..)`.
+///
+/// # Errors
+///
+/// Returns `Step1Error::InvalidSignature` if `pk` is not a valid Ed25519 key or the signature
+/// does not cover the ticket's canonical encoding.
+pub fn verify_ticket_sig(signed: &SignedTicket) -> Result<(), Step1Error> {
+    let pk =
+        VerifyingKey::from_bytes(&signed.ticket.pk).map_err(|_| Step1Error::InvalidSignature)?;
+    let sig = Signature::from_bytes(&signed.sig);
+    pk.verify_strict(&encode_ticket(&signed.ticket), &sig)
+        .map_err(|_| Step1Error::InvalidSignature)
+}
+
+/// Batch verify multiple tickets.
+///
+/// With `current_time == None` the clock is read once at batch start, so every ticket in the
+/// batch is judged against the same timestamp.
+#[must_use]
+pub fn verify_tickets_batch(tickets: &[Ticket], current_time: Option<u64>) -> Vec<bool> {
+    verify_tickets_batch_with_clock(tickets, current_time, unix_now)
+}
+
+fn verify_tickets_batch_with_clock(
+    tickets: &[Ticket],
+    current_time: Option<u64>,
+    clock: impl FnOnce() -> u64,
+) -> Vec<bool> {
+    let now = current_time.unwrap_or_else(clock);
+    tickets
+        .iter()
+        .map(|ticket| verify_ticket_time(ticket, Some(now)).is_ok())
+        .collect()
+}
+
+/// Check if a ticket is within the valid time window.
+#[must_use]
+pub fn is_ticket_valid_time(ticket: &Ticket, current_time: Option<u64>) -> bool {
+    let now = current_time.unwrap_or_else(unix_now);
+
+    now >= ticket.valid_from && now <= ticket.valid_to
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> TicketParams {
+        TicketParams {
+            chain_id: [1u8; 32],
+            epoch_number: 5,
+            epoch_hash: [2u8; 32],
+            epoch_nonce: [3u8; 32],
+            pk: [0u8; 32],
+            root: [4u8; 32],
+            valid_from: Some(1_000),
+            valid_duration_secs: 60,
+        }
+    }
+
+    #[test]
+    fn signed_ticket_verifies_and_keeps_time_window() {
+        let sk = SigningKey::from_bytes(&[9u8; 32]);
+        let signed = create_ticket_signed(params(), &sk);
+        assert_eq!(signed.ticket.pk, sk.verifying_key().to_bytes());
+        assert!(verify_ticket_sig(&signed).is_ok());
+        assert!(verify_ticket_time(&signed.ticket, Some(1_030)).is_ok());
+        assert!(verify_ticket_time(&signed.ticket, Some(1_061)).is_err());
+    }
+
+    #[test]
+    fn batch_without_time_reads_the_clock_once() {
+        // Windows [1000, 1060], [1001, 1061], ...: any clock drift inside the batch would split
+        // the verdicts at a different ticket than a single snapshot does.
+        let tickets: Vec<Ticket> = (0..100u64)
+            .map(|i| {
+                create_ticket(TicketParams {
+                    valid_from: Some(1_000 + i),
+                    ..params()
+                })
+            })
+            .collect();
+        let mut reads = 0u32;
+        let out = verify_tickets_batch_with_clock(&tickets, None, || {
+            reads += 1;
+            1_050
+        });
+        assert_eq!(reads, 1);
+        assert_eq!(out, verify_tickets_batch(&tickets, Some(1_050)));
+        assert!(out.iter().take(51).all(|&ok| ok));
+        assert!(out.iter().skip(51).all(|&ok| !ok));
+
+        let mut reads = 0u32;
+        let fixed = verify_tickets_batch_with_clock(&tickets, Some(1_050), || {
+            reads += 1;
+            0
+        });
+        assert_eq!((reads, fixed), (0, out));
+    }
+
+    #[test]
+    fn tampered_ticket_is_rejected() {
+        let sk = SigningKey::from_bytes(&[9u8; 32]);
+        let signed = create_ticket_signed(params(), &sk);
+        let tampers: [fn(&mut SignedTicket); 6] = [
+            |s| s.ticket.root[0] ^= 1,
+            |s| s.ticket.epoch_number += 1,
+            |s| s.ticket.valid_to += 1,
+            |s| {
+                s.ticket.pk = SigningKey::from_bytes(&[8u8; 32])
+                    .verifying_key()
+                    .to_bytes();
+            },
+            |s| s.ticket.chain_id[31] ^= 0x80,
+            |s| s.sig[0] ^= 1,
+        ];
+        for tamper in tampers {
+            let mut t = signed;
+            tamper(&mut t);
+            assert!(matches!(
+                verify_ticket_sig(&t),
+                Err(Step1Error::InvalidSignature)
+            ));
+        }
+    }
+}
@@ -1,23 +1,29 @@
-use core::convert::TryFrom;
 use crate::errors::Step1Error;
+use core::convert::TryFrom;
 
 pub const DOMAIN_TAG: &[u8; 14] = br#"[Iota]_|::"v1""#; // 14-byte ASCII per README
 pub const ALPHA_LEN: usize = 14 + 32 + 8 + 32; // 86 bytes
-pub const VRF_OUTPUT_LEN: usize = 64;          // y
-pub const VRF_PROOF_LEN: usize  = 80;          // π = γ(32)||c(16)||s(32)
-pub const LEAF_LEN: usize = 32;                // 32-byte leaves
-pub const MERKLE_ROOT_LEN: usize = 32;         // BLAKE3 root
-pub const N_LOG2: u8 = 26;                     // tree depth 26
-pub const N_LEAVES: u32 = 1u32 << N_LOG2;      // 67,108,864
-pub const CHALLENGE_COUNT: usize = 32;          // number of challenges per registration
+pub const VRF_OUTPUT_LEN: usize = 64; // y
+pub const VRF_PROOF_LEN: usize = 80; // π = γ(32)||c(16)||s(32)
+pub const LEAF_LEN: usize = 32; // 32-byte leaves
+pub const MERKLE_ROOT_LEN: usize = 32; // BLAKE3 root
+pub const N_LOG2: u8 = 26; // tree depth 26
+pub const N_LEAVES: u32 = 1u32 << N_LOG2; // 67,108,864
+pub const CHALLENGE_COUNT: usize = 32; // number of challenges per registration
 
 // Fixed-size newtypes prevent misuse
-#[repr(transparent)] pub struct ChainId(pub [u8; 32]);
-#[repr(transparent)] pub struct EpochNonce(pub [u8; 32]);
-#[repr(transparent)] pub struct VrfOutput(pub [u8; VRF_OUTPUT_LEN]);
-#[repr(transparent)] pub struct VrfProof(pub  [u8; VRF_PROOF_LEN]);
-#[repr(transparent)] pub struct MerkleRoot(pub [u8; MERKLE_ROOT_LEN]);
-#[repr(transparent)] pub struct EpochHash(pub [u8; 32]);
+#[repr(transparent)]
+pub struct ChainId(pub [u8; 32]);
+#[repr(transparent)]
+pub struct EpochNonce(pub [u8; 32]);
+#[repr(transparent)]
+pub struct VrfOutput(pub [u8; VRF_OUTPUT_LEN]);
+#[repr(transparent)]
+pub struct VrfProof(pub [u8; VRF_PROOF_LEN]);
+#[repr(transparent)]
+pub struct MerkleRoot(pub [u8; MERKLE_ROOT_LEN]);
+#[repr(transparent)]
+pub struct EpochHash(pub [u8; 32]);
 
 // Exact-sized decode helpers
 macro_rules! impl_tryfrom_slice {
@@ -26,19 +32,22 @@ macro_rules! impl_tryfrom_slice {
             type Error = Step1Error;
             fn try_from(b: &[u8]) -> Result<Self, Self::Error> {
                 if b.len() != $len {
-                    return Err(Step1Error::InvalidLength { expected: $len, got: b.len() });
+                    return Err(Step1Error::InvalidLength {
+                        expected: $len,
+                        got: b.len(),
+                    });
                 }
                 let mut arr = [0u8; $len];
                 arr.copy_from_slice(b);
                 Ok(Self(arr))
             }
         }
-    }
+    };
 }
 impl_tryfrom_slice!(ChainId, 32, "ChainId");
 impl_tryfrom_slice!(EpochNonce, 32, "EpochNonce");
 impl_tryfrom_slice!(VrfOutput, VRF_OUTPUT_LEN, "VrfOutput");
-impl_tryfrom_slice!(VrfProof,  VRF_PROOF_LEN,  "VrfProof");
+impl_tryfrom_slice!(VrfProof, VRF_PROOF_LEN, "VrfProof");
 impl_tryfrom_slice!(MerkleRoot, MERKLE_ROOT_LEN, "MerkleRoot");
 impl_tryfrom_slice!(EpochHash, 32, "EpochHash");
 
@@ -60,8 +69,8 @@ pub struct MerklePath {
 }
 
 pub struct ChallengeOpen<'a> {
-    pub index: u32,          // must be < N_LEAVES
-    pub leaf: &'a [u8; 32],  // exact leaf bytes
+    pub index: u32,         // must be < N_LEAVES
+    pub leaf: &'a [u8; 32], // exact leaf bytes
     pub path: &'a MerklePath,
 }
 
@@ -75,4 +84,11 @@ pub struct Ticket {
     pub root: [u8; 32],
     pub valid_from: u64,
     pub valid_to: u64,
-}
\ No newline at end of file
+}
+
+/// Ticket bound to its `pk` by an Ed25519 signature over `encode_ticket(&ticket)`.
+#[derive(Clone, Copy)]
+pub struct SignedTicket {
+    pub ticket: Ticket,
+    pub sig: [u8; 64],
+}
@@ -0,0 +1,167 @@
+use crate::{
+    errors::Step1Error,
+    types::{VrfOutput, VrfProof, ALPHA_LEN},
+};
+
+/// Public VRF trait used by the registration verifier.
+pub trait Vrf {
+    /// Verify a VRF proof π on input α under the VRF public key.
+    /// Returns the 64-byte VRF output y on success.
+    ///
+    /// # Errors
+    /// Returns `Step1Error` if the input sizes are invalid or verification fails.
+    fn verify(&self, alpha: &[u8], proof: &VrfProof) -> Result<VrfOutput, Step1Error>;
+}
+
+/// Chain VRF adapter backed by the vrf-r255 RFC 9381 implementation.
+///
+/// Note: `pk_bytes` are the VRF public key bytes (Ristretto255), not Ed25519.
+/// Instances built with `ChainVrf::from_secret_bytes` can also prove.
+///
+/// The secret scalar is kept as bytes and the key rebuilt per proof; with the `zeroize` feature
+/// those bytes are wiped on drop.
+pub struct ChainVrf {
+    pk_bytes: [u8; 32],
+    #[cfg(feature = "vrf-r255")]
+    sk_bytes: Option<[u8; 32]>,
+}
+
+/// Construct a VRF verifier instance from a 32-byte VRF public key.
+#[must_use]
+pub const fn mk_chain_vrf(pk_bytes: [u8; 32]) -> ChainVrf {
+    ChainVrf {
+        pk_bytes,
+        #[cfg(feature = "vrf-r255")]
+        sk_bytes: None,
+    }
+}
+
+#[cfg(all(feature = "vrf-r255", feature = "zeroize"))]
+impl Drop for ChainVrf {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.sk_bytes);
+    }
+}
+
+#[cfg(all(feature = "vrf-r255", feature = "zeroize"))]
+impl zeroize::ZeroizeOnDrop for ChainVrf {}
+
+#[cfg(feature = "vrf-r255")]
+impl ChainVrf {
+    /// Construct a proving instance from a 32-byte VRF secret key (Ristretto255 scalar).
+    ///
+    /// # Errors
+    /// Returns `Step1Error::DecodeError` if the bytes are not a canonical scalar.
+    pub fn from_secret_bytes(sk_bytes: &[u8; 32]) -> Result<Self, Step1Error> {
+        let sk = vrf_r255::SecretKey::from_bytes(*sk_bytes)
+            .into_option()
+            .ok_or(Step1Error::DecodeError("VRF secret key"))?;
+        let pk_bytes = vrf_r255::PublicKey::from(sk).to_bytes();
+        Ok(Self {
+            pk_bytes,
+            sk_bytes: Some(*sk_bytes),
+        })
+    }
+
+    /// VRF public key bytes this instance verifies against.
+    #[must_use]
+    pub const fn public_key(&self) -> [u8; 32] {
+        self.pk_bytes
+    }
+
+    /// Prove α (must be `ALPHA_LEN` bytes, see `build_alpha`): returns π (80 bytes) and
+    /// y (64 bytes) such that `verify(alpha, &π)` yields the same y.
+    ///
+    /// # Errors
+    /// Returns `Step1Error::InvalidLength` for a wrong α size and `Step1Error::MissingSecretKey`
+    /// for a verify-only instance.
+    pub fn prove(&self, alpha: &[u8]) -> Result<(VrfProof, VrfOutput), Step1Error> {
+        if alpha.len() != ALPHA_LEN {
+            return Err(Step1Error::InvalidLength {
+                expected: ALPHA_LEN,
+                got: alpha.len(),
+            });
+        }
+        let sk_bytes = self.sk_bytes.ok_or(Step1Error::MissingSecretKey)?;
+        let sk = vrf_r255::SecretKey::from_bytes(sk_bytes)
+            .into_option()
+            .ok_or(Step1Error::DecodeError("VRF secret key"))?;
+        let proof = sk.prove(alpha);
+        let output = vrf_r255::PublicKey::from(sk)
+            .verify(alpha, &proof)
+            .into_option()
+            .ok_or(Step1Error::InvalidProof)?;
+        Ok((VrfProof(proof.to_bytes()), VrfOutput(output)))
+    }
+}
+
+impl Vrf for ChainVrf {
+    fn verify(&self, alpha: &[u8], proof: &VrfProof) -> Result<VrfOutput, Step1Error> {
+        if alpha.len() != ALPHA_LEN {
+            return Err(Step1Error::InvalidLength {
+                expected: ALPHA_LEN,
+                got: alpha.len(),
+            });
+        }
+
+        #[cfg(not(feature = "vrf-r255"))]
+        {
+            compile_error!("ChainVrf requires the 'vrf-r255' feature to be enabled");
+        }
+
+        #[cfg(feature = "vrf-r255")]
+        {
+            let Some(pk) = vrf_r255::PublicKey::from_bytes(self.pk_bytes) else {
+                return Err(Step1Error::InvalidProof);
+            };
+
+            let mut proof_arr = [0u8; 80];
+            proof_arr.copy_from_slice(&proof.0);
+            let Some(proof) = vrf_r255::Proof::from_bytes(proof_arr) else {
+                return Err(Step1Error::InvalidProof);
+            };
+
+            let verified = pk.verify(alpha, &proof);
+            let Some(output) = verified.into_option() else {
+                return Err(Step1Error::InvalidProof);
+            };
+            return Ok(VrfOutput(output));
+        }
+
+        #[allow(unreachable_code)]
+        Err(Step1Error::InvalidProof)
+    }
+}
+
+#[cfg(all(test, feature = "vrf-r255"))]
+mod tests {
+    use super::*;
+    use crate::{
+        ser::build_alpha,
+        types::{ChainId, EpochNonce},
+    };
+
+    #[test]
+    fn prove_verify_roundtrip_over_alpha() {
+        let prover = ChainVrf::from_secret_bytes(&[7u8; 32]).expect("valid scalar");
+        let alpha = build_alpha(&ChainId([1u8; 32]), 42, &EpochNonce([9u8; 32]));
+        assert_eq!(alpha.len(), ALPHA_LEN);
+
+        let (pi, y) = prover.prove(&alpha).expect("prove");
+        let verifier = mk_chain_vrf(prover.public_key());
+        let y_verified = verifier.verify(&alpha, &pi).expect("verify");
+        assert_eq!(y_verified.0, y.0);
+
+        let mut other = alpha;
+        other[ALPHA_LEN - 1] ^= 1;
+        assert!(verifier.verify(&other, &pi).is_err());
+        assert!(matches!(
+            verifier.prove(&alpha),
+            Err(Step1Error::MissingSecretKey)
+        ));
+        assert!(matches!(
+            prover.prove(&alpha[..ALPHA_LEN - 1]),
+            Err(Step1Error::InvalidLength { .. })
+        ));
+    }
+}
@@ -1,8 +1,8 @@
 //! Property-based tests for Obex Engine I
 
+use ed25519_dalek::{Signer, SigningKey};
 use obex_engine_i::*;
 use proptest::prelude::*;
-use ed25519_dalek::{SigningKey, Signer};
 use rand_core::OsRng;
 
 // Property test: Merkle path verification should be deterministic
@@ -15,7 +15,7 @@ proptest! {
     ) {
         let path = MerklePath { path: path_data };
         let root = MerkleRoot([0u8; 32]); // Dummy root
-        
+
         // Verification should be deterministic
         let result1 = verify_merkle_path(index, &leaf, &path, &root);
         let result2 = verify_merkle_path(index, &leaf, &path, &root);
@@ -39,7 +39,7 @@ proptest! {
         let signing_key = SigningKey::generate(&mut OsRng);
         let verifying_key = signing_key.verifying_key();
         let dummy_sig = signing_key.sign(b"test message");
-        
+
         let _reg = Registration {
             chain_id: &ChainId(chain_id),
             epoch_number,
@@ -51,7 +51,7 @@ proptest! {
             sig: &dummy_sig,
             root: &MerkleRoot(root),
         };
-        
+
         // Challenge derivation should be deterministic
         let challenge_seed1 = build_challenge_seed(
             &EpochHash(epoch_hash),
@@ -65,7 +65,7 @@ proptest! {
             &verifying_key,
             &MerkleRoot(root)
         );
-        
+
         prop_assert_eq!(challenge_seed1, challenge_seed2);
     }
 }
@@ -89,12 +89,12 @@ proptest! {
             valid_from,
             valid_to,
         };
-        
+
         // Use the ticket to compute validity and ensure binding
         let is_valid_time = current_time >= ticket.valid_from && current_time <= ticket.valid_to;
         let expected_valid = valid_from <= valid_to && is_valid_time;
-        
-        // This is a simplified check - in real implementation, 
+
+        // This is a simplified check - in real implementation,
         // ticket validation would involve signature verification
         prop_assert_eq!(is_valid_time, expected_valid);
     }
@@ -121,7 +121,7 @@ proptest! {
         let vrf_proof_wrapped = VrfProof(vrf_proof_array);
         let epoch_hash_wrapped = MerkleRoot(epoch_hash);
         let root_wrapped = MerkleRoot(root);
-        
+
         // Verify that wrapped values preserve the original data
         prop_assert_eq!(chain_id_wrapped.0, chain_id);
         prop_assert_eq!(epoch_nonce_wrapped.0, epoch_nonce);
@@ -130,4 +130,4 @@ proptest! {
         prop_assert_eq!(epoch_hash_wrapped.0, epoch_hash);
         prop_assert_eq!(root_wrapped.0, root);
     }
-}
\ No newline at end of file
+}
@@ -50,19 +50,12 @@ pub const LEN_U32: usize = 4;
 pub const LEN_U64: usize = 8;
 pub const LEN_U128: usize = 16;
 
-// Length-framed, domain-tagged SHA3-256
+// Length-framed, domain-tagged SHA3-256 (delegates to the canonical crate-level `h_tag`).
 #[inline]
 #[must_use]
 pub fn h_tag(tag: &str, parts: &[&[u8]]) -> Hash256 {
     debug_assert!(OBEX_SHA3_TAGS.contains(&tag));
-    let mut buf = Vec::with_capacity(64);
-    buf.extend_from_slice(tag.as_bytes());
-    for p in parts {
-        let len = (p.len() as u64).to_le_bytes();
-        buf.extend_from_slice(&len);
-        buf.extend_from_slice(p);
-    }
-    sha3_256(&buf)
+    crate::h_tag(tag, parts)
 }
 
 // Plug in your real SHA3-256 here:
@@ -76,37 +69,22 @@ pub fn sha3_256(input: &[u8]) -> Hash256 {
     hash
 }
 
-// Binary Merkle with duplicate-last
+// Binary Merkle with duplicate-last: single hashing path shared with the crate root,
+// so every caller commits under the same `constants::TAG_MERKLE_*` domain tags.
 #[inline]
 #[must_use]
 pub fn merkle_leaf(payload: &[u8]) -> Hash256 {
-    h_tag("merkle.leaf", &[payload])
+    crate::merkle_leaf(payload)
 }
 
 #[inline]
 #[must_use]
 pub fn merkle_node(l: &Hash256, r: &Hash256) -> Hash256 {
-    let mut cat = [0u8; 64];
-    cat[..32].copy_from_slice(l);
-    cat[32..].copy_from_slice(r);
-    h_tag("merkle.node", &[&cat])
+    crate::merkle_node(l, r)
 }
 
+#[cfg(feature = "alloc")]
 #[must_use]
 pub fn merkle_root(leaves_payload: &[Vec<u8>]) -> Hash256 {
-    if leaves_payload.is_empty() {
-        return h_tag("merkle.empty", &[]);
-    }
-    let mut lvl: Vec<Hash256> = leaves_payload.iter().map(|p| merkle_leaf(p)).collect();
-    while lvl.len() > 1 {
-        if lvl.len() & 1 == 1 {
-            lvl.push(*lvl.last().unwrap());
-        }
-        let mut nxt = Vec::with_capacity(lvl.len() / 2);
-        for i in (0..lvl.len()).step_by(2) {
-            nxt.push(merkle_node(&lvl[i], &lvl[i + 1]));
-        }
-        lvl = nxt;
-    }
-    lvl[0]
+    crate::merkle_root(leaves_payload)
 }
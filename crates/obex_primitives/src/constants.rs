@@ -35,3 +35,102 @@ pub const TAG_TICKET_LEAF: &str = "obex.ticket.leaf";
 pub const TAG_SYS_TX: &str = "obex.sys.tx";
 pub const TAG_REWARD_DRAW: &str = "obex.reward.draw";
 pub const TAG_REWARD_RANK: &str = "obex.reward.rank";
+
+/// Typed consensus domain tag: one variant per `TAG_*` constant, so tag choice is checked at
+/// compile time (see `h_tag_domain`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DomainTag {
+    MerkleLeaf,
+    MerkleNode,
+    MerkleEmpty,
+    Alpha,
+    Seed,
+    L0,
+    Lbl,
+    Idx,
+    Chal,
+    PartLeaf,
+    Partrec,
+    Vrfy,
+    HeaderId,
+    SlotSeed,
+    VdfYcore,
+    VdfEdge,
+    TxAccess,
+    TxBodyV1,
+    TxId,
+    TxCommit,
+    TxSig,
+    TxidLeaf,
+    TicketId,
+    TicketLeaf,
+    SysTx,
+    RewardDraw,
+    RewardRank,
+}
+
+impl DomainTag {
+    pub const ALL: [Self; 27] = [
+        Self::MerkleLeaf,
+        Self::MerkleNode,
+        Self::MerkleEmpty,
+        Self::Alpha,
+        Self::Seed,
+        Self::L0,
+        Self::Lbl,
+        Self::Idx,
+        Self::Chal,
+        Self::PartLeaf,
+        Self::Partrec,
+        Self::Vrfy,
+        Self::HeaderId,
+        Self::SlotSeed,
+        Self::VdfYcore,
+        Self::VdfEdge,
+        Self::TxAccess,
+        Self::TxBodyV1,
+        Self::TxId,
+        Self::TxCommit,
+        Self::TxSig,
+        Self::TxidLeaf,
+        Self::TicketId,
+        Self::TicketLeaf,
+        Self::SysTx,
+        Self::RewardDraw,
+        Self::RewardRank,
+    ];
+
+    /// Exact ASCII tag string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::MerkleLeaf => TAG_MERKLE_LEAF,
+            Self::MerkleNode => TAG_MERKLE_NODE,
+            Self::MerkleEmpty => TAG_MERKLE_EMPTY,
+            Self::Alpha => TAG_ALPHA,
+            Self::Seed => TAG_SEED,
+            Self::L0 => TAG_L0,
+            Self::Lbl => TAG_LBL,
+            Self::Idx => TAG_IDX,
+            Self::Chal => TAG_CHAL,
+            Self::PartLeaf => TAG_PART_LEAF,
+            Self::Partrec => TAG_PARTREC,
+            Self::Vrfy => TAG_VRFY,
+            Self::HeaderId => TAG_HEADER_ID,
+            Self::SlotSeed => TAG_SLOT_SEED,
+            Self::VdfYcore => TAG_VDF_YCORE,
+            Self::VdfEdge => TAG_VDF_EDGE,
+            Self::TxAccess => TAG_TX_ACCESS,
+            Self::TxBodyV1 => TAG_TX_BODY_V1,
+            Self::TxId => TAG_TX_ID,
+            Self::TxCommit => TAG_TX_COMMIT,
+            Self::TxSig => TAG_TX_SIG,
+            Self::TxidLeaf => TAG_TXID_LEAF,
+            Self::TicketId => TAG_TICKET_ID,
+            Self::TicketLeaf => TAG_TICKET_LEAF,
+            Self::SysTx => TAG_SYS_TX,
+            Self::RewardDraw => TAG_REWARD_DRAW,
+            Self::RewardRank => TAG_REWARD_RANK,
+        }
+    }
+}
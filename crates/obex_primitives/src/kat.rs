@@ -0,0 +1,172 @@
+// ========== kat.rs (known-answer vector output) ==========
+//
+// Stable textual forms for golden vectors shared across obex.α crates and other
+// implementations: one `LABEL:hex` line per value, or a canonical JSON object.
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{collections::BTreeMap, string::String};
+#[cfg(feature = "std")]
+use std::{collections::BTreeMap, string::String};
+
+use crate::Hash256;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+fn push_hex(out: &mut String, bytes: &[u8]) {
+    for b in bytes {
+        out.push(char::from(HEX[usize::from(b >> 4)]));
+        out.push(char::from(HEX[usize::from(b & 0x0f)]));
+    }
+}
+
+/// Lowercase hex of `bytes`.
+#[must_use]
+pub fn to_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    push_hex(&mut out, bytes);
+    out
+}
+
+/// Lowercase 64-character hex of a digest; the form used by golden `.hex` files.
+#[cfg(feature = "hex")]
+#[must_use]
+pub fn hash_to_hex(h: &Hash256) -> String {
+    to_hex(h)
+}
+
+/// Why `hash_from_hex` rejected its input.
+#[cfg(feature = "hex")]
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum HexParseError {
+    #[error("expected 64 hex characters, got {0}")]
+    BadLength(usize),
+    #[error("non-hex character at byte offset {0}")]
+    BadDigit(usize),
+}
+
+#[cfg(feature = "hex")]
+const fn hex_val(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parse exactly 64 hex characters (either case, no prefix or whitespace) into a digest.
+#[cfg(feature = "hex")]
+pub fn hash_from_hex(s: &str) -> Result<Hash256, HexParseError> {
+    let b = s.as_bytes();
+    if b.len() != 64 {
+        return Err(HexParseError::BadLength(b.len()));
+    }
+    let mut out = [0u8; 32];
+    for (i, o) in out.iter_mut().enumerate() {
+        let hi = hex_val(b[2 * i]).ok_or(HexParseError::BadDigit(2 * i))?;
+        let lo = hex_val(b[2 * i + 1]).ok_or(HexParseError::BadDigit(2 * i + 1))?;
+        *o = (hi << 4) | lo;
+    }
+    Ok(out)
+}
+
+/// `LABEL:hex` line (no trailing newline) for arbitrary bytes.
+#[must_use]
+pub fn dump_bytes(label: &str, bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(label.len() + 1 + bytes.len() * 2);
+    out.push_str(label);
+    out.push(':');
+    push_hex(&mut out, bytes);
+    out
+}
+
+/// `LABEL:hex` line for a 32-byte digest.
+#[must_use]
+pub fn dump_hash(label: &str, h: &Hash256) -> String {
+    dump_bytes(label, h)
+}
+
+fn push_json_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if u32::from(c) < 0x20 => {
+                out.push_str("\\u00");
+                push_hex(out, &[u8::try_from(u32::from(c)).unwrap_or(0)]);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Labeled KAT entries, kept sorted by label; re-adding a label replaces its value.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KatSet {
+    entries: BTreeMap<String, String>,
+}
+
+impl KatSet {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_bytes(&mut self, label: &str, bytes: &[u8]) {
+        self.entries.insert(String::from(label), to_hex(bytes));
+    }
+
+    pub fn add_hash(&mut self, label: &str, h: &Hash256) {
+        self.add_bytes(label, h);
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Hex value recorded under `label`.
+    #[must_use]
+    pub fn get(&self, label: &str) -> Option<&str> {
+        self.entries.get(label).map(String::as_str)
+    }
+
+    /// One `LABEL:hex` line per entry in label order, each newline-terminated.
+    #[must_use]
+    pub fn to_lines(&self) -> String {
+        let mut out = String::new();
+        for (label, hex) in &self.entries {
+            out.push_str(label);
+            out.push(':');
+            out.push_str(hex);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Canonical JSON: a single object, keys in byte order, lowercase hex string values,
+    /// no whitespace.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{");
+        for (i, (label, hex)) in self.entries.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            push_json_str(&mut out, label);
+            out.push(':');
+            push_json_str(&mut out, hex);
+        }
+        out.push('}');
+        out
+    }
+}
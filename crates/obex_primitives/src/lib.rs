@@ -14,7 +14,7 @@
 )]
 
 //! Obex alpha primitives: hashing, fixed-width little-endian encodings, binary Merkle trees.
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
 extern crate alloc;
 //
@@ -45,12 +45,18 @@ pub type Sig64 = [u8; 64];
 
 pub mod consensus;
 pub mod constants;
+#[cfg(feature = "alloc")]
+pub mod kat;
+#[cfg(feature = "hex")]
+pub use kat::{hash_from_hex, hash_to_hex, HexParseError};
 
 /// Convert an unsigned integer to fixed-width little-endian bytes.
 ///
-/// The output is exactly `W` bytes (no overlong encodings).
+/// The output is exactly `W` bytes (no overlong encodings). Bytes above `W` are dropped;
+/// debug builds assert that none are set. Use `le_bytes_checked` where `x` may not fit.
 #[must_use]
 pub fn le_bytes<const W: usize>(mut x: u128) -> [u8; W] {
+    debug_assert!(fits_width::<W>(x), "le_bytes::<{W}> truncates {x}");
     let mut out = [0u8; W];
     let mut i = 0usize;
     while i < W {
@@ -61,6 +67,28 @@ pub fn le_bytes<const W: usize>(mut x: u128) -> [u8; W] {
     out
 }
 
+#[inline]
+const fn fits_width<const W: usize>(x: u128) -> bool {
+    W >= 16 || x >> (8 * W) == 0
+}
+
+/// As `le_bytes`, but `None` when `x >= 2^(8*W)` instead of truncating.
+#[must_use]
+pub fn le_bytes_checked<const W: usize>(x: u128) -> Option<[u8; W]> {
+    fits_width::<W>(x).then(|| le_bytes::<W>(x))
+}
+
+/// A length that does not fit a canonical `LE(len, 4)` prefix.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+#[error("length {0} exceeds the u32 length prefix")]
+pub struct LenPrefixOverflow(pub usize);
+
+/// `LE(len, 4)` length prefix of the canonical codecs; an error rather than a truncated
+/// prefix for lengths `>= 2^32`.
+pub fn le_len4(len: usize) -> Result<[u8; 4], LenPrefixOverflow> {
+    le_bytes_checked::<4>(len as u128).ok_or(LenPrefixOverflow(len))
+}
+
 /// Read a `u64` from the first 8 bytes of a little-endian byte slice.
 #[must_use]
 pub fn u64_from_le(b: &[u8]) -> u64 {
@@ -77,22 +105,334 @@ pub fn u64_from_le(b: &[u8]) -> u64 {
 /// `H(tag_ascii, parts[])` = `SHA3_256`( UTF8(tag) || Σ ( LE(|p|,8) || p ) )
 #[must_use]
 pub fn h_tag(tag: &str, parts: &[&[u8]]) -> Hash256 {
-    // Assert that consensus tags are all within the `obex.` namespace in debug builds.
-    debug_assert!(
-        tag.starts_with("obex."),
-        "non-obex.* tag used in consensus hashing: {tag}"
-    );
-    let mut hasher = Sha3_256::new();
-    hasher.update(tag.as_bytes());
+    let mut st = HashTag::new(tag);
     for p in parts {
-        let len_le = le_bytes::<8>(p.len() as u128);
-        hasher.update(len_le);
-        hasher.update(p);
+        st.update(p);
     }
-    let digest = hasher.finalize();
-    let mut out = [0u8; 32];
-    out.copy_from_slice(&digest);
-    out
+    st.finalize()
+}
+
+/// `h_tag` over a typed `DomainTag`; identical digest to `h_tag(tag.as_str(), parts)`.
+#[must_use]
+pub fn h_tag_domain(tag: constants::DomainTag, parts: &[&[u8]]) -> Hash256 {
+    h_tag(tag.as_str(), parts)
+}
+
+/// A 32-byte streaming hash that `h_tag_with` can frame in place of SHA3-256.
+///
+/// For benchmarking alternative primitives under the exact `h_tag` framing; consensus hashing
+/// (`h_tag`, `HashTag`, Merkle) is always SHA3-256.
+pub trait HashBackend: Default {
+    fn update(&mut self, bytes: &[u8]);
+    fn finalize(self) -> Hash256;
+}
+
+/// The consensus backend: SHA3-256.
+#[derive(Clone, Debug, Default)]
+pub struct Sha3Backend(Sha3_256);
+
+impl HashBackend for Sha3Backend {
+    fn update(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn finalize(self) -> Hash256 {
+        let digest = self.0.finalize();
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&digest);
+        out
+    }
+}
+
+/// `h_tag` framing over backend `B`: `B( UTF8(tag) || Σ ( LE(|p|,8) || p ) )`.
+/// `h_tag_with::<Sha3Backend>` equals `h_tag`.
+#[must_use]
+pub fn h_tag_with<B: HashBackend>(tag: &str, parts: &[&[u8]]) -> Hash256 {
+    let mut b = B::default();
+    b.update(tag.as_bytes());
+    for p in parts {
+        b.update(&le_bytes::<8>(p.len() as u128));
+        b.update(p);
+    }
+    b.finalize()
+}
+
+/// Incremental form of `h_tag`: feeds parts one at a time with the same `LE(|p|,8) || p`
+/// framing, producing byte-identical digests without assembling a `&[&[u8]]`.
+///
+/// A part whose length is known up front can also be streamed in chunks with `begin_part`
+/// followed by `write`; its chunks must total exactly the declared length.
+#[derive(Clone, Debug)]
+pub struct HashTag {
+    hasher: Sha3_256,
+    pending: u64,
+}
+
+impl HashTag {
+    #[must_use]
+    pub fn new(tag: &str) -> Self {
+        // Assert that consensus tags are all within the `obex.` namespace in debug builds.
+        debug_assert!(
+            tag.starts_with("obex."),
+            "non-obex.* tag used in consensus hashing: {tag}"
+        );
+        let mut hasher = Sha3_256::new();
+        hasher.update(tag.as_bytes());
+        Self { hasher, pending: 0 }
+    }
+
+    /// Append one complete part.
+    pub fn update(&mut self, part: &[u8]) -> &mut Self {
+        self.begin_part(part.len() as u64);
+        self.write(part)
+    }
+
+    /// Start a part of `len` bytes to be supplied through `write`.
+    pub fn begin_part(&mut self, len: u64) -> &mut Self {
+        assert_eq!(self.pending, 0, "previous part not completed");
+        self.hasher.update(le_bytes::<8>(u128::from(len)));
+        self.pending = len;
+        self
+    }
+
+    /// Append bytes of the part opened by `begin_part`.
+    pub fn write(&mut self, chunk: &[u8]) -> &mut Self {
+        let n = chunk.len() as u64;
+        assert!(n <= self.pending, "write exceeds declared part length");
+        self.hasher.update(chunk);
+        self.pending -= n;
+        self
+    }
+
+    #[must_use]
+    pub fn finalize(self) -> Hash256 {
+        assert_eq!(self.pending, 0, "part shorter than declared length");
+        let digest = self.hasher.finalize();
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&digest);
+        out
+    }
+}
+
+/// Compute the Merkle leaf hash of a payload using the shared leaf domain tag.
+#[must_use]
+pub fn merkle_leaf(payload: &[u8]) -> Hash256 {
+    h_tag(constants::TAG_MERKLE_LEAF, &[payload])
+}
+
+/// Compute the Merkle node hash from two child node hashes using the shared node domain tag.
+#[must_use]
+pub fn merkle_node(left: &Hash256, right: &Hash256) -> Hash256 {
+    let mut cat = [0u8; 64];
+    cat[..32].copy_from_slice(left);
+    cat[32..].copy_from_slice(right);
+    h_tag(constants::TAG_MERKLE_NODE, &[&cat])
+}
+
+/// Compute the binary Merkle root. When the number of nodes at a level is odd,
+/// the last node is duplicated. The empty tree root is `H("obex.merkle.empty", [])`.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn merkle_root(leaves_payload: &[Vec<u8>]) -> Hash256 {
+    if leaves_payload.is_empty() {
+        return h_tag(constants::TAG_MERKLE_EMPTY, &[]);
+    }
+    let mut level: Vec<Hash256> = leaves_payload.iter().map(|p| merkle_leaf(p)).collect();
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            if let Some(last) = level.last().copied() {
+                level.push(last);
+            }
+        }
+        let mut next: Vec<Hash256> = Vec::with_capacity(level.len() / 2);
+        let mut i = 0usize;
+        while i < level.len() {
+            next.push(merkle_node(&level[i], &level[i + 1]));
+            i += 2;
+        }
+        level = next;
+    }
+    // length >= 1
+    level[0]
+}
+
+/// A Merkle authentication path for a leaf at `index`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerklePath {
+    /// Sibling hashes from leaf to root.
+    pub siblings: Vec<Hash256>,
+    /// Leaf index in the tree (0-based).
+    pub index: u64,
+}
+
+/// Why a Merkle inclusion proof was rejected.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum MerkleVerifyError {
+    /// The path does not have one sibling per level of a tree of known depth.
+    #[error("path has {got} siblings, tree depth is {expected}")]
+    PathLength { expected: usize, got: usize },
+    /// `index` has set bits above the path length, so the path cannot reach that leaf.
+    #[error("index {index} out of range for a path of {depth} siblings")]
+    IndexOutOfRange { index: u64, depth: usize },
+    /// The folded root differs from the expected root.
+    #[error("computed root does not match")]
+    RootMismatch,
+}
+
+/// Verify a Merkle leaf payload against the supplied root with the given path.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn merkle_verify_leaf(root: &Hash256, leaf_payload: &[u8], path: &MerklePath) -> bool {
+    merkle_verify_leaf_checked(root, leaf_payload, path).is_ok()
+}
+
+/// `merkle_verify_leaf` reporting which check failed.
+#[cfg(feature = "alloc")]
+pub fn merkle_verify_leaf_checked(
+    root: &Hash256,
+    leaf_payload: &[u8],
+    path: &MerklePath,
+) -> Result<(), MerkleVerifyError> {
+    verify_leaf_slice_checked(root, leaf_payload, &path.siblings, path.index)
+}
+
+/// Root implied by folding `leaf_payload` up `siblings` (leaf to root) at leaf `index`.
+#[must_use]
+pub fn merkle_path_root(leaf_payload: &[u8], siblings: &[Hash256], index: u64) -> Hash256 {
+    let mut h = merkle_leaf(leaf_payload);
+    let mut idx = index;
+    for sib in siblings {
+        h = if idx & 1 == 0 {
+            merkle_node(&h, sib)
+        } else {
+            merkle_node(sib, &h)
+        };
+        idx >>= 1;
+    }
+    h
+}
+
+/// `merkle_verify_leaf` over borrowed siblings; needs no `MerklePath` and no allocation.
+///
+/// Rejects an `index` with set bits above `siblings.len()`: such a path cannot reach that
+/// leaf position, and folding would silently ignore the extra bits.
+#[must_use]
+pub fn merkle_verify_leaf_slice(
+    root: &Hash256,
+    leaf_payload: &[u8],
+    siblings: &[Hash256],
+    index: u64,
+) -> bool {
+    verify_leaf_slice_checked(root, leaf_payload, siblings, index).is_ok()
+}
+
+fn verify_leaf_slice_checked(
+    root: &Hash256,
+    leaf_payload: &[u8],
+    siblings: &[Hash256],
+    index: u64,
+) -> Result<(), MerkleVerifyError> {
+    if !index_fits_depth(index, siblings.len()) {
+        return Err(MerkleVerifyError::IndexOutOfRange {
+            index,
+            depth: siblings.len(),
+        });
+    }
+    if ct_eq_hash(root, &merkle_path_root(leaf_payload, siblings, index)) {
+        Ok(())
+    } else {
+        Err(MerkleVerifyError::RootMismatch)
+    }
+}
+
+/// Depth of the tree `merkle_root` builds over `n_leaves` leaves: `ceil(log2(n_leaves))`.
+#[must_use]
+pub const fn merkle_depth(n_leaves: usize) -> usize {
+    if n_leaves <= 1 {
+        0
+    } else {
+        n_leaves.next_power_of_two().trailing_zeros() as usize
+    }
+}
+
+#[inline]
+const fn index_fits_depth(index: u64, depth: usize) -> bool {
+    depth >= 64 || index >> depth == 0
+}
+
+/// Inclusion proof against a tree of known depth: requires `siblings.len() == tree_depth`
+/// and `index < 2^tree_depth` before folding, so a truncated path for a deep index fails.
+#[must_use]
+pub fn merkle_verify_leaf_at_depth(
+    root: &Hash256,
+    leaf_payload: &[u8],
+    siblings: &[Hash256],
+    index: u64,
+    tree_depth: usize,
+) -> bool {
+    merkle_verify_leaf_at_depth_checked(root, leaf_payload, siblings, index, tree_depth).is_ok()
+}
+
+/// `merkle_verify_leaf_at_depth` reporting which check failed; checks run in the order
+/// length, index bounds, root.
+pub fn merkle_verify_leaf_at_depth_checked(
+    root: &Hash256,
+    leaf_payload: &[u8],
+    siblings: &[Hash256],
+    index: u64,
+    tree_depth: usize,
+) -> Result<(), MerkleVerifyError> {
+    if siblings.len() != tree_depth {
+        return Err(MerkleVerifyError::PathLength {
+            expected: tree_depth,
+            got: siblings.len(),
+        });
+    }
+    verify_leaf_slice_checked(root, leaf_payload, siblings, index)
+}
+
+/// Authentication path for leaf `index` of the tree `merkle_root(leaves_payload)` builds
+/// (odd levels duplicate their last node). `None` if `index` is out of range.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn merkle_prove(leaves_payload: &[Vec<u8>], index: usize) -> Option<MerklePath> {
+    if index >= leaves_payload.len() {
+        return None;
+    }
+    let mut level: Vec<Hash256> = leaves_payload.iter().map(|p| merkle_leaf(p)).collect();
+    let mut siblings = Vec::new();
+    let mut i = index;
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            if let Some(last) = level.last().copied() {
+                level.push(last);
+            }
+        }
+        siblings.push(level[i ^ 1]);
+        level = level
+            .chunks_exact(2)
+            .map(|pair| merkle_node(&pair[0], &pair[1]))
+            .collect();
+        i >>= 1;
+    }
+    Some(MerklePath {
+        siblings,
+        index: index as u64,
+    })
+}
+
+/// Constant-time equality for two 32-byte hashes.
+#[must_use]
+pub fn ct_eq_hash(a: &Hash256, b: &Hash256) -> bool {
+    a.ct_eq(b).into()
+}
+
+/// Constant-time equality for byte slices of equal length; a length mismatch returns `false`
+/// immediately (lengths are treated as public).
+#[must_use]
+pub fn ct_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && bool::from(a.ct_eq(b))
 }
 
 #[cfg(test)]
@@ -176,79 +516,7 @@ mod tag_asserts {
     }
 }
 
-/// Compute the Merkle leaf hash of a payload using the shared leaf domain tag.
-#[must_use]
-pub fn merkle_leaf(payload: &[u8]) -> Hash256 {
-    h_tag(constants::TAG_MERKLE_LEAF, &[payload])
-}
-
-/// Compute the Merkle node hash from two child node hashes using the shared node domain tag.
-#[must_use]
-pub fn merkle_node(left: &Hash256, right: &Hash256) -> Hash256 {
-    let mut cat = [0u8; 64];
-    cat[..32].copy_from_slice(left);
-    cat[32..].copy_from_slice(right);
-    h_tag(constants::TAG_MERKLE_NODE, &[&cat])
-}
-
-/// Compute the binary Merkle root. When the number of nodes at a level is odd,
-/// the last node is duplicated. The empty tree root is `H("obex.merkle.empty", [])`.
-#[must_use]
-pub fn merkle_root(leaves_payload: &[Vec<u8>]) -> Hash256 {
-    if leaves_payload.is_empty() {
-        return h_tag(constants::TAG_MERKLE_EMPTY, &[]);
-    }
-    let mut level: Vec<Hash256> = leaves_payload.iter().map(|p| merkle_leaf(p)).collect();
-    while level.len() > 1 {
-        if level.len() % 2 == 1 {
-            if let Some(last) = level.last().copied() {
-                level.push(last);
-            }
-        }
-        let mut next: Vec<Hash256> = Vec::with_capacity(level.len() / 2);
-        let mut i = 0usize;
-        while i < level.len() {
-            next.push(merkle_node(&level[i], &level[i + 1]));
-            i += 2;
-        }
-        level = next;
-    }
-    // length >= 1
-    level[0]
-}
-
-/// A Merkle authentication path for a leaf at `index`.
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct MerklePath {
-    /// Sibling hashes from leaf to root.
-    pub siblings: Vec<Hash256>,
-    /// Leaf index in the tree (0-based).
-    pub index: u64,
-}
-
-/// Verify a Merkle leaf payload against the supplied root with the given path.
-#[must_use]
-pub fn merkle_verify_leaf(root: &Hash256, leaf_payload: &[u8], path: &MerklePath) -> bool {
-    let mut h = merkle_leaf(leaf_payload);
-    let mut idx = path.index;
-    for sib in &path.siblings {
-        h = if idx & 1 == 0 {
-            merkle_node(&h, sib)
-        } else {
-            merkle_node(sib, &h)
-        };
-        idx >>= 1;
-    }
-    ct_eq_hash(root, &h)
-}
-
-/// Constant-time equality for two 32-byte hashes.
-#[must_use]
-pub fn ct_eq_hash(a: &Hash256, b: &Hash256) -> bool {
-    a.ct_eq(b).into()
-}
-
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 #[allow(
     clippy::too_many_lines,
     clippy::needless_pass_by_value,
@@ -276,4 +544,26 @@ mod tests {
         let root_swapped = merkle_root(&[vec![0xBBu8; 5], vec![0xAAu8; 3]]);
         assert!(!ct_eq_hash(&root, &root_swapped));
     }
+
+    #[test]
+    fn consensus_merkle_matches_crate_root() {
+        let inputs: [Vec<Vec<u8>>; 5] = [
+            vec![],
+            vec![vec![]],
+            vec![vec![0x01u8; 7]],
+            vec![vec![0xAAu8; 3], vec![0xBBu8; 5], vec![0xCCu8; 32]],
+            (0u8..9).map(|i| vec![i; usize::from(i) + 1]).collect(),
+        ];
+        for leaves in &inputs {
+            assert_eq!(consensus::merkle_root(leaves), merkle_root(leaves));
+        }
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        assert_eq!(consensus::merkle_leaf(&a), merkle_leaf(&a));
+        assert_eq!(consensus::merkle_node(&a, &b), merkle_node(&a, &b));
+        assert_eq!(
+            consensus::h_tag(constants::TAG_SEED, &[&a, &b]),
+            h_tag(constants::TAG_SEED, &[&a, &b])
+        );
+    }
 }
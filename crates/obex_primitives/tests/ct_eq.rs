@@ -0,0 +1,26 @@
+use obex_primitives::ct_eq_bytes;
+
+#[test]
+fn ct_eq_bytes_agrees_with_eq() {
+    let inputs: [&[u8]; 6] = [
+        &[],
+        &[0u8; 64],
+        &[1u8; 64],
+        &[0xFF; 32],
+        &[0xFF; 33],
+        b"obex vrf output",
+    ];
+    for a in inputs {
+        for b in inputs {
+            assert_eq!(ct_eq_bytes(a, b), a == b, "{a:?} vs {b:?}");
+        }
+    }
+    // Single-bit differences at every position of a 64-byte value
+    let y = [0x5Au8; 64];
+    for i in 0..y.len() * 8 {
+        let mut z = y;
+        z[i / 8] ^= 1 << (i % 8);
+        assert!(!ct_eq_bytes(&y, &z));
+    }
+    assert!(ct_eq_bytes(&y, &y.clone()));
+}
@@ -0,0 +1,41 @@
+use obex_primitives::constants::{self, DomainTag};
+use obex_primitives::{h_tag, h_tag_domain};
+
+#[test]
+fn domain_tag_bytes_equal_constants() {
+    let pairs = [
+        (DomainTag::MerkleLeaf, constants::TAG_MERKLE_LEAF),
+        (DomainTag::MerkleNode, constants::TAG_MERKLE_NODE),
+        (DomainTag::MerkleEmpty, constants::TAG_MERKLE_EMPTY),
+        (DomainTag::Alpha, constants::TAG_ALPHA),
+        (DomainTag::Seed, constants::TAG_SEED),
+        (DomainTag::L0, constants::TAG_L0),
+        (DomainTag::Lbl, constants::TAG_LBL),
+        (DomainTag::Idx, constants::TAG_IDX),
+        (DomainTag::Chal, constants::TAG_CHAL),
+        (DomainTag::PartLeaf, constants::TAG_PART_LEAF),
+        (DomainTag::Partrec, constants::TAG_PARTREC),
+        (DomainTag::Vrfy, constants::TAG_VRFY),
+        (DomainTag::HeaderId, constants::TAG_HEADER_ID),
+        (DomainTag::SlotSeed, constants::TAG_SLOT_SEED),
+        (DomainTag::VdfYcore, constants::TAG_VDF_YCORE),
+        (DomainTag::VdfEdge, constants::TAG_VDF_EDGE),
+        (DomainTag::TxAccess, constants::TAG_TX_ACCESS),
+        (DomainTag::TxBodyV1, constants::TAG_TX_BODY_V1),
+        (DomainTag::TxId, constants::TAG_TX_ID),
+        (DomainTag::TxCommit, constants::TAG_TX_COMMIT),
+        (DomainTag::TxSig, constants::TAG_TX_SIG),
+        (DomainTag::TxidLeaf, constants::TAG_TXID_LEAF),
+        (DomainTag::TicketId, constants::TAG_TICKET_ID),
+        (DomainTag::TicketLeaf, constants::TAG_TICKET_LEAF),
+        (DomainTag::SysTx, constants::TAG_SYS_TX),
+        (DomainTag::RewardDraw, constants::TAG_REWARD_DRAW),
+        (DomainTag::RewardRank, constants::TAG_REWARD_RANK),
+    ];
+    assert_eq!(pairs.len(), DomainTag::ALL.len());
+    for ((tag, s), all) in pairs.iter().zip(DomainTag::ALL) {
+        assert_eq!(*tag, all);
+        assert_eq!(tag.as_str().as_bytes(), s.as_bytes());
+        assert_eq!(h_tag_domain(*tag, &[b"x"]), h_tag(s, &[b"x"]));
+    }
+}
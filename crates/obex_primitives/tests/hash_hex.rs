@@ -0,0 +1,42 @@
+#![cfg(feature = "hex")]
+
+use obex_primitives::{consensus, hash_from_hex, hash_to_hex, HexParseError};
+
+#[test]
+fn hash_hex_round_trips() {
+    for h in [
+        [0u8; 32],
+        [0xFF; 32],
+        consensus::h_tag("obex.header.id", &[b"x"]),
+    ] {
+        let s = hash_to_hex(&h);
+        assert_eq!(s.len(), 64);
+        assert_eq!(s, hex::encode(h));
+        assert_eq!(hash_from_hex(&s), Ok(h));
+        assert_eq!(hash_from_hex(&s.to_uppercase()), Ok(h));
+    }
+}
+
+#[test]
+fn hash_from_hex_rejects_bad_input() {
+    let good = "ab".repeat(32);
+    assert_eq!(hash_from_hex(""), Err(HexParseError::BadLength(0)));
+    assert_eq!(
+        hash_from_hex(&good[..62]),
+        Err(HexParseError::BadLength(62))
+    );
+    assert_eq!(
+        hash_from_hex(&format!("{good}\n")),
+        Err(HexParseError::BadLength(65))
+    );
+    assert_eq!(
+        hash_from_hex(&format!("0x{}", &good[2..])),
+        Err(HexParseError::BadDigit(1))
+    );
+    let mut bad = good.into_bytes();
+    bad[40] = b'g';
+    assert_eq!(
+        hash_from_hex(core::str::from_utf8(&bad).unwrap()),
+        Err(HexParseError::BadDigit(40))
+    );
+}
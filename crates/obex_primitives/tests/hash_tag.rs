@@ -0,0 +1,81 @@
+use obex_primitives::{constants, h_tag, HashTag};
+
+#[test]
+fn streaming_three_parts_matches_h_tag() {
+    let a = [1u8; 32];
+    let b: Vec<u8> = (0..=255u8).cycle().take(70_000).collect();
+    let c: [u8; 0] = [];
+    let mut st = HashTag::new(constants::TAG_VDF_YCORE);
+    st.update(&a).update(&b).update(&c);
+    assert_eq!(
+        st.finalize(),
+        h_tag(constants::TAG_VDF_YCORE, &[&a, &b, &c])
+    );
+
+    // No parts
+    assert_eq!(
+        HashTag::new(constants::TAG_MERKLE_EMPTY).finalize(),
+        h_tag(constants::TAG_MERKLE_EMPTY, &[])
+    );
+}
+
+#[test]
+fn chunked_part_matches_whole_part() {
+    let big: Vec<u8> = (0..1_000_003u32).map(|i| (i % 251) as u8).collect();
+    let mut st = HashTag::new(constants::TAG_VDF_EDGE);
+    st.update(b"head").begin_part(big.len() as u64);
+    for chunk in big.chunks(4096) {
+        st.write(chunk);
+    }
+    st.update(b"tail");
+    assert_eq!(
+        st.finalize(),
+        h_tag(constants::TAG_VDF_EDGE, &[b"head", &big, b"tail"])
+    );
+}
+
+#[test]
+#[should_panic(expected = "part shorter than declared length")]
+fn finalize_rejects_incomplete_part() {
+    let mut st = HashTag::new(constants::TAG_VDF_EDGE);
+    st.begin_part(8).write(&[0u8; 4]);
+    let _ = st.finalize();
+}
+
+#[test]
+fn sha3_backend_matches_h_tag() {
+    use obex_primitives::{h_tag_with, Sha3Backend};
+    let big: Vec<u8> = (0..10_000u32).map(|i| (i % 253) as u8).collect();
+    let cases: [&[&[u8]]; 3] = [&[], &[b"", b"x"], &[&[7u8; 32], &big]];
+    for parts in cases {
+        for tag in [constants::TAG_MERKLE_EMPTY, constants::TAG_VDF_EDGE] {
+            assert_eq!(h_tag_with::<Sha3Backend>(tag, parts), h_tag(tag, parts));
+        }
+    }
+}
+
+#[test]
+fn backend_sees_the_h_tag_framing() {
+    use obex_primitives::{consensus::sha3_256, h_tag_with, le_bytes, Hash256, HashBackend};
+
+    /// Records the framed input and digests it in one shot at the end.
+    #[derive(Default)]
+    struct Transcript(Vec<u8>);
+    impl HashBackend for Transcript {
+        fn update(&mut self, bytes: &[u8]) {
+            self.0.extend_from_slice(bytes);
+        }
+        fn finalize(self) -> Hash256 {
+            sha3_256(&self.0)
+        }
+    }
+
+    let mut expected = b"obex.vdf.edge".to_vec();
+    expected.extend_from_slice(&le_bytes::<8>(2));
+    expected.extend_from_slice(b"ab");
+    expected.extend_from_slice(&le_bytes::<8>(0));
+    assert_eq!(
+        h_tag_with::<Transcript>(constants::TAG_VDF_EDGE, &[b"ab", b""]),
+        sha3_256(&expected)
+    );
+}
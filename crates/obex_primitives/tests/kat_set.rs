@@ -0,0 +1,50 @@
+#![cfg(feature = "alloc")]
+
+use hex::ToHex;
+use obex_primitives::kat::{dump_bytes, dump_hash, KatSet};
+use obex_primitives::{constants, h_tag};
+
+fn sample() -> KatSet {
+    let mut set = KatSet::new();
+    set.add_hash("TXROOT", &h_tag(constants::TAG_TXID_LEAF, &[]));
+    set.add_bytes("MEMO", &[0x00, 0xAB, 0xFF]);
+    set.add_hash("EMPTY_ROOT", &h_tag(constants::TAG_MERKLE_EMPTY, &[]));
+    set
+}
+
+#[test]
+fn dump_lines_are_label_colon_lower_hex() {
+    let h = h_tag(constants::TAG_MERKLE_EMPTY, &[]);
+    assert_eq!(
+        dump_hash("EMPTY_ROOT", &h),
+        format!("EMPTY_ROOT:{}", h.encode_hex::<String>())
+    );
+    assert_eq!(dump_bytes("B", &[0x0A, 0xF0]), "B:0af0");
+    assert_eq!(dump_bytes("E", &[]), "E:");
+}
+
+#[test]
+fn json_is_canonical_and_deterministic() {
+    let a = sample();
+    // Same entries inserted in a different order.
+    let mut b = KatSet::new();
+    b.add_hash("EMPTY_ROOT", &h_tag(constants::TAG_MERKLE_EMPTY, &[]));
+    b.add_bytes("MEMO", &[0x00, 0xAB, 0xFF]);
+    b.add_hash("TXROOT", &h_tag(constants::TAG_TXID_LEAF, &[]));
+    assert_eq!(a.to_json(), b.to_json());
+    assert_eq!(a.to_json(), sample().to_json());
+
+    let json = a.to_json();
+    assert!(json.starts_with("{\"EMPTY_ROOT\":\""));
+    assert!(json.contains(",\"MEMO\":\"00abff\","));
+    assert!(!json.contains(char::is_whitespace));
+    assert_eq!(a.to_lines().lines().count(), 3);
+    assert_eq!(a.get("MEMO"), Some("00abff"));
+}
+
+#[test]
+fn json_escapes_labels() {
+    let mut set = KatSet::new();
+    set.add_bytes("a\"b\\c\n", &[1]);
+    assert_eq!(set.to_json(), "{\"a\\\"b\\\\c\\u000a\":\"01\"}");
+}
@@ -0,0 +1,47 @@
+use obex_primitives::{le_bytes, le_bytes_checked};
+
+#[test]
+fn checked_boundaries_per_width() {
+    fn check<const W: usize>() {
+        let max = if W >= 16 {
+            u128::MAX
+        } else {
+            (1u128 << (8 * W)) - 1
+        };
+        assert_eq!(le_bytes_checked::<W>(0), Some([0u8; W]));
+        assert_eq!(le_bytes_checked::<W>(max), Some([0xFF; W]));
+        assert_eq!(le_bytes_checked::<W>(max), Some(le_bytes::<W>(max)));
+        if W < 16 {
+            assert_eq!(le_bytes_checked::<W>(max + 1), None);
+            assert_eq!(le_bytes_checked::<W>(u128::MAX), None);
+        }
+    }
+    check::<1>();
+    check::<2>();
+    check::<4>();
+    check::<8>();
+    check::<16>();
+    assert_eq!(le_bytes_checked::<4>(0x1_0000_0000), None);
+    assert_eq!(
+        le_bytes_checked::<4>(0x0102_0304),
+        Some([0x04, 0x03, 0x02, 0x01])
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "truncates")]
+fn unchecked_asserts_in_debug() {
+    let _ = le_bytes::<4>(0x1_0000_0000);
+}
+
+#[test]
+fn le_len4_rejects_lengths_past_u32() {
+    use obex_primitives::{le_len4, LenPrefixOverflow};
+    assert_eq!(le_len4(0), Ok([0u8; 4]));
+    assert_eq!(le_len4(0x0102_0304), Ok([0x04, 0x03, 0x02, 0x01]));
+    assert_eq!(le_len4(u32::MAX as usize), Ok([0xFF; 4]));
+    if let Some(over) = (u32::MAX as usize).checked_add(1) {
+        assert_eq!(le_len4(over), Err(LenPrefixOverflow(over)));
+    }
+}
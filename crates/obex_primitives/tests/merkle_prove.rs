@@ -0,0 +1,150 @@
+#![cfg(feature = "alloc")]
+
+use obex_primitives::{merkle_prove, merkle_root, merkle_verify_leaf};
+
+#[test]
+fn every_leaf_proves_against_root() {
+    for n in 1u8..=9 {
+        let leaves: Vec<Vec<u8>> = (0..n).map(|i| vec![i; 3]).collect();
+        let root = merkle_root(&leaves);
+        for (i, leaf) in leaves.iter().enumerate() {
+            let path = merkle_prove(&leaves, i).unwrap();
+            assert!(merkle_verify_leaf(&root, leaf, &path), "n={n} i={i}");
+            assert!(!merkle_verify_leaf(&root, &[0xFF], &path));
+        }
+        assert!(merkle_prove(&leaves, usize::from(n)).is_none());
+    }
+    assert!(merkle_prove(&[], 0).is_none());
+}
+
+#[test]
+fn slice_verify_matches_path_verify() {
+    use obex_primitives::{merkle_path_root, merkle_verify_leaf_slice};
+    let leaves: Vec<Vec<u8>> = (0u8..7).map(|i| vec![i; 5]).collect();
+    let root = merkle_root(&leaves);
+    for (i, leaf) in leaves.iter().enumerate() {
+        let path = merkle_prove(&leaves, i).unwrap();
+        assert_eq!(merkle_path_root(leaf, &path.siblings, path.index), root);
+        for index in [path.index, path.index ^ 1] {
+            let owned = obex_primitives::MerklePath {
+                siblings: path.siblings.clone(),
+                index,
+            };
+            assert_eq!(
+                merkle_verify_leaf_slice(&root, leaf, &path.siblings, index),
+                merkle_verify_leaf(&root, leaf, &owned)
+            );
+        }
+    }
+}
+
+#[test]
+fn truncated_path_for_deep_index_is_rejected() {
+    use obex_primitives::{
+        merkle_depth, merkle_path_root, merkle_verify_leaf_at_depth, merkle_verify_leaf_slice,
+    };
+    let leaves: Vec<Vec<u8>> = (0u8..8).map(|i| vec![i; 3]).collect();
+    let root = merkle_root(&leaves);
+    let depth = merkle_depth(leaves.len());
+    assert_eq!(depth, 3);
+    let path = merkle_prove(&leaves, 0).unwrap();
+    assert!(merkle_verify_leaf_at_depth(
+        &root,
+        &leaves[0],
+        &path.siblings,
+        0,
+        depth
+    ));
+
+    // A one-level tree's path for leaf 0 also folds to the root for index 0b100: the high bit is
+    // never consumed. Both checks must refuse it.
+    let two = &leaves[..2];
+    let root2 = merkle_root(two);
+    let p2 = merkle_prove(two, 0).unwrap();
+    assert_eq!(merkle_path_root(&two[0], &p2.siblings, 4), root2);
+    assert!(!merkle_verify_leaf_slice(&root2, &two[0], &p2.siblings, 4));
+    assert!(merkle_verify_leaf_slice(&root2, &two[0], &p2.siblings, 0));
+
+    // Truncated path against the deep tree: wrong length for the declared depth.
+    let short = &path.siblings[..2];
+    assert!(!merkle_verify_leaf_at_depth(
+        &root, &leaves[0], short, 4, depth
+    ));
+    assert!(!merkle_verify_leaf_at_depth(
+        &root,
+        &leaves[0],
+        &path.siblings,
+        8,
+        depth
+    ));
+    assert_eq!(merkle_depth(0), 0);
+    assert_eq!(merkle_depth(1), 0);
+    assert_eq!(merkle_depth(5), 3);
+}
+
+#[test]
+fn checked_verify_maps_each_failure_to_its_variant() {
+    use obex_primitives::{
+        merkle_verify_leaf_at_depth, merkle_verify_leaf_at_depth_checked,
+        merkle_verify_leaf_checked, MerklePath, MerkleVerifyError,
+    };
+    let leaves: Vec<Vec<u8>> = (0u8..6).map(|i| vec![i; 4]).collect();
+    let root = merkle_root(&leaves);
+    let path = merkle_prove(&leaves, 4).unwrap();
+    let depth = path.siblings.len();
+    assert_eq!(depth, 3);
+
+    assert_eq!(merkle_verify_leaf_checked(&root, &leaves[4], &path), Ok(()));
+    assert_eq!(
+        merkle_verify_leaf_checked(&root, &leaves[3], &path),
+        Err(MerkleVerifyError::RootMismatch)
+    );
+    let beyond = MerklePath {
+        index: 4 | (1 << depth),
+        ..path.clone()
+    };
+    assert_eq!(
+        merkle_verify_leaf_checked(&root, &leaves[4], &beyond),
+        Err(MerkleVerifyError::IndexOutOfRange { index: 12, depth })
+    );
+    // No siblings: only index 0 of a one-leaf tree is reachable.
+    let empty = MerklePath {
+        siblings: vec![],
+        index: 1,
+    };
+    assert_eq!(
+        merkle_verify_leaf_checked(&root, &leaves[4], &empty),
+        Err(MerkleVerifyError::IndexOutOfRange { index: 1, depth: 0 })
+    );
+
+    let sib = &path.siblings;
+    assert_eq!(
+        merkle_verify_leaf_at_depth_checked(&root, &leaves[4], sib, 4, depth),
+        Ok(())
+    );
+    assert_eq!(
+        merkle_verify_leaf_at_depth_checked(&root, &leaves[4], &sib[..2], 4, depth),
+        Err(MerkleVerifyError::PathLength {
+            expected: depth,
+            got: 2
+        })
+    );
+    assert_eq!(
+        merkle_verify_leaf_at_depth_checked(&root, &leaves[4], sib, 8, depth),
+        Err(MerkleVerifyError::IndexOutOfRange { index: 8, depth })
+    );
+    assert_eq!(
+        merkle_verify_leaf_at_depth_checked(&root, &leaves[5], sib, 4, depth),
+        Err(MerkleVerifyError::RootMismatch)
+    );
+    // The bool wrappers agree with the checked forms.
+    assert!(merkle_verify_leaf(&root, &leaves[4], &path));
+    assert!(!merkle_verify_leaf(&root, &leaves[4], &beyond));
+    assert!(!merkle_verify_leaf_at_depth(
+        &root,
+        &leaves[4],
+        &sib[..2],
+        4,
+        depth
+    ));
+}
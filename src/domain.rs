@@ -1,5 +0,0 @@
-pub const TAG_VRFOUT: &[u8] = b"VRFOUT";
-pub const TAG_EPOCH:  &[u8] = b"EPOCH";
-pub const TAG_SEED:   &[u8] = b"SEED";
-pub const TAG_KDF:    &[u8] = b"KDF";
-pub const TAG_CHAL:   &[u8] = b"CHAL";
\ No newline at end of file